@@ -0,0 +1,400 @@
+use std::{future::Future, io::Write};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use futures::TryStreamExt;
+use libft_api::export::{csv::FtCsvWriter, FtExportRecord};
+use libft_api::prelude::*;
+use serde::Serialize;
+
+const PER_PAGE: u8 = 100;
+
+/// Command line access to the 42 Intra API.
+#[derive(Parser, Debug)]
+#[command(name = "ft", version, about, long_about = None)]
+struct Cli {
+    /// Output format of the listed records
+    #[arg(short, long, value_enum, default_value_t = Output::Json, global = true)]
+    output: Output,
+
+    /// Campus id or name the listings are restricted to
+    #[arg(short, long, global = true, default_value = "Gyeongsan")]
+    campus: String,
+
+    /// Log the POST/PATCH/DELETE requests instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Output {
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List the users of the campus
+    Users {
+        /// Only users of this piscine month (e.g. `july`)
+        #[arg(long)]
+        pool_month: Option<String>,
+        /// Only users of this piscine year (e.g. `2024`)
+        #[arg(long)]
+        pool_year: Option<String>,
+    },
+    /// List or patch scale teams
+    ScaleTeams {
+        #[command(subcommand)]
+        command: ScaleTeamsCommand,
+    },
+    /// List the teams of a project session
+    Teams {
+        #[arg(long)]
+        project_session_id: u16,
+        /// Only teams created after this date (e.g. `2025-01-20`)
+        #[arg(long)]
+        from: String,
+        /// Only teams created before this date (e.g. `2025-02-15`)
+        #[arg(long)]
+        to: String,
+    },
+    /// Export the correction point historics of users
+    Export {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        user_ids: Vec<i32>,
+        /// Only historics created after this date, in RFC 3339
+        #[arg(long)]
+        from: Option<DateTime<Utc>>,
+        /// Only historics created before this date, in RFC 3339
+        #[arg(long)]
+        to: Option<DateTime<Utc>>,
+        /// Only historics whose reason contains this text
+        #[arg(long)]
+        reason: Option<String>,
+    },
+    /// Assign evaluators to the teams of a project session
+    Schedule {
+        #[arg(long)]
+        project_session_id: u16,
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        evaluators: Vec<i32>,
+        /// Begin of the first slot, in RFC 3339 (e.g. `2025-01-28T05:00:00Z`)
+        #[arg(long)]
+        begin_at: DateTime<Utc>,
+        /// Length of a slot in minutes
+        #[arg(long, default_value_t = 60)]
+        slot_minutes: i64,
+        /// Only teams created after this date
+        #[arg(long)]
+        from: String,
+        /// Only teams created before this date
+        #[arg(long)]
+        to: String,
+        /// Book the scale teams instead of only printing them
+        #[arg(long)]
+        post: bool,
+    },
+    /// Show the remaining rate-limit quota of the application
+    RateLimit,
+}
+
+#[derive(Subcommand, Debug)]
+enum ScaleTeamsCommand {
+    /// List the scale teams of the campus
+    List {
+        /// Only scale teams beginning after this date
+        #[arg(long)]
+        from: Option<String>,
+        /// Only scale teams beginning before this date
+        #[arg(long)]
+        to: Option<String>,
+    },
+    /// Set the scale of scale teams
+    Patch {
+        #[arg(short, long, value_delimiter = ',', num_args = 1..)]
+        scale_team_ids: Vec<i32>,
+        #[arg(short, long)]
+        new_scale_id: i32,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+    let cli = Cli::parse();
+
+    let token = FtApiToken::try_get(AuthInfo::build_from_env()?)
+        .await
+        .map_err(|e| format!("Token error: {e:?}"))?;
+    let client = FtClient::new(FtClientReqwestConnector::new()).with_dry_run(cli.dry_run);
+    let session = client.open_session(token);
+    let campus_id = match cli.campus.parse() {
+        Ok(id) => FtCampusId::new(id),
+        Err(_) => match FtCampusRegistry::by_name(&cli.campus) {
+            Some(id) => id,
+            None => {
+                FtCampusRegistry::load(&session).await?;
+                FtCampusRegistry::by_name(&cli.campus)
+                    .ok_or_else(|| format!("Unknown campus: {}", cli.campus))?
+            }
+        },
+    };
+
+    match cli.command {
+        Command::Users {
+            pool_month,
+            pool_year,
+        } => {
+            let mut filter = Vec::new();
+            if let Some(pool_month) = pool_month {
+                filter.push(FtFilterOption::new(
+                    FtFilterField::PoolMonth,
+                    vec![pool_month],
+                ));
+            }
+            if let Some(pool_year) = pool_year {
+                filter.push(FtFilterOption::new(
+                    FtFilterField::PoolYear,
+                    vec![pool_year],
+                ));
+            }
+
+            let users = fetch_all(|page| {
+                let req = FtApiCampusIdUsersRequest::new(campus_id.clone())
+                    .with_filter(filter.clone())
+                    .with_page(page)
+                    .with_per_page(PER_PAGE);
+                let session = &session;
+                async move { session.campus_id_users(req).await.map(|res| res.users) }
+            })
+            .await?;
+            print(&users, cli.output)?;
+        }
+        Command::ScaleTeams {
+            command: ScaleTeamsCommand::List { from, to },
+        } => {
+            let range = match (from, to) {
+                (None, None) => vec![],
+                (from, to) => vec![FtRangeOption::new(
+                    FtRangeField::BeginAt,
+                    vec![from.unwrap_or_default(), to.unwrap_or_default()],
+                )],
+            };
+
+            let scale_teams = fetch_all(|page| {
+                let req = FtApiScaleTeamsRequest::new()
+                    .with_filter(vec![FtFilterOption::new(
+                        FtFilterField::CampusId,
+                        vec![campus_id.to_string()],
+                    )])
+                    .with_range(range.clone())
+                    .with_page(page)
+                    .with_per_page(PER_PAGE);
+                let session = &session;
+                async move { session.scale_teams(req).await.map(|res| res.scale_teams) }
+            })
+            .await?;
+            print(&scale_teams, cli.output)?;
+        }
+        Command::ScaleTeams {
+            command:
+                ScaleTeamsCommand::Patch {
+                    scale_team_ids,
+                    new_scale_id,
+                },
+        } => {
+            for id in scale_team_ids {
+                match session
+                    .scale_teams_id_update(FtApiScaleTeamsIdUpdateRequest::new(
+                        FtScaleTeamId::new(id),
+                        FtApiScaleTeamsIdUpdateBody::new()
+                            .with_scale_id(FtScaleId::new(new_scale_id)),
+                    ))
+                    .await
+                {
+                    Ok(_) => eprintln!("Successfully patched scale_team {id}"),
+                    Err(e) if e.is_dry_run() => eprintln!("{e}"),
+                    Err(e) => eprintln!("Failed to patch scale_team {id}: {e}"),
+                }
+            }
+        }
+        Command::Teams {
+            project_session_id,
+            from,
+            to,
+        } => {
+            let teams = project_session_teams(
+                &session,
+                campus_id,
+                FtProjectSessionId::new(project_session_id),
+                from,
+                to,
+            )
+            .await?;
+            print(&teams, cli.output)?;
+        }
+        Command::Export {
+            user_ids,
+            from,
+            to,
+            reason,
+        } => {
+            let user_ids = user_ids.into_iter().map(FtUserId::new).collect::<Vec<_>>();
+            let mut filter = FtCorrectionPointAuditFilter::new();
+            filter.from = from;
+            filter.to = to;
+            filter.reason = reason;
+
+            let rows = correction_point_audit(&session, &user_ids, filter).await?;
+            print(&rows, cli.output)?;
+        }
+        Command::Schedule {
+            project_session_id,
+            evaluators,
+            begin_at,
+            slot_minutes,
+            from,
+            to,
+            post,
+        } => {
+            let team_ids = project_session_teams(
+                &session,
+                campus_id,
+                FtProjectSessionId::new(project_session_id),
+                from,
+                to,
+            )
+            .await?
+            .into_iter()
+            .map(|team| team.id)
+            .collect::<Vec<_>>();
+            let evaluators = evaluators
+                .into_iter()
+                .map(FtUserId::new)
+                .collect::<Vec<_>>();
+
+            let schedule = FtDefenseScheduler::new(begin_at, TimeDelta::minutes(slot_minutes))
+                .schedule(&team_ids, &evaluators)?;
+            for conflict in &schedule.conflicts {
+                eprintln!(
+                    "{} moved from {} to {} for team {}",
+                    conflict.user_id,
+                    conflict.requested_at,
+                    conflict.scheduled_at,
+                    conflict.team_id
+                );
+            }
+
+            if post {
+                for req in schedule.into_requests() {
+                    let report = session.scale_teams_multiple_create_post(req).await?;
+                    for (body, err) in &report.failed {
+                        eprintln!(
+                            "team {} with evaluator {} not booked: {err}",
+                            body.team_id, body.user_id
+                        );
+                    }
+                    for err in &report.unattributed {
+                        eprintln!("booking error for an unknown team: {err}");
+                    }
+                    if !report.simulated.is_empty() {
+                        eprintln!(
+                            "dry run, {} scale team(s) not booked",
+                            report.simulated.len()
+                        );
+                    }
+                    print(&report.created, cli.output)?;
+                }
+            } else {
+                let bodies = schedule.batches.into_iter().flatten().collect::<Vec<_>>();
+                print(&bodies, cli.output)?;
+            }
+        }
+        Command::RateLimit => {
+            // One small request to get the current headers of the Intra.
+            session
+                .users(FtApiUsersRequest::new().with_per_page(1))
+                .await?;
+            let status = client.rate_limit_status();
+            println!(
+                "secondly: {}/{} (reset in {:?})",
+                status.secondly_remaining, status.secondly_limit, status.secondly_reset_in
+            );
+            println!(
+                "hourly: {}/{} (reset in {:?})",
+                status.hourly_remaining, status.hourly_limit, status.hourly_reset_in
+            );
+            if let Some(retry_after) = status.retry_after {
+                println!("retry after: {retry_after:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn project_session_teams<FCHC>(
+    session: &FtClientSession<'_, FCHC>,
+    campus_id: FtCampusId,
+    project_session_id: FtProjectSessionId,
+    from: String,
+    to: String,
+) -> ClientResult<Vec<FtTeam>>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    fetch_all(|page| {
+        let req = FtApiProjectSessionsTeamsRequest::new(project_session_id.clone())
+            .with_filter(vec![FtFilterOption::new(
+                FtFilterField::Campus,
+                vec![campus_id.to_string()],
+            )])
+            .with_range(vec![FtRangeOption::new(
+                FtRangeField::CreatedAt,
+                vec![from.clone(), to.clone()],
+            )])
+            .with_page(usize::from(page))
+            .with_per_page(PER_PAGE);
+        async move {
+            session
+                .project_sessions_id_teams(req)
+                .await
+                .map(|res| res.teams)
+        }
+    })
+    .await
+}
+
+/// Fetches every page of `PER_PAGE` records, waiting out the rate limits.
+async fn fetch_all<T, F, Fut>(fetch: F) -> ClientResult<Vec<T>>
+where
+    F: FnMut(u16) -> Fut,
+    Fut: Future<Output = ClientResult<Vec<T>>>,
+{
+    paginate(PER_PAGE, fetch).try_collect().await
+}
+
+fn print<R>(records: &[R], output: Output) -> Result<(), Box<dyn std::error::Error>>
+where
+    R: FtExportRecord + Serialize,
+{
+    let stdout = std::io::stdout().lock();
+    match output {
+        Output::Json => {
+            let mut stdout = stdout;
+            serde_json::to_writer_pretty(&mut stdout, records)?;
+            writeln!(stdout)?;
+        }
+        Output::Csv => {
+            let mut writer = FtCsvWriter::new(stdout);
+            writer.write_all(records)?;
+            writer.into_inner()?.flush()?;
+        }
+    }
+
+    Ok(())
+}
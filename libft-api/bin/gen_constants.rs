@@ -0,0 +1,69 @@
+//! Regenerates the id constants from the API.
+//!
+//! Usage:
+//! * `cargo run --bin gen-constants [-- <output path>]` rewrites `src/info/ft_campus_id.rs` from
+//!   the `/campus` endpoint.
+//! * `cargo run --bin gen-constants -- project-sessions <campus> <cursus id> [module]` prints the
+//!   project session constants of a campus (id or name) and cursus, to refresh the
+//!   `ft_project_session_ids` modules.
+
+use std::path::PathBuf;
+
+use libft_api::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let token = FtApiToken::try_get(AuthInfo::build_from_env()?)
+        .await
+        .map_err(|e| format!("Token error: {e:?}"))?;
+    let client = FtClient::new(FtClientReqwestConnector::new());
+    let session = client.open_session(token);
+
+    match args.first().map(String::as_str) {
+        Some("project-sessions") => {
+            let [campus, cursus_id] = [args.get(1), args.get(2)]
+                .map(|arg| arg.ok_or("Usage: gen-constants project-sessions <campus> <cursus id>"));
+            let (campus, cursus_id) = (campus?, FtCursusId::new(cursus_id?.parse()?));
+            let module = args.get(3).map_or("ft_cursus", String::as_str);
+
+            let campus_id = match campus.parse() {
+                Ok(id) => FtCampusId::new(id),
+                Err(_) => FtCampusRegistry::by_name(campus)
+                    .ok_or_else(|| format!("Unknown campus: {campus}"))?,
+            };
+            let registry = FtProjectRegistry::new();
+            registry
+                .load(&session, campus_id.clone(), cursus_id.clone())
+                .await?;
+            print!(
+                "{}",
+                registry.to_constants_module(module, &campus_id, &cursus_id)
+            );
+        }
+        path => {
+            let path = path.map_or_else(
+                || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/info/ft_campus_id.rs"),
+                PathBuf::from,
+            );
+
+            let mut registry = FtCampusRegistry::builtin();
+            let known = registry.campuses().len();
+            registry.merge(FtCampusRegistry::fetch(&session).await?);
+
+            for campus in &registry.campuses()[known..] {
+                eprintln!("new campus: {} ({})", campus.name, campus.id);
+            }
+            std::fs::write(&path, registry.to_constants_module())?;
+            eprintln!(
+                "{} campuses written to {}",
+                registry.campuses().len(),
+                path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
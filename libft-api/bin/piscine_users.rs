@@ -1,53 +1,75 @@
-use std::{io::Write, sync::Arc};
+//! Prints a CSV summary of the students of a pool.
+//!
+//! Usage: `cargo run --bin piscine_users -- <begin date> <end date> [campus]`, dates as
+//! `YYYY-MM-DD` and the campus as an id or a name (Gyeongsan by default).
 
-use futures::FutureExt;
+use chrono::NaiveDate;
 use libft_api::{info::ft_campus_id::GYEONGSAN, prelude::*};
-use tokio::task::JoinSet;
 
 #[tokio::main]
-async fn main() {
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
-    let client = Arc::new(FtClient::new(FtClientReqwestConnector::new()));
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
 
-    let req: ReqFn<_> = |session, page| {
-        async move {
-            session
-                .users(
-                    FtApiUsersRequest::new()
-                        .with_page(page)
-                        .with_per_page(100)
-                        .with_filter(vec![FtFilterOption::new(
-                            FtFilterField::PrimaryCampusId,
-                            vec![GYEONGSAN.to_string()],
-                        )]),
-                )
-                .await
-        }
-        .boxed()
+    let [begin_at, end_at] = [args.first(), args.get(1)].map(|arg| {
+        arg.ok_or("Usage: piscine_users <begin date> <end date> [campus]")
+            .and_then(|date| {
+                date.parse::<NaiveDate>()
+                    .map_err(|_| "Dates must be formatted as YYYY-MM-DD")
+            })
+            .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+    });
+    let campus_id = match args.get(2) {
+        None => FtCampusId::new(GYEONGSAN),
+        Some(campus) => match campus.parse() {
+            Ok(id) => FtCampusId::new(id),
+            Err(_) => FtCampusRegistry::by_name(campus)
+                .ok_or_else(|| format!("Unknown campus: {campus}"))?,
+        },
     };
 
-    let mut handles = JoinSet::new();
-    let mut result = Vec::new();
+    let token = FtApiToken::try_get(AuthInfo::build_from_env()?)
+        .await
+        .map_err(|e| format!("Token error: {e:?}"))?;
+    let client = FtClient::new(FtClientReqwestConnector::new());
+    let session = client.open_session(token);
 
-    let client_clone = Arc::clone(&client);
-    handles.spawn(async move { scroller(&client_clone, 8, 1, req).await });
-    if let Some(Ok(res)) = handles.join_next().await {
-        result.extend(res);
-    }
-
-    for i in 2..=8 {
-        let client = Arc::clone(&client);
-        handles.spawn(async move { scroller(&client, 8, i, req).await });
-    }
+    let students = FtPiscine::new(campus_id, begin_at?, end_at?)
+        .fetch(&session, &chrono::Utc)
+        .await?;
 
-    while let Some(res) = handles.join_next().await {
-        match res {
-            Ok(v) => result.extend(v),
-            Err(e) => tracing::error!("task failed: {e}"),
-        }
+    println!("user_id,login,level,best_exam,exams,rushes,validated_projects,logtime_hours,attendance_days");
+    for student in students {
+        let marks = |results: &[FtPiscineResult]| {
+            results
+                .iter()
+                .map(|result| {
+                    result
+                        .final_mark
+                        .as_ref()
+                        .map_or("-".to_string(), ToString::to_string)
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        println!(
+            "{},{},{:.2},{},{},{},{},{:.1},{}",
+            student.user_id,
+            student
+                .login
+                .as_ref()
+                .map_or(String::new(), ToString::to_string),
+            student.level,
+            student
+                .best_exam()
+                .map_or(String::new(), ToString::to_string),
+            marks(&student.exams),
+            marks(&student.rushes),
+            student.validated_projects(),
+            student.logtime.num_minutes() as f64 / 60.0,
+            student.attendance_days,
+        );
     }
 
-    let mut file = std::fs::File::create("whole.json").unwrap();
-    file.write_all(serde_json::to_string_pretty(&result).unwrap().as_bytes())
-        .unwrap();
+    Ok(())
 }
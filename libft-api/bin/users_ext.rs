@@ -1,13 +1,10 @@
-use std::{io::Write, sync::Arc, time::Duration};
+use std::io::Write;
 
 use libft_api::prelude::*;
-use serde_with::DurationSeconds;
-use tokio::{task::JoinSet, time::sleep};
 
 #[tokio::main]
 async fn main() {
     tracing_subscriber::fmt::init();
-    let mut handles = JoinSet::new();
     let user_ids = [
         249693, 249676, 247198, 247197, 247155, 247154, 247153, 247152, 247151, 247150, 247149,
         247148, 247147, 247146, 247145, 247144, 247143, 247142, 247141, 247140, 247139, 247138,
@@ -142,41 +139,21 @@ async fn main() {
     //     "taewonki", "takwak", "yeonjuki", "yoshin", "yuhyoon",
     // ]
     // .map(std::string::ToString::to_string);
-    let client = Arc::new(FtClient::with_ratelimits(
-        FtClientReqwestConnector::new(),
-        8,
-        1600,
-    ));
+    let client = FtClient::with_ratelimits(FtClientReqwestConnector::new(), 8, 1600);
+    let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+        .await
+        .unwrap();
+    let session = client.open_session(token);
 
     let mut result = Vec::new();
-    for id in user_ids {
-        let client = Arc::clone(&client);
-        handles.spawn(async move {
-            let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
-                .await
-                .unwrap();
-            let session = client.open_session(token);
-            loop {
-                let result = session
-                    .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::UserId(
-                        FtUserId::new(id),
-                    )))
-                    .await;
-                match result {
-                    Ok(v) => return v.user,
-                    Err(FtClientError::RateLimitError(v)) => {
-                        sleep(v.retry_after.unwrap()).await;
-                    }
-                    Err(e) => {
-                        tracing::error!("{:?}", e)
-                    }
-                }
-            }
-        });
-    }
-
-    while let Some(Ok(v)) = handles.join_next().await {
-        result.push(v);
+    let users = session
+        .fetch_users(user_ids.into_iter().map(FtUserId::new), 8)
+        .await;
+    for (id, user) in user_ids.iter().zip(users) {
+        match user {
+            Ok(user) => result.push(user),
+            Err(e) => tracing::error!("{id}: {:?}", e),
+        }
     }
 
     let mut file = std::fs::File::create("cadet.json").unwrap();
@@ -0,0 +1,13 @@
+//! Derived figures computed from API models.
+//!
+//! Nothing here talks to the API on its own, except the helpers loading the reference tables the
+//! computations need (e.g. the XP table of a cursus).
+
+pub mod blackhole;
+pub use blackhole::*;
+pub mod level;
+pub use level::*;
+pub mod logtime;
+pub use logtime::*;
+pub mod piscine;
+pub use piscine::*;
@@ -0,0 +1,337 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// The default number of days before a deadline from which a student is reported at risk.
+pub const FT_DEADLINE_DEFAULT_RISK_DAYS: i64 = 14;
+
+/// A set of projects to validate within `days` days of the cursus `begin_at`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtMilestone {
+    pub name: String,
+    pub projects: Vec<FtSlug>,
+    pub days: i64,
+}
+
+impl FtMilestone {
+    pub fn new(name: impl Into<String>, projects: Vec<FtSlug>, days: i64) -> Self {
+        Self {
+            name: name.into(),
+            projects,
+            days,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FtDeadlineStatus {
+    /// The next deadline is further than the risk window.
+    Safe,
+    /// The next deadline falls within the risk window.
+    AtRisk,
+    /// Every deadline has passed.
+    Overdue,
+}
+
+/// The next deadline of a student in a cursus.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtStudentDeadline {
+    pub user_id: Option<FtUserId>,
+    pub login: Option<FtLoginId>,
+    pub cursus_id: FtCursusId,
+    pub blackholed_at: Option<DateTime<Utc>>,
+    /// The first milestone not validated yet, with its deadline.
+    pub milestone: Option<(String, DateTime<Utc>)>,
+    /// The earliest of `blackholed_at` and the milestone deadline still ahead, or the earliest
+    /// passed one when none is.
+    pub deadline: Option<DateTime<Utc>>,
+    pub days_left: Option<i64>,
+    pub status: FtDeadlineStatus,
+    /// Whether a deadline has passed, e.g. a milestone missed while the blackhole is still ahead.
+    pub overdue: bool,
+}
+
+/// Computes blackhole and milestone deadlines from cursus users and projects users.
+///
+/// ```rust
+/// use chrono::Utc;
+/// use libft_api::prelude::*;
+///
+/// fn weekly_report(cursus_users: &[FtCursusUser], projects_users: &[FtProjectsUser]) {
+///     let calculator = FtDeadlineCalculator::new(Utc::now())
+///         .with_risk_days(7)
+///         .with_milestones(vec![FtMilestone::new(
+///             "milestone 1",
+///             vec![FtSlug::new("42cursus-libft".to_string())],
+///             60,
+///         )]);
+///     for student in calculator.at_risk(cursus_users, projects_users) {
+///         println!("{:?}: {:?} days left", student.login, student.days_left);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FtDeadlineCalculator {
+    pub now: DateTime<Utc>,
+    pub risk_days: i64,
+    pub milestones: Vec<FtMilestone>,
+}
+
+impl FtDeadlineCalculator {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            risk_days: FT_DEADLINE_DEFAULT_RISK_DAYS,
+            milestones: Vec::new(),
+        }
+    }
+
+    pub fn with_risk_days(mut self, risk_days: i64) -> Self {
+        self.risk_days = risk_days;
+        self
+    }
+
+    /// Milestones to check, in order.
+    pub fn with_milestones(mut self, milestones: Vec<FtMilestone>) -> Self {
+        self.milestones = milestones;
+        self
+    }
+
+    /// The next deadline of `cursus_user`, given the projects users of the same user.
+    ///
+    /// Projects users of other cursus are ignored.
+    pub fn deadline<'a>(
+        &self,
+        cursus_user: &FtCursusUser,
+        projects_users: impl IntoIterator<Item = &'a FtProjectsUser>,
+    ) -> FtStudentDeadline {
+        let validated = projects_users
+            .into_iter()
+            .filter(|projects_user| projects_user.cursus_ids.contains(&cursus_user.cursus_id))
+            .filter(|projects_user| projects_user.validated == Some(true))
+            .map(|projects_user| &projects_user.project.slug)
+            .collect::<Vec<_>>();
+
+        let milestone = cursus_user.begin_at.as_ref().and_then(|begin_at| {
+            self.milestones
+                .iter()
+                .find(|milestone| {
+                    !milestone
+                        .projects
+                        .iter()
+                        .all(|slug| validated.contains(&slug))
+                })
+                .map(|milestone| {
+                    (
+                        milestone.name.clone(),
+                        *begin_at.value() + TimeDelta::days(milestone.days),
+                    )
+                })
+        });
+        let blackholed_at = cursus_user
+            .blackholed_at
+            .as_ref()
+            .map(|blackholed_at| *blackholed_at.value());
+
+        let deadlines = [blackholed_at, milestone.as_ref().map(|(_, at)| *at)];
+        let (passed, ahead) = deadlines
+            .into_iter()
+            .flatten()
+            .partition::<Vec<_>, _>(|deadline| *deadline < self.now);
+        let deadline = ahead.iter().min().or_else(|| passed.iter().min()).copied();
+        let days_left = deadline.map(|deadline| (deadline - self.now).num_days());
+        let status = match deadline {
+            Some(deadline) if deadline < self.now => FtDeadlineStatus::Overdue,
+            Some(deadline) if deadline - self.now <= TimeDelta::days(self.risk_days) => {
+                FtDeadlineStatus::AtRisk
+            }
+            _ => FtDeadlineStatus::Safe,
+        };
+
+        FtStudentDeadline {
+            user_id: cursus_user.user.id,
+            login: cursus_user.user.login.clone(),
+            cursus_id: cursus_user.cursus_id.clone(),
+            blackholed_at,
+            milestone,
+            deadline,
+            days_left,
+            status,
+            overdue: !passed.is_empty(),
+        }
+    }
+
+    /// The deadlines of every cursus user, the projects users being matched by `user.id`.
+    pub fn deadlines(
+        &self,
+        cursus_users: &[FtCursusUser],
+        projects_users: &[FtProjectsUser],
+    ) -> Vec<FtStudentDeadline> {
+        let mut by_user = HashMap::<FtUserId, Vec<&FtProjectsUser>>::new();
+        for projects_user in projects_users {
+            if let Some(user_id) = projects_user.user.as_ref().and_then(|user| user.id) {
+                by_user.entry(user_id).or_default().push(projects_user);
+            }
+        }
+
+        cursus_users
+            .iter()
+            .map(|cursus_user| {
+                let projects_users = cursus_user
+                    .user
+                    .id
+                    .and_then(|user_id| by_user.get(&user_id))
+                    .into_iter()
+                    .flatten()
+                    .copied();
+                self.deadline(cursus_user, projects_users)
+            })
+            .collect()
+    }
+
+    /// The students whose next deadline falls within `risk_days` or who already missed one,
+    /// earliest deadline first.
+    pub fn at_risk(
+        &self,
+        cursus_users: &[FtCursusUser],
+        projects_users: &[FtProjectsUser],
+    ) -> Vec<FtStudentDeadline> {
+        let mut at_risk = self
+            .deadlines(cursus_users, projects_users)
+            .into_iter()
+            .filter(|student| student.status != FtDeadlineStatus::Safe || student.overdue)
+            .collect::<Vec<_>>();
+        at_risk.sort_by_key(|student| student.deadline);
+        at_risk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()
+    }
+
+    fn cursus_user(user_id: i32, blackholed_at: Option<&str>) -> FtCursusUser {
+        serde_json::from_value(serde_json::json!({
+            "id": user_id,
+            "grade": "Learner",
+            "level": 1.5,
+            "skills": [],
+            "blackholed_at": blackholed_at,
+            "begin_at": "2025-01-01T00:00:00.000Z",
+            "end_at": null,
+            "cursus_id": 21,
+            "has_coalition": true,
+            "created_at": "2025-01-01T00:00:00.000Z",
+            "updated_at": "2025-01-01T00:00:00.000Z",
+            "user": { "id": user_id, "login": format!("user{user_id}") },
+            "cursus": {
+                "id": 21,
+                "created_at": "2019-07-29T08:45:17.896Z",
+                "name": "42cursus",
+                "slug": "42cursus",
+                "kind": "main"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn validated(user_id: i32, slug: &str) -> FtProjectsUser {
+        serde_json::from_value(serde_json::json!({
+            "created_at": "2025-01-02T00:00:00.000Z",
+            "cursus_ids": [21],
+            "final_mark": 100,
+            "id": user_id,
+            "marked": true,
+            "occurrence": 0,
+            "project": { "id": 1, "name": slug, "slug": slug },
+            "status": "finished",
+            "updated_at": "2025-01-20T00:00:00.000Z",
+            "user": { "id": user_id },
+            "validated?": true
+        }))
+        .unwrap()
+    }
+
+    fn calculator() -> FtDeadlineCalculator {
+        FtDeadlineCalculator::new(now()).with_milestones(vec![
+            FtMilestone::new("m0", vec![FtSlug::new("42cursus-libft".to_string())], 30),
+            FtMilestone::new(
+                "m1",
+                vec![FtSlug::new("42cursus-ft_printf".to_string())],
+                65,
+            ),
+        ])
+    }
+
+    #[test]
+    fn milestone_deadline() {
+        let calculator = calculator();
+
+        let late = calculator.deadline(&cursus_user(1, None), []);
+        assert_eq!(late.status, FtDeadlineStatus::Overdue);
+        assert!(late.overdue);
+        assert_eq!(late.milestone.unwrap().0, "m0");
+
+        let libft = validated(2, "42cursus-libft");
+        let on_track = calculator.deadline(&cursus_user(2, None), [&libft]);
+        assert_eq!(on_track.status, FtDeadlineStatus::AtRisk);
+        assert_eq!(on_track.days_left, Some(6));
+    }
+
+    #[test]
+    fn blackhole_before_milestone() {
+        let libft = validated(3, "42cursus-libft");
+        let student =
+            calculator().deadline(&cursus_user(3, Some("2025-03-04T00:00:00.000Z")), [&libft]);
+
+        assert_eq!(
+            student.deadline,
+            Some(Utc.with_ymd_and_hms(2025, 3, 4, 0, 0, 0).unwrap())
+        );
+        assert_eq!(student.days_left, Some(3));
+    }
+
+    #[test]
+    fn missed_milestone_keeps_the_blackhole_ahead() {
+        let student = calculator().deadline(&cursus_user(4, Some("2025-03-04T00:00:00.000Z")), []);
+
+        assert_eq!(
+            student.deadline,
+            Some(Utc.with_ymd_and_hms(2025, 3, 4, 0, 0, 0).unwrap())
+        );
+        assert_eq!(student.status, FtDeadlineStatus::AtRisk);
+        assert!(student.overdue);
+    }
+
+    #[test]
+    fn at_risk_matches_projects_users_by_user() {
+        let cursus_users = [
+            cursus_user(1, None),
+            cursus_user(2, None),
+            cursus_user(3, Some("2025-06-01T00:00:00.000Z")),
+        ];
+        let projects_users = [
+            validated(2, "42cursus-libft"),
+            validated(3, "42cursus-libft"),
+            validated(3, "42cursus-ft_printf"),
+        ];
+
+        // The overdue student first, then the one whose milestone is near.
+        let at_risk = calculator().at_risk(&cursus_users, &projects_users);
+        assert_eq!(
+            at_risk
+                .iter()
+                .map(|student| student.user_id)
+                .collect::<Vec<_>>(),
+            vec![Some(FtUserId::new(1)), Some(FtUserId::new(2))]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+#[cfg(feature = "levels")]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+use crate::prelude::*;
+
+static FT_LEVEL_TABLES: LazyLock<RwLock<HashMap<FtCursusId, FtLevelTable>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// The XP table of a cursus, as returned by the levels endpoint.
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// async fn example(projects_user: FtProjectsUser) -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let cursus_id = FtCursusId::new(FT_CURSUS_ID);
+///     FtLevelTable::load(&session, cursus_id.clone()).await?;
+///     if let Some(xp_delta) = xp_delta_for(&projects_user) {
+///         let projected = level_from_xp(&cursus_id, 78_880 + xp_delta);
+///         println!("{projected:?}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtLevelTable {
+    /// `(lvl, xp)` pairs sorted by xp.
+    levels: Vec<(i32, i64)>,
+}
+
+impl FtLevelTable {
+    pub fn new(levels: &[FtLevel]) -> Self {
+        let mut levels = levels
+            .iter()
+            .map(|level| (level.lvl, level.xp))
+            .collect::<Vec<_>>();
+        levels.sort_by_key(|(_, xp)| *xp);
+        Self { levels }
+    }
+
+    /// Fetches the XP table of `cursus_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    #[cfg(feature = "levels")]
+    pub async fn fetch<FCHC>(
+        session: &FtClientSession<'_, FCHC>,
+        cursus_id: FtCursusId,
+    ) -> ClientResult<Self>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let levels = paginate(PER_PAGE, |page| {
+            session.levels(
+                FtApiLevelsRequest::new()
+                    .with_cursus_id(cursus_id.clone())
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        Ok(Self::new(&levels))
+    }
+
+    /// Fetches the XP table of `cursus_id` and registers it for `level_from_xp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    #[cfg(feature = "levels")]
+    pub async fn load<FCHC>(
+        session: &FtClientSession<'_, FCHC>,
+        cursus_id: FtCursusId,
+    ) -> ClientResult<()>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        Self::fetch(session, cursus_id.clone())
+            .await?
+            .register(cursus_id);
+        Ok(())
+    }
+
+    /// Makes this table the one `level_from_xp` uses for `cursus_id`.
+    pub fn register(self, cursus_id: FtCursusId) {
+        FT_LEVEL_TABLES
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(cursus_id, self);
+    }
+
+    /// The level reached with `xp`, the fractional part being the progress towards the next
+    /// level, as in `FtCursusUser::level`.
+    pub fn level(&self, xp: i64) -> f64 {
+        let next = self.levels.partition_point(|(_, level_xp)| *level_xp <= xp);
+        match (
+            next.checked_sub(1).map(|i| self.levels[i]),
+            self.levels.get(next),
+        ) {
+            (Some((lvl, lvl_xp)), Some((_, next_xp))) => {
+                f64::from(lvl) + (xp - lvl_xp) as f64 / (next_xp - lvl_xp) as f64
+            }
+            (Some((lvl, _)), None) => f64::from(lvl),
+            (None, _) => 0.0,
+        }
+    }
+
+    /// The XP matching `level`, the inverse of `FtLevelTable::level`.
+    pub fn xp(&self, level: f64) -> i64 {
+        let lvl = level.floor();
+        let Some(i) = self
+            .levels
+            .iter()
+            .rposition(|(table_lvl, _)| f64::from(*table_lvl) <= lvl)
+        else {
+            return 0;
+        };
+        let (_, lvl_xp) = self.levels[i];
+        match self.levels.get(i + 1) {
+            Some((_, next_xp)) => {
+                lvl_xp + ((next_xp - lvl_xp) as f64 * (level - lvl)).round() as i64
+            }
+            None => lvl_xp,
+        }
+    }
+
+    /// The level reached from `level` after earning `xp_delta`.
+    pub fn projected_level(&self, level: f64, xp_delta: i64) -> f64 {
+        self.level(self.xp(level) + xp_delta)
+    }
+}
+
+/// The level reached with `xp` in `cursus_id`, or `None` if its table was not loaded with
+/// `FtLevelTable::load` or `FtLevelTable::register`.
+pub fn level_from_xp(cursus_id: &FtCursusId, xp: i64) -> Option<f64> {
+    FT_LEVEL_TABLES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(cursus_id)
+        .map(|table| table.level(xp))
+}
+
+/// The XP a project of `difficulty` gives for `final_mark`, bonus included.
+pub fn xp_delta(difficulty: i32, final_mark: i32) -> i64 {
+    i64::from(difficulty) * i64::from(final_mark.max(0)) / 100
+}
+
+/// The XP `projects_user` gives for its final mark.
+///
+/// Returns `None` if it is not marked yet, failed, or if its project comes without `difficulty`
+/// (the `projects_users` endpoint omits it; fetch the project with `projects_id` to fill it).
+pub fn xp_delta_for(projects_user: &FtProjectsUser) -> Option<i64> {
+    if projects_user.validated == Some(false) {
+        return None;
+    }
+    let final_mark = projects_user.final_mark.as_ref()?;
+    Some(xp_delta(
+        projects_user.project.difficulty?,
+        *final_mark.value(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> FtLevelTable {
+        let levels: Vec<FtLevel> = serde_json::from_value(serde_json::json!([
+            { "id": 352, "lvl": 10, "xp": 78_880 },
+            { "id": 343, "lvl": 0, "xp": 0 },
+            { "id": 353, "lvl": 11, "xp": 105_000 },
+            { "id": 344, "lvl": 1, "xp": 462 },
+        ]))
+        .unwrap();
+        FtLevelTable::new(&levels)
+    }
+
+    #[test]
+    fn level() {
+        let table = table();
+
+        assert_eq!(table.level(0), 0.0);
+        assert_eq!(table.level(231), 0.5);
+        assert_eq!(table.level(78_880), 10.0);
+        assert_eq!(table.level(200_000), 11.0);
+        assert_eq!(table.xp(0.5), 231);
+        assert_eq!(table.projected_level(10.0, 13_060), 10.5);
+    }
+
+    #[test]
+    fn registered_table() {
+        let cursus_id = FtCursusId::new(-1);
+        assert_eq!(level_from_xp(&cursus_id, 462), None);
+
+        table().register(cursus_id.clone());
+        assert_eq!(level_from_xp(&cursus_id, 462), Some(1.0));
+    }
+
+    #[test]
+    fn xp_delta_from_projects_user() {
+        let mut projects_user: FtProjectsUser = serde_json::from_value(serde_json::json!({
+            "created_at": "2024-09-04T02:33:07.366Z",
+            "current_team_id": 6_033_392,
+            "cursus_ids": [21],
+            "final_mark": 125,
+            "id": 3_893_640,
+            "marked": true,
+            "marked_at": "2024-09-10T08:09:31.120Z",
+            "occurrence": 0,
+            "project": { "id": 1314, "name": "Libft", "slug": "42cursus-libft" },
+            "retriable_at": null,
+            "status": "finished",
+            "updated_at": "2024-09-10T08:09:31.179Z",
+            "validated?": true
+        }))
+        .unwrap();
+        assert_eq!(xp_delta_for(&projects_user), None);
+
+        projects_user.project.difficulty = Some(462);
+        assert_eq!(xp_delta_for(&projects_user), Some(577));
+
+        projects_user.validated = Some(false);
+        assert_eq!(xp_delta_for(&projects_user), None);
+    }
+}
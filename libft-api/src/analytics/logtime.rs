@@ -0,0 +1,325 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
+#[cfg(feature = "locations")]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+#[cfg(feature = "locations")]
+use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::prelude::*;
+
+/// Whose locations `logtime` aggregates.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FtLogtimeScope {
+    User(FtUserId),
+    Campus(FtCampusId),
+}
+
+/// Time logged on the workstations, per user and per local day.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct FtLogtime {
+    pub days: HashMap<FtUserId, BTreeMap<NaiveDate, TimeDelta>>,
+}
+
+impl FtLogtime {
+    /// Aggregates `locations` between `begin_at` and `end_at`, splitting them at midnight in `tz`.
+    ///
+    /// Overlapping sessions of a user (e.g. two workstations at once) are counted once, and
+    /// sessions still open count until `end_at`.
+    pub fn from_locations<Tz: TimeZone>(
+        locations: &[FtLocation],
+        begin_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+        tz: &Tz,
+    ) -> Self {
+        let mut intervals = HashMap::<FtUserId, Vec<(DateTime<Utc>, DateTime<Utc>)>>::new();
+        for location in locations {
+            let Some(user_id) = location.user.id else {
+                continue;
+            };
+            let location_begin_at = (*location.begin_at.value()).max(begin_at);
+            let location_end_at = location
+                .end_at
+                .as_ref()
+                .map_or(end_at, |end_at| *end_at.value())
+                .min(end_at);
+            if location_begin_at < location_end_at {
+                intervals
+                    .entry(user_id)
+                    .or_default()
+                    .push((location_begin_at, location_end_at));
+            }
+        }
+
+        let mut logtime = Self::default();
+        for (user_id, intervals) in intervals {
+            let days = logtime.days.entry(user_id).or_default();
+            for (begin_at, end_at) in merge(intervals) {
+                split_by_day(begin_at, end_at, tz, |day, duration| {
+                    *days.entry(day).or_insert_with(TimeDelta::zero) += duration;
+                });
+            }
+        }
+        logtime
+    }
+
+    /// The daily durations of `user_id`, empty if the user has no session in the range.
+    pub fn user(&self, user_id: &FtUserId) -> impl Iterator<Item = (&NaiveDate, &TimeDelta)> {
+        self.days.get(user_id).into_iter().flatten()
+    }
+
+    /// The total logtime of `user_id`.
+    pub fn total(&self, user_id: &FtUserId) -> TimeDelta {
+        self.user(user_id)
+            .fold(TimeDelta::zero(), |total, (_, duration)| total + *duration)
+    }
+
+    /// The number of days `user_id` logged in.
+    pub fn attendance_days(&self, user_id: &FtUserId) -> usize {
+        self.days.get(user_id).map_or(0, BTreeMap::len)
+    }
+}
+
+/// Fetches the locations of `scope` and aggregates them per day in `tz`.
+///
+/// Locations begun from one day before `begin_at`, ended within the range or still open are
+/// listed, so sessions begun earlier are counted from `begin_at`. Sessions still open count
+/// until now.
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+///     let logtime = logtime(
+///         &session,
+///         FtLogtimeScope::Campus(FtCampusId::new(ft_campus_id::GYEONGSAN)),
+///         Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+///         &kst,
+///     )
+///     .await?;
+///     for (user_id, days) in &logtime.days {
+///         println!("{user_id}: {} day(s)", days.len());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a page of locations cannot be fetched.
+#[cfg(feature = "locations")]
+pub async fn logtime<FCHC, Tz>(
+    session: &FtClientSession<'_, FCHC>,
+    scope: FtLogtimeScope,
+    begin_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+    tz: &Tz,
+) -> ClientResult<FtLogtime>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+    Tz: TimeZone,
+{
+    const PER_PAGE: u8 = 100;
+
+    let end_at = end_at.min(Utc::now());
+    let range = |field, begin_at: DateTime<Utc>| {
+        Some(vec![FtRangeOption::new(
+            field,
+            vec![begin_at.to_rfc3339(), end_at.to_rfc3339()],
+        )])
+    };
+    // Sessions begun from the day before, sessions begun earlier and ended in the range, and
+    // sessions still open, however long ago they began.
+    let passes = [
+        (
+            range(FtRangeField::BeginAt, begin_at - TimeDelta::days(1)),
+            None,
+        ),
+        (range(FtRangeField::EndAt, begin_at), None),
+        (None, Some(true)),
+    ];
+
+    let mut seen = HashSet::new();
+    let mut locations = Vec::new();
+    for (range, active) in passes {
+        let pass = paginate(PER_PAGE, |page| {
+            let mut req = FtApiLocationsRequest::new()
+                .with_page(page)
+                .with_per_page(PER_PAGE);
+            req.range = range.clone();
+            req.active = active;
+            match &scope {
+                FtLogtimeScope::User(user_id) => req.user_id = Some(*user_id),
+                FtLogtimeScope::Campus(campus_id) => req.campus_id = Some(campus_id.clone()),
+            }
+            session.locations(req)
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        locations.extend(
+            pass.into_iter()
+                .filter(|location| seen.insert(location.id.clone())),
+        );
+    }
+
+    Ok(FtLogtime::from_locations(&locations, begin_at, end_at, tz))
+}
+
+fn merge(
+    mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    intervals.sort();
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for (begin_at, end_at) in intervals {
+        match merged.last_mut() {
+            Some((_, last_end)) if begin_at <= *last_end => *last_end = (*last_end).max(end_at),
+            _ => merged.push((begin_at, end_at)),
+        }
+    }
+    merged
+}
+
+fn split_by_day<Tz: TimeZone>(
+    begin_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+    tz: &Tz,
+    mut add: impl FnMut(NaiveDate, TimeDelta),
+) {
+    let mut cursor = begin_at;
+    while cursor < end_at {
+        let day = cursor.with_timezone(tz).date_naive();
+        let next_midnight = day
+            .succ_opt()
+            .and_then(|next| next.and_hms_opt(0, 0, 0))
+            .and_then(|midnight| tz.from_local_datetime(&midnight).earliest())
+            .map_or(end_at, |midnight| midnight.with_timezone(&Utc));
+        let until = next_midnight.min(end_at);
+        add(day, until - cursor);
+        cursor = until;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn location(
+        user_id: i32,
+        begin_at: DateTime<Utc>,
+        end_at: Option<DateTime<Utc>>,
+    ) -> FtLocation {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "begin_at": begin_at,
+            "end_at": end_at,
+            "primary": true,
+            "host": "c1r1s1",
+            "campus_id": ft_campus_id::GYEONGSAN,
+            "user": { "id": user_id }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn merges_and_splits_by_local_day() {
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let user_id = FtUserId::new(1);
+        let locations = [
+            // 10:00 to 16:00 KST, and an overlapping session on another host.
+            location(1, at(3, 1), Some(at(3, 7))),
+            location(1, at(3, 5), Some(at(3, 8))),
+            // 23:00 KST to 02:00 KST the next day.
+            location(1, at(3, 14), Some(at(3, 17))),
+            // Still open, counted until the end of the range.
+            location(2, at(4, 20), None),
+        ];
+
+        let logtime = FtLogtime::from_locations(&locations, at(1, 0), at(5, 0), &kst);
+
+        let days = logtime.user(&user_id).collect::<Vec<_>>();
+        assert_eq!(
+            days,
+            vec![
+                (
+                    &NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+                    &TimeDelta::hours(8)
+                ),
+                (
+                    &NaiveDate::from_ymd_opt(2025, 3, 4).unwrap(),
+                    &TimeDelta::hours(2)
+                ),
+            ]
+        );
+        assert_eq!(logtime.total(&user_id), TimeDelta::hours(10));
+        assert_eq!(logtime.total(&FtUserId::new(2)), TimeDelta::hours(4));
+        assert_eq!(logtime.attendance_days(&FtUserId::new(3)), 0);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest", feature = "locations"))]
+mod fetch_tests {
+    use super::*;
+    use crate::test_support::*;
+
+    #[tokio::test]
+    async fn long_open_sessions_are_counted_once() {
+        let mock = FtMockServer::start().await;
+        // Left open since a week before the range, answered to every pass.
+        mock.mock_get(
+            "locations",
+            serde_json::json!([{
+                "id": 1,
+                "begin_at": "2025-02-22T00:00:00Z",
+                "end_at": null,
+                "primary": true,
+                "host": "c1r1s1",
+                "campus_id": ft_campus_id::GYEONGSAN,
+                "user": { "id": 1 }
+            }]),
+        )
+        .await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let begin_at = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let logtime = logtime(
+            &session,
+            FtLogtimeScope::User(FtUserId::new(1)),
+            begin_at,
+            begin_at + TimeDelta::days(2),
+            &Utc,
+        )
+        .await
+        .unwrap();
+        assert_eq!(logtime.total(&FtUserId::new(1)), TimeDelta::days(2));
+
+        let queries = mock
+            .server()
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|req| req.url.query().map(str::to_string))
+            .collect::<Vec<_>>();
+        assert!(queries
+            .iter()
+            .any(|query| query.contains("range%5Bend_at%5D")));
+        assert!(queries
+            .iter()
+            .any(|query| query.contains("filter%5Bactive%5D=true")));
+    }
+}
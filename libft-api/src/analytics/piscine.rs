@@ -0,0 +1,390 @@
+use chrono::{DateTime, TimeDelta, Utc};
+#[cfg(all(
+    feature = "cursus_users",
+    feature = "projects_users",
+    feature = "locations"
+))]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// The mark of a student on one project of the pool.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtPiscineResult {
+    pub project: FtSlug,
+    pub final_mark: Option<FtFinalMark>,
+    pub validated: Option<bool>,
+}
+
+/// What a student did during a pool.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtPiscineSummary {
+    pub user_id: FtUserId,
+    pub login: Option<FtLoginId>,
+    pub level: f64,
+    /// Exam projects, i.e. those whose slug contains `exam`.
+    pub exams: Vec<FtPiscineResult>,
+    /// Rush projects, i.e. those whose slug contains `rush`.
+    pub rushes: Vec<FtPiscineResult>,
+    /// Every other project of the pool.
+    pub projects: Vec<FtPiscineResult>,
+    /// Time logged on the campus workstations within the pool, overlapping sessions counted once.
+    pub logtime: TimeDelta,
+    /// Days of the pool, in the time zone given to `FtPiscine::fetch`, with at least one
+    /// workstation session.
+    pub attendance_days: usize,
+}
+
+impl FtPiscineSummary {
+    /// The best exam mark, `None` without any marked exam.
+    pub fn best_exam(&self) -> Option<&FtFinalMark> {
+        self.exams
+            .iter()
+            .filter_map(|exam| exam.final_mark.as_ref())
+            .max_by_key(|final_mark| *final_mark.value())
+    }
+
+    pub fn validated_projects(&self) -> usize {
+        self.projects
+            .iter()
+            .filter(|project| project.validated == Some(true))
+            .count()
+    }
+}
+
+/// Per-student summaries of a pool: exam scores, rush results and attendance.
+///
+/// The students are the piscine cursus users of `campus_id` who began between `begin_at` and
+/// `end_at`; their projects users and workstation sessions are then aggregated over that range,
+/// the attendance counted per day in the time zone given to `fetch`.
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let pool = FtPiscine::new(
+///         FtCampusId::new(ft_campus_id::GYEONGSAN),
+///         Utc.with_ymd_and_hms(2025, 7, 7, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 2, 0, 0, 0).unwrap(),
+///     );
+///     let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+///     for student in pool.fetch(&session, &kst).await? {
+///         println!(
+///             "{:?}: best exam {:?}, {} day(s) attended",
+///             student.login,
+///             student.best_exam(),
+///             student.attendance_days
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtPiscine {
+    pub campus_id: FtCampusId,
+    pub begin_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+}
+
+impl FtPiscine {
+    pub fn new(campus_id: FtCampusId, begin_at: DateTime<Utc>, end_at: DateTime<Utc>) -> Self {
+        Self {
+            campus_id,
+            begin_at,
+            end_at,
+        }
+    }
+
+    /// Fetches the students of the pool and their projects users, and summarizes them with the
+    /// `logtime` of the campus over the pool, split at midnight in `tz`.
+    ///
+    /// Sessions still open count until now, so a pool in progress gets no time past now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    #[cfg(all(
+        feature = "cursus_users",
+        feature = "projects_users",
+        feature = "locations"
+    ))]
+    pub async fn fetch<FCHC, Tz>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        tz: &Tz,
+    ) -> ClientResult<Vec<FtPiscineSummary>>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+        Tz: chrono::TimeZone,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let range = |field| {
+            vec![FtRangeOption::new(
+                field,
+                vec![self.begin_at.to_rfc3339(), self.end_at.to_rfc3339()],
+            )]
+        };
+
+        let cursus_users = paginate(PER_PAGE, |page| {
+            session.cursus_users(
+                FtApiCursusUsersRequest::new()
+                    .with_filter(vec![
+                        FtFilterOption::new(
+                            FtFilterField::CampusId,
+                            vec![self.campus_id.to_string()],
+                        ),
+                        FtFilterOption::new(
+                            FtFilterField::CursusId,
+                            vec![FT_PISCINE_CURSUS_ID.to_string()],
+                        ),
+                    ])
+                    .with_range(range(FtRangeField::BeginAt))
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let projects_users = paginate(PER_PAGE, |page| {
+            session.projects_users(
+                FtApiProjectsUsersRequest::new()
+                    .with_campus_id(self.campus_id.clone())
+                    .with_cursus_id(FtCursusId::new(FT_PISCINE_CURSUS_ID))
+                    .with_range(range(FtRangeField::CreatedAt))
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let logtime = logtime(
+            session,
+            FtLogtimeScope::Campus(self.campus_id.clone()),
+            self.begin_at,
+            self.end_at,
+            tz,
+        )
+        .await?;
+
+        Ok(self.summarize(&cursus_users, &projects_users, &logtime))
+    }
+
+    /// Summarizes each student of `cursus_users`, sorted by login.
+    ///
+    /// Projects users are matched by `user.id`; those of other students are ignored. `logtime`
+    /// covers the pool, e.g. as returned by `logtime` or `FtLogtime::from_locations`.
+    pub fn summarize(
+        &self,
+        cursus_users: &[FtCursusUser],
+        projects_users: &[FtProjectsUser],
+        logtime: &FtLogtime,
+    ) -> Vec<FtPiscineSummary> {
+        let mut summaries = HashMap::<FtUserId, FtPiscineSummary>::new();
+        for cursus_user in cursus_users {
+            let Some(user_id) = cursus_user.user.id else {
+                continue;
+            };
+            summaries.insert(
+                user_id,
+                FtPiscineSummary {
+                    user_id,
+                    login: cursus_user.user.login.clone(),
+                    level: *cursus_user.level.value(),
+                    exams: Vec::new(),
+                    rushes: Vec::new(),
+                    projects: Vec::new(),
+                    logtime: TimeDelta::zero(),
+                    attendance_days: 0,
+                },
+            );
+        }
+
+        for projects_user in projects_users {
+            let Some(summary) = projects_user
+                .user
+                .as_ref()
+                .and_then(|user| user.id)
+                .and_then(|user_id| summaries.get_mut(&user_id))
+            else {
+                continue;
+            };
+            let result = FtPiscineResult {
+                project: projects_user.project.slug.clone(),
+                final_mark: projects_user.final_mark.clone(),
+                validated: projects_user.validated,
+            };
+            let slug = result.project.value();
+            if slug.contains("exam") {
+                summary.exams.push(result);
+            } else if slug.contains("rush") {
+                summary.rushes.push(result);
+            } else {
+                summary.projects.push(result);
+            }
+        }
+
+        for summary in summaries.values_mut() {
+            summary.logtime = logtime.total(&summary.user_id);
+            summary.attendance_days = logtime.attendance_days(&summary.user_id);
+        }
+
+        let mut summaries = summaries.into_values().collect::<Vec<_>>();
+        summaries.sort_by(|a, b| {
+            let key = |summary: &FtPiscineSummary| {
+                (
+                    summary.login.as_ref().map(|login| login.value().clone()),
+                    *summary.user_id.value(),
+                )
+            };
+            key(a).cmp(&key(b))
+        });
+        summaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn pool() -> FtPiscine {
+        FtPiscine::new(
+            FtCampusId::new(ft_campus_id::GYEONGSAN),
+            Utc.with_ymd_and_hms(2025, 7, 7, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 8, 2, 0, 0, 0).unwrap(),
+        )
+    }
+
+    fn pool_logtime<Tz: TimeZone>(locations: &[FtLocation], tz: &Tz) -> FtLogtime {
+        let pool = pool();
+        FtLogtime::from_locations(locations, pool.begin_at, pool.end_at, tz)
+    }
+
+    fn cursus_user(user_id: i32) -> FtCursusUser {
+        serde_json::from_value(serde_json::json!({
+            "id": user_id,
+            "grade": null,
+            "level": 5.42,
+            "skills": [],
+            "blackholed_at": null,
+            "begin_at": "2025-07-07T00:00:00.000Z",
+            "end_at": "2025-08-02T00:00:00.000Z",
+            "cursus_id": 9,
+            "has_coalition": false,
+            "created_at": "2025-07-01T00:00:00.000Z",
+            "updated_at": "2025-08-02T00:00:00.000Z",
+            "user": { "id": user_id, "login": format!("user{user_id}") },
+            "cursus": {
+                "id": 9,
+                "created_at": "2017-11-22T13:41:26.963Z",
+                "name": "C Piscine",
+                "slug": "c-piscine",
+                "kind": "piscine"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn projects_user(user_id: i32, slug: &str, final_mark: i32) -> FtProjectsUser {
+        serde_json::from_value(serde_json::json!({
+            "created_at": "2025-07-10T00:00:00.000Z",
+            "cursus_ids": [9],
+            "final_mark": final_mark,
+            "id": user_id,
+            "marked": true,
+            "occurrence": 0,
+            "project": { "id": 1, "name": slug, "slug": slug },
+            "status": "finished",
+            "updated_at": "2025-07-11T00:00:00.000Z",
+            "user": { "id": user_id },
+            "validated?": final_mark >= 50
+        }))
+        .unwrap()
+    }
+
+    fn location(user_id: i32, begin_at: &str, end_at: Option<&str>) -> FtLocation {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "begin_at": begin_at,
+            "end_at": end_at,
+            "primary": true,
+            "host": "c1r1s1",
+            "campus_id": ft_campus_id::GYEONGSAN,
+            "user": { "id": user_id }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn summarize() {
+        let summaries = pool().summarize(
+            &[cursus_user(2), cursus_user(1)],
+            &[
+                projects_user(1, "c-piscine-exam-00", 40),
+                projects_user(1, "c-piscine-final-exam", 72),
+                projects_user(1, "c-piscine-rush-00", 85),
+                projects_user(1, "c-piscine-c-00", 100),
+                projects_user(3, "c-piscine-c-00", 100),
+            ],
+            &pool_logtime(
+                &[
+                    location(
+                        1,
+                        "2025-07-07T09:00:00.000Z",
+                        Some("2025-07-07T17:00:00.000Z"),
+                    ),
+                    location(
+                        1,
+                        "2025-07-08T22:00:00.000Z",
+                        Some("2025-07-09T02:00:00.000Z"),
+                    ),
+                    location(2, "2025-08-01T23:00:00.000Z", None),
+                ],
+                &Utc,
+            ),
+        );
+
+        assert_eq!(summaries.len(), 2);
+        let (first, second) = (&summaries[0], &summaries[1]);
+        assert_eq!(first.user_id, FtUserId::new(1));
+        assert_eq!(first.best_exam(), Some(&FtFinalMark::new(72)));
+        assert_eq!(first.rushes.len(), 1);
+        assert_eq!(first.validated_projects(), 1);
+        assert_eq!(first.logtime, TimeDelta::hours(12));
+        assert_eq!(first.attendance_days, 3);
+
+        assert_eq!(second.logtime, TimeDelta::hours(1));
+        assert_eq!(second.attendance_days, 1);
+        assert_eq!(second.best_exam(), None);
+    }
+
+    #[test]
+    fn attendance_days_follow_the_time_zone() {
+        let locations = [location(
+            1,
+            "2025-07-07T14:00:00.000Z",
+            Some("2025-07-07T16:00:00.000Z"),
+        )];
+        let attendance = |summaries: Vec<FtPiscineSummary>| summaries[0].attendance_days;
+
+        let utc = pool().summarize(&[cursus_user(1)], &[], &pool_logtime(&locations, &Utc));
+        assert_eq!(attendance(utc), 1);
+
+        // 23:00 to 01:00 in Gyeongsan.
+        let kst = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        let kst = pool().summarize(&[cursus_user(1)], &[], &pool_logtime(&locations, &kst));
+        assert_eq!(attendance(kst), 2);
+    }
+}
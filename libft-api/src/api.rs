@@ -2,21 +2,56 @@
 //!
 //! Each submodule mirrors an API domain (campus, user, project, exam, and so on) and exposes
 //! request/response types plus the associated `FtClientSession` helpers for issuing calls.
+//! Each one is built with the cargo feature named after its path, e.g. `scale_team` with
+//! `scale_teams`; the default `full` feature enables them all.
 //!
 //! This module provides structured access to various 42 Intra API endpoints organized by domain:
+//! * **Achievement**: Achievements and the users who unlocked them
+//! * **Bloc**: Coalition blocs and their standings
 //! * **Campus**: Information about 42 campuses and their locations
+//! * **Close**: Account closes
+//! * **Coalition**: Coalitions, their members and scores
+//! * **Community Service**: Disciplinary community services (TIG)
 //! * **Cursus**: Curriculum-related information and user cursus associations
+//! * **Cursus User**: Enrollment of users into a cursus
 //! * **User**: User profiles and related data
+//! * **Note**: Administrative notes on user profiles
 //! * **Project**: Project information and user project associations
+//! * **Event**: Campus events
 //! * **Exam**: Exam session information
+//! * **Experience**: Experience points granted to users
+//! * **Feedback**: Feedbacks left after evaluations
 //! * **Group**: Group-related functionality
+//! * **Journal**: Activity log of the intra
+//! * **Language**: Languages of the intra and of each campus
+//! * **Location**: Workstation sessions of users
+//! * **Scale**: Evaluation sheets with their sections and questions
 //! * **Scale Team**: Evaluation team functionality
+//! * **Slot**: Evaluation availabilities
 //! * **Project Session**: Project session data
+//! * **Team**: Team information and team membership
+//! * **Title**: Titles granted to users
+//! * **Transaction**: Wallet transactions
+//! * **Waitlist**: Waitlists of full events and exams
+//! * **Broadcast**: Messages broadcast to the users of a campus
+//! * **Tag**: Tags of the forum and notions
+//! * **Translation**: Localized strings of projects, quests and other objects
+//! * **Product**: Campus shop products and commands
+//! * **Quest**: Quests (milestones) of a cursus
+//! * **Level**: XP table of a cursus
+//! * **Skill**: Skills evaluated by a cursus
+//! * **Notion**: E-learning notions, subnotions and their attachments
+//! * **Attachment**: Files attached to projects and subnotions
+//! * **User Candidatures**: Piscine admission files, filterable by campus and pool
+//! * **Pools**: Point pools of each campus and cursus
+//! * **Gitlab Users**: Links between intra users and vogsphere gitlab accounts
+//! * **Offers**: Job and internship offers of the campus job board
+//! * **Apps**: OAuth applications with their scopes and rate limits
 //!
 //! # Example
 //!
 //! # Example                                                                                
-//! ```rust                                                                                  
+//! ```rust,no_run                                                                                  
 //! use libft_api::{prelude::*, info::ft_campus_id::GYEONGSAN};                              
 //!                                                                                          
 //! # async fn run() -> ClientResult<()> {                                                   
@@ -36,15 +71,90 @@
 //! # tokio::runtime::Runtime::new().unwrap().block_on(run()).unwrap();                      
 //! ```                                                                                      
 
+#[cfg(feature = "achievements")]
+pub mod achievement;
+#[cfg(feature = "apps")]
+pub mod app;
+#[cfg(feature = "attachments")]
+pub mod attachment;
+#[cfg(feature = "blocs")]
+pub mod bloc;
+#[cfg(feature = "broadcasts")]
+pub mod broadcast;
+#[cfg(feature = "campus")]
 pub mod campus;
+#[cfg(feature = "closes")]
+pub mod close;
+#[cfg(feature = "coalitions")]
+pub mod coalition;
+#[cfg(feature = "community_services")]
+pub mod community_service;
+#[cfg(feature = "cursus")]
 pub mod cursus;
+#[cfg(feature = "cursus_users")]
+pub mod cursus_user;
+#[cfg(feature = "events")]
+pub mod event;
+#[cfg(feature = "exams")]
 pub mod exam;
+#[cfg(feature = "experiences")]
+pub mod experience;
+#[cfg(feature = "feedbacks")]
+pub mod feedback;
+#[cfg(feature = "gitlab_users")]
+pub mod gitlab_user;
+#[cfg(feature = "groups")]
 pub mod group;
+#[cfg(feature = "journals")]
+pub mod journal;
+#[cfg(feature = "languages")]
+pub mod language;
+#[cfg(feature = "levels")]
+pub mod level;
+#[cfg(feature = "locations")]
+pub mod location;
+#[cfg(feature = "notes")]
+pub mod note;
+#[cfg(feature = "notions")]
+pub mod notion;
+#[cfg(feature = "offers")]
+pub mod offer;
+#[cfg(feature = "pools")]
+pub mod pool;
+#[cfg(feature = "products")]
+pub mod product;
+#[cfg(feature = "projects")]
 pub mod project;
+#[cfg(feature = "project_sessions")]
 pub mod project_session;
+#[cfg(feature = "projects_users")]
 pub mod project_user;
+#[cfg(feature = "quests")]
+pub mod quest;
+#[cfg(feature = "scales")]
+pub mod scale;
+#[cfg(feature = "scale_teams")]
 pub mod scale_team;
+#[cfg(feature = "skills")]
+pub mod skill;
+#[cfg(feature = "slots")]
+pub mod slot;
+#[cfg(feature = "tags")]
+pub mod tag;
+#[cfg(feature = "teams")]
+pub mod team;
+#[cfg(feature = "titles")]
+pub mod title;
+#[cfg(feature = "transactions")]
+pub mod transaction;
+#[cfg(feature = "translations")]
+pub mod translation;
+#[cfg(feature = "users")]
 pub mod user;
+#[cfg(feature = "user_candidatures")]
+pub mod user_candidature;
+#[cfg(feature = "waitlists")]
+pub mod waitlist;
 
 pub mod prelude;
 
@@ -59,3 +169,13 @@ pub trait HasVec<T> {
     /// Take ownership of the contained vector.
     fn take_vec(self) -> Vec<T>;
 }
+
+impl<T> HasVec<T> for Vec<T> {
+    fn get_vec(&self) -> &Vec<T> {
+        self
+    }
+
+    fn take_vec(self) -> Vec<T> {
+        self
+    }
+}
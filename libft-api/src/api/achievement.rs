@@ -0,0 +1,38 @@
+//! API endpoints related to achievements.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with achievements and the
+//! users who unlocked them.
+//!
+//! # Endpoints
+//!
+//! * **achievements**: Retrieve a list of achievements with filtering, pagination, and sorting options
+//! * **achievements_id_users**: Get the users who unlocked a specific achievement
+//! * **achievements_users**: Retrieve the links between users and achievements
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .achievements_id_users(FtApiAchievementsIdUsersRequest::new(FtAchievementId::new(45)))
+//!         .await?;
+//!     for user in response.users {
+//!         println!("{:?}", user.login);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod achievements;
+pub use achievements::*;
+mod achievements_id_users;
+pub use achievements_id_users::*;
+mod achievements_users;
+pub use achievements_users::*;
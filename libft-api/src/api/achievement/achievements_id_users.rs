@@ -0,0 +1,117 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rvstruct::ValueStruct;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiAchievementsIdUsersRequest {
+    pub achievement_id: FtAchievementId,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiAchievementsIdUsersResponse {
+    pub users: Vec<FtUser>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the users who unlocked a given achievement.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiAchievementsIdUsersRequest` object containing the parameters for the API call.
+    ///     - `achievement_id` (required): `FtAchievementId`
+    ///       The achievement id.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiAchievementsIdUsersResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn achievements_id_users(
+        &self,
+        req: FtApiAchievementsIdUsersRequest,
+    ) -> ClientResult<FtApiAchievementsIdUsersResponse> {
+        let url = &format!("achievements/{}/users", req.achievement_id.value());
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .achievements_id_users(
+                FtApiAchievementsIdUsersRequest::new(FtAchievementId::new(45)).with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,34 @@
+//! API endpoints related to applications.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the OAuth applications
+//! of the intra, along with their scopes and rate limits.
+//!
+//! # Endpoints
+//!
+//! * **apps**: Retrieve a list of applications, optionally owned by one user
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token.clone());
+//!
+//!     let response = session
+//!         .apps(FtApiAppsRequest::new().with_user_id(FtUserId::new(212_750)))
+//!         .await?;
+//!     if let Some(app) = response.apps.first() {
+//!         // Reopen a client tuned to the limits of the application.
+//!         let client = FtClient::with_app_ratelimits(FtClientReqwestConnector::new(), app);
+//!         let _session = client.open_session(token);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod apps;
+pub use apps::*;
@@ -0,0 +1,34 @@
+//! API endpoints related to attachments.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the files attached to
+//! projects and subnotions, and a helper to download them.
+//!
+//! # Endpoints
+//!
+//! * **attachments**: Retrieve a list of attachments, optionally of a given project or subnotion
+//! * **attachments_download**: Download the file of an attachment
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .attachments(FtApiAttachmentsRequest::new().with_project_id(FtProjectId::new(1314)))
+//!         .await?;
+//!     for attachment in response.attachments {
+//!         let file = session.attachments_download(&attachment).await?;
+//!         println!("{}: {} bytes", attachment.name, file.len());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod attachments;
+pub use attachments::*;
@@ -0,0 +1,154 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rvstruct::ValueStruct;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiAttachmentsRequest {
+    pub project_id: Option<FtProjectId>,
+    pub subnotion_id: Option<FtSubnotionId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiAttachmentsResponse {
+    pub attachments: Vec<FtAttachment>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the attachments of the intra, e.g. subject PDFs and e-learning videos.
+    ///
+    /// When `project_id` is set, the attachments of that project are listed
+    /// (`/projects/:project_id/attachments`); otherwise when `subnotion_id` is set, the attachments of
+    /// that subnotion are listed (`/subnotions/:subnotion_id/attachments`).
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiAttachmentsRequest` object containing the parameters for the API call.
+    ///     - `project_id` (optional): `FtProjectId`
+    ///       Only attachments of this project.
+    ///     - `subnotion_id` (optional): `FtSubnotionId`
+    ///       Only attachments of this subnotion.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `name`, `slug`, `kind`, `language_id`, `created_at`, `updated_at`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `name`, `slug`, `kind`, `language_id`, `created_at`, `updated_at`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `name`, `slug`, `kind`, `language_id`, `created_at`, `updated_at`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiAttachmentsResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn attachments(
+        &self,
+        req: FtApiAttachmentsRequest,
+    ) -> ClientResult<FtApiAttachmentsResponse> {
+        let url = &match (&req.project_id, &req.subnotion_id) {
+            (Some(project_id), _) => format!("projects/{project_id}/attachments"),
+            (None, Some(subnotion_id)) => format!("subnotions/{subnotion_id}/attachments"),
+            (None, None) => "attachments".to_string(),
+        };
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Downloads the file of an attachment, e.g. to mirror a subject PDF.
+    ///
+    /// The whole file is buffered in memory.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the attachment has no `url`, or if the HTTP request
+    /// fails.
+    pub async fn attachments_download(&self, attachment: &FtAttachment) -> ClientResult<Vec<u8>> {
+        let url = attachment.url.as_ref().ok_or_else(|| {
+            FtClientError::SystemError(
+                FtSystemError::new()
+                    .with_message(format!("attachment {} has no url", attachment.id.value())),
+            )
+        })?;
+
+        self.http_session_api
+            .http_get_bytes_uri(url.value().parse()?)
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .attachments(
+                FtApiAttachmentsRequest::new()
+                    .with_project_id(FtProjectId::new(1314))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,35 @@
+//! API endpoints related to coalition blocs.
+//!
+//! A bloc groups the coalitions that compete against each other on a campus and cursus. This
+//! module provides access to the blocs and to the scores that make up their standings.
+//!
+//! # Endpoints
+//!
+//! * **blocs**: Retrieve a list of blocs with filtering, pagination, and sorting options
+//! * **blocs_id_scores**: Get the scores awarded to the coalitions of a specific bloc
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .blocs_id_scores(FtApiBlocsIdScoresRequest::new(FtBlocId::new(72)))
+//!         .await?;
+//!     for score in response.scores {
+//!         println!("{:?}: {} ({:?})", score.coalition_id, score.value, score.reason);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod blocs;
+pub use blocs::*;
+mod blocs_id_scores;
+pub use blocs_id_scores::*;
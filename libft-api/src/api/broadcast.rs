@@ -0,0 +1,32 @@
+//! API endpoints related to broadcasts.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the messages broadcast to
+//! the users of a campus.
+//!
+//! # Endpoints
+//!
+//! * **broadcasts**: Retrieve a list of broadcasts, optionally scoped to a campus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .broadcasts(FtApiBroadcastsRequest::new().with_campus_id(FtCampusId::new(69)))
+//!         .await?;
+//!     for broadcast in response.broadcasts {
+//!         println!("{:?}: {:?}", broadcast.title, broadcast.content);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod broadcasts;
+pub use broadcasts::*;
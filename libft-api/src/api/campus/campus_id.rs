@@ -81,11 +81,12 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() -> ClientResult<()> {
         let token = FtApiToken::try_get(AuthInfo::build_from_env()?).await?;
         let client = FtClient::new(FtClientReqwestConnector::with_connector(
@@ -112,12 +112,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
     use crate::info::ft_campus_id::GYEONGSAN;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn location_with_params() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
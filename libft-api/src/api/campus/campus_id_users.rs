@@ -79,41 +79,59 @@ where
     ) -> ClientResult<FtApiCampusIdUsersResponse> {
         let url = &format!("campus/{}/users", req.campus_id);
 
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
-        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+        self.http_session_api
+            .http_get(url, &campus_id_users_params(req))
+            .await
+    }
 
-        let params = vec![
-            to_param!(req, page),
-            to_param!(req, per_page),
-            (
-                "sort".to_string(),
-                req.sort.as_ref().map(|v| {
-                    v.iter()
-                        .map(|v| {
-                            format!(
-                                "{}{}",
-                                if v.descending { "-" } else { "" },
-                                serde_plain::to_string(&v.field).unwrap()
-                            )
-                        })
-                        .collect::<Vec<_>>()
-                        .join(",")
-                }),
-            ),
-        ];
+    /// `campus_id_users`, returning the page undecoded, to deserialize its records one at a time.
+    pub async fn campus_id_users_raw(
+        &self,
+        req: FtApiCampusIdUsersRequest,
+    ) -> ClientResult<FtRawPage<FtUser>> {
+        let url = &format!("campus/{}/users", req.campus_id);
 
         self.http_session_api
-            .http_get(url, &[filters, range, params].concat())
+            .http_get_raw(url, &campus_id_users_params(req))
             .await
     }
 }
 
-#[cfg(test)]
+/// The query parameters of `campus_id_users`.
+fn campus_id_users_params(req: FtApiCampusIdUsersRequest) -> Vec<(String, Option<String>)> {
+    let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+    let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+    let params = vec![
+        to_param!(req, page),
+        to_param!(req, per_page),
+        (
+            "sort".to_string(),
+            req.sort.as_ref().map(|v| {
+                v.iter()
+                    .map(|v| {
+                        format!(
+                            "{}{}",
+                            if v.descending { "-" } else { "" },
+                            serde_plain::to_string(&v.field).unwrap()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        ),
+    ];
+
+    [filters, range, params].concat()
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
     use crate::info::ft_campus_id::GYEONGSAN;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
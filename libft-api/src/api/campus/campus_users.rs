@@ -109,12 +109,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,29 @@
+//! API endpoints related to account closes.
+//!
+//! Closes are created through `users_id_closes_post` in the user module; this module provides the
+//! endpoints acting on an existing close.
+//!
+//! # Endpoints
+//!
+//! * **closes_id_unclose**: Reopen a closed account
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     session
+//!         .closes_id_unclose(FtApiClosesIdUncloseRequest::new(FtCloseId::new(51422)))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod closes_id_unclose;
+pub use closes_id_unclose::*;
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiClosesIdUncloseRequest {
+    pub id: FtCloseId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiClosesIdUncloseResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Reopens an account closed by `users_id_closes_post`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn closes_id_unclose(
+        &self,
+        req: FtApiClosesIdUncloseRequest,
+    ) -> ClientResult<FtApiClosesIdUncloseResponse> {
+        let url = &format!("closes/{}/unclose", req.id.value());
+        let body = serde_json::json!({});
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
@@ -0,0 +1,46 @@
+//! API endpoints related to coalitions.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with coalitions, their
+//! members, and the scores awarded to them.
+//!
+//! # Endpoints
+//!
+//! * **coalitions**: Retrieve a list of coalitions with filtering, pagination, and sorting options
+//! * **coalitions_id_users**: Get the members of a specific coalition
+//! * **coalitions_id_scores_post**: Award points to a coalition
+//! * **coalitions_users**: Retrieve coalition memberships with their score and rank
+//! * **scores_id_delete**: Revoke the points of a score
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .coalitions_users(
+//!             FtApiCoalitionsUsersRequest::new().with_coalition_id(FtCoalitionId::new(310)),
+//!         )
+//!         .await?;
+//!     for coalitions_user in response.coalitions_users {
+//!         println!("{:?}: {:?}", coalitions_user.user_id, coalitions_user.score);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod coalitions;
+pub use coalitions::*;
+mod coalitions_id_scores;
+pub use coalitions_id_scores::*;
+mod coalitions_id_users;
+pub use coalitions_id_users::*;
+mod coalitions_users;
+pub use coalitions_users::*;
+mod scores_id;
+pub use scores_id::*;
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCoalitionsIdScoresPostRequest {
+    pub coalition_id: FtCoalitionId,
+    pub score: FtApiCoalitionsIdScoresPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCoalitionsIdScoresPostBody {
+    /// Why the points are awarded, shown on the coalition page.
+    pub reason: String,
+    /// The amount of points, negative to remove points.
+    pub value: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coalitions_user_id: Option<FtCoalitionsUserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoreable_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoreable_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiCoalitionsIdScoresPostResponse {
+    pub score: FtScore,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Awards points to a coalition.
+    ///
+    /// Set `coalitions_user_id` to credit the points to a specific member of the coalition.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn coalitions_id_scores_post(
+        &self,
+        req: FtApiCoalitionsIdScoresPostRequest,
+    ) -> ClientResult<FtApiCoalitionsIdScoresPostResponse> {
+        let url = &format!("coalitions/{}/scores", req.coalition_id.value());
+        let body = serde_json::json!({ "score": req.score });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body = FtApiCoalitionsIdScoresPostBody::new("Hackathon winner".to_string(), 42)
+            .with_coalitions_user_id(FtCoalitionsUserId::new(123_456));
+
+        assert_eq!(
+            r#"{"reason":"Hackathon winner","value":42,"coalitions_user_id":123456}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScoresIdDeleteRequest {
+    pub id: FtScoreId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiScoresIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Deletes a score, revoking the points it awarded to its coalition.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn scores_id_delete(
+        &self,
+        req: FtApiScoresIdDeleteRequest,
+    ) -> ClientResult<FtApiScoresIdDeleteResponse> {
+        let url = &format!("scores/{}", req.id.value());
+        let body = serde_json::json!({});
+
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
@@ -0,0 +1,37 @@
+//! API endpoints related to community services (TIG).
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with community services,
+//! the disciplinary work assigned to a user after a close.
+//!
+//! # Endpoints
+//!
+//! * **community_services**: Retrieve a list of community services with filtering, pagination, and sorting options
+//! * **closes_id_community_services_post**: Assign a community service to the user of a close
+//! * **community_services_id_patch**: Update a community service, e.g. to validate or invalidate it
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     session
+//!         .community_services_id_patch(FtApiCommunityServicesIdPatchRequest::new(
+//!             FtCommunityServiceId::new(18234),
+//!             FtApiCommunityServicesIdPatchBody::new()
+//!                 .with_state(FtCommunityServiceState::Validated),
+//!         ))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod community_services;
+pub use community_services::*;
+mod community_services_id;
+pub use community_services_id::*;
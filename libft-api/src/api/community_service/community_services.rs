@@ -0,0 +1,166 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rvstruct::ValueStruct;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCommunityServicesRequest {
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiCommunityServicesResponse {
+    pub community_services: Vec<FtCommunityService>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiClosesIdCommunityServicesPostRequest {
+    pub close_id: FtCloseId,
+    pub community_service: FtApiClosesIdCommunityServicesPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiClosesIdCommunityServicesPostBody {
+    /// The duration of the service, in seconds.
+    pub duration: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occupation: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiClosesIdCommunityServicesPostResponse {
+    pub community_service: FtCommunityService,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the community services (TIG) of the whole intra.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiCommunityServicesRequest` object containing the parameters for the API call.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `close_id`, `duration`, `schedule_at`, `occupation`, `state`,
+    ///       `created_at`, `updated_at`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `close_id`, `duration`, `schedule_at`, `occupation`, `state`,
+    ///       `created_at`, `updated_at`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `close_id`, `duration`, `schedule_at`, `occupation`, `state`,
+    ///       `created_at`, `updated_at`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiCommunityServicesResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn community_services(
+        &self,
+        req: FtApiCommunityServicesRequest,
+    ) -> ClientResult<FtApiCommunityServicesResponse> {
+        let url = "community_services";
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Assigns a community service (TIG) to the user of a close.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn closes_id_community_services_post(
+        &self,
+        req: FtApiClosesIdCommunityServicesPostRequest,
+    ) -> ClientResult<FtApiClosesIdCommunityServicesPostResponse> {
+        let url = &format!("closes/{}/community_services", req.close_id.value());
+        let body = serde_json::json!({ "community_service": req.community_service });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body = FtApiClosesIdCommunityServicesPostBody::new(7200)
+            .with_occupation("Cleaning the cluster keyboards".to_string());
+
+        assert_eq!(
+            r#"{"duration":7200,"occupation":"Cleaning the cluster keyboards"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .community_services(FtApiCommunityServicesRequest::new().with_per_page(1))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
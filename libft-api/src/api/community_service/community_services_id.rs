@@ -0,0 +1,63 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCommunityServicesIdPatchRequest {
+    pub id: FtCommunityServiceId,
+    pub community_service: FtApiCommunityServicesIdPatchBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCommunityServicesIdPatchBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<FtCommunityServiceState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occupation: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiCommunityServicesIdPatchResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Updates a community service (TIG).
+    ///
+    /// Setting `state` to `validated` or `invalidated` marks the service as done or failed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn community_services_id_patch(
+        &self,
+        req: FtApiCommunityServicesIdPatchRequest,
+    ) -> ClientResult<FtApiCommunityServicesIdPatchResponse> {
+        let url = &format!("community_services/{}", req.id.value());
+        let body = serde_json::json!({ "community_service": req.community_service });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patch_body_serde() {
+        let body =
+            FtApiCommunityServicesIdPatchBody::new().with_state(FtCommunityServiceState::Validated);
+
+        assert_eq!(
+            r#"{"state":"validated"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -1,11 +1,14 @@
 //! API endpoints related to cursus information.
 //!
 //! This module provides access to the 42 Intra API endpoints that deal with curriculum data.
-//! It includes functionality for retrieving information about projects associated with specific cursus.
+//! It includes functionality for listing cursus and retrieving the projects and users associated with a
+//! specific cursus.
 //!
 //! # Endpoints
 //!
+//! * **cursus**: Retrieve a list of cursus with filtering, pagination, and sorting options
 //! * **cursus_id_projects**: Retrieve projects associated with a specific cursus by its ID
+//! * **cursus_id_users**: Retrieve the users of a specific cursus, by campus and level range
 //!
 //! # Example
 //!
@@ -27,5 +30,9 @@
 //! }
 //! ```
 
+mod cursus_list;
+pub use cursus_list::*;
 mod cursus_id_projects;
 pub use cursus_id_projects::*;
+mod cursus_id_users;
+pub use cursus_id_users::*;
@@ -28,12 +28,15 @@ where
 {
     /// Retrieves projects associated with a specific cursus from the 42 Intra API.
     ///
+    /// Each project carries its `parent`, `children` and `difficulty`, which is enough to build the
+    /// project graph of the cursus.
+    ///
     /// # Parameters
     /// - `req`: A `FtApiCursusIdProjectsRequest` struct containing the query parameters.
     ///
     /// # Query Parameters
     /// - `cursus_id`: The ID of the cursus to retrieve projects for (required)
-    /// - `project_id`: Optional project ID to filter results
+    /// - `project_id`: Optional project ID to filter results, a shortcut for `filter[id]`
     /// - `sort`: Optional vector of sort options
     /// - `range`: Optional vector of range options
     /// - `filter`: Optional vector of filter options
@@ -44,7 +47,7 @@ where
     /// - `ClientResult<FtApiCursusIdProjectsResponse>`: Contains a vector of `FtProject` objects
     ///
     /// # Example
-    /// ```rust                                                                                  
+    /// ```rust,no_run                                                                                  
     /// use libft_api::{prelude::*, info::ft_campus_id::GYEONGSAN};                              
     ///                                                                                          
     /// # async fn run() -> ClientResult<()> {                                                   
@@ -74,8 +77,16 @@ where
     ) -> ClientResult<FtApiCursusIdProjectsResponse> {
         let url = &format!("cursus/{}/projects", req.cursus_id);
 
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(project_id) = req.project_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Id,
+                vec![project_id.to_string()],
+            ));
+        }
+
         let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
 
         let params = vec![
             to_param!(req, page),
@@ -103,11 +114,12 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,143 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCursusIdUsersRequest {
+    pub cursus_id: FtCursusId,
+    pub level: Option<(f64, f64)>,
+    pub campus_id: Option<FtCampusId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiCursusIdUsersResponse {
+    pub users: Vec<FtUser>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the users enrolled in a cursus.
+    ///
+    /// `campus_id` is a shortcut for the `filter[campus_id]` option and `level` for the
+    /// `range[level]` option, e.g. `(10.0, 30.0)` for the users between level 10 and 30 in the cursus.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiCursusIdUsersRequest` object containing the parameters for the API call.
+    ///     - `cursus_id` (required): `FtCursusId`
+    ///       The cursus id.
+    ///     - `level` (optional): `(f64, f64)`
+    ///       Only users whose level in the cursus is within this inclusive range.
+    ///     - `campus_id` (optional): `FtCampusId`
+    ///       Only users of this campus.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiCursusIdUsersResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn cursus_id_users(
+        &self,
+        req: FtApiCursusIdUsersRequest,
+    ) -> ClientResult<FtApiCursusIdUsersResponse> {
+        let url = &format!("cursus/{}/users", req.cursus_id);
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CampusId,
+                vec![campus_id.to_string()],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let mut range = req.range.unwrap_or_default();
+        if let Some((min, max)) = req.level {
+            range.push(FtRangeOption::new(
+                FtRangeField::Level,
+                vec![min.to_string(), max.to_string()],
+            ));
+        }
+        let range = convert_range_option_to_tuple(range).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .cursus_id_users(
+                FtApiCursusIdUsersRequest::new(FtCursusId::new(FT_CURSUS_ID))
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_level((10.0, 30.0))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,43 @@
+//! API endpoints related to cursus user associations.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the enrollment of users
+//! into a cursus. It includes functionality for listing cursus users across the intra, enrolling a
+//! user into a cursus and updating an existing enrollment.
+//!
+//! # Endpoints
+//!
+//! * **cursus_users**: Retrieve a list of cursus users with filtering, pagination, and sorting options
+//! * **cursus_users_post**: Enroll a user into a cursus
+//! * **cursus_users_id_patch**: Update an existing cursus user (begin_at, end_at, blackholed_at, ...)
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::{prelude::*, info::ft_campus_id::GYEONGSAN};
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     // Get the active 42cursus students of a campus
+//!     let response = session
+//!         .cursus_users(
+//!             FtApiCursusUsersRequest::new()
+//!                 .with_filter(vec![
+//!                     FtFilterOption::new(FtFilterField::CampusId, vec![GYEONGSAN.to_string()]),
+//!                     FtFilterOption::new(FtFilterField::Active, vec!["true".to_string()]),
+//!                 ])
+//!                 .with_per_page(100),
+//!         )
+//!         .await?;
+//!     println!("Found {} cursus users", response.cursus_users.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod cursus_users;
+pub use cursus_users::*;
+mod cursus_users_id;
+pub use cursus_users_id::*;
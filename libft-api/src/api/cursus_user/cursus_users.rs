@@ -0,0 +1,162 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCursusUsersRequest {
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiCursusUsersResponse {
+    pub cursus_users: Vec<FtCursusUser>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiCursusUsersPostResponse {
+    pub cursus_user: FtCursusUser,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the cursus users of the whole intra.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiCursusUsersRequest` object containing the parameters for the API call.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `cursus_id`, `user_id`, `created_at`, `updated_at`, `end_at`,
+    ///       `begin_at`, `has_coalition`, `blackholed_at`, `level`.
+    ///       The sort field. Sorted by `created_at` desc, `id` desc by default.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `cursus_id`, `user_id`, `created_at`, `updated_at`, `end_at`,
+    ///       `begin_at`, `has_coalition`, `blackholed_at`, `level`, `active`, `campus_id`, `end`,
+    ///       `future`, `blackholed`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `cursus_id`, `user_id`, `created_at`, `updated_at`, `end_at`,
+    ///       `begin_at`, `has_coalition`, `blackholed_at`, `level`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiCursusUsersResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn cursus_users(
+        &self,
+        req: FtApiCursusUsersRequest,
+    ) -> ClientResult<FtApiCursusUsersResponse> {
+        let url = "cursus_users";
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Enrolls a user into a cursus.
+    ///
+    /// The enrollment starts at `begin_at`. Requires a role allowed to manage cursus users.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn cursus_users_post(
+        &self,
+        req: FtApiUsersIdCursusUsersPostRequest,
+    ) -> ClientResult<FtApiCursusUsersPostResponse> {
+        let url = "cursus_users";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::info::ft_campus_id::GYEONGSAN;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiUsersIdCursusUsersPostRequest::new(FtApiCursusUsersBody::new(
+            FtCursusId::new(9),
+            FtUserId::new(212_750),
+            "2025-01-06T00:42:00Z".to_string(),
+            false,
+        ));
+
+        let raw = r#"{"cursus_user":{"cursus_id":9,"user_id":212750,"begin_at":"2025-01-06T00:42:00Z","has_coalition":false}}"#;
+
+        assert_eq!(raw, serde_json::to_string(&req).unwrap());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .cursus_users(
+                FtApiCursusUsersRequest::new()
+                    .with_filter(vec![FtFilterOption::new(
+                        FtFilterField::CampusId,
+                        vec![GYEONGSAN.to_string()],
+                    )])
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
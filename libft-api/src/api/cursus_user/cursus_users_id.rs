@@ -0,0 +1,64 @@
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCursusUsersIdPatchRequest {
+    pub id: FtCursusUserId,
+    pub cursus_user: FtApiCursusUsersIdPatchBody,
+}
+
+/// Fields of a cursus user that can be updated. Unset fields are left untouched.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiCursusUsersIdPatchBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blackholed_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_coalition: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiCursusUsersIdPatchResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Updates a cursus user.
+    ///
+    /// Only the fields set on `FtApiCursusUsersIdPatchBody` are sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn cursus_users_id_patch(
+        &self,
+        req: FtApiCursusUsersIdPatchRequest,
+    ) -> ClientResult<FtApiCursusUsersIdPatchResponse> {
+        let url = &format!("cursus_users/{}", req.id.value());
+        let body = serde_json::json!({ "cursus_user": req.cursus_user });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patch_body_serde() {
+        let body = FtApiCursusUsersIdPatchBody::new().with_has_coalition(true);
+
+        assert_eq!(
+            r#"{"has_coalition":true}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! API endpoints related to campus events.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with events, so recurring
+//! campus events can be provisioned from code.
+//!
+//! # Endpoints
+//!
+//! * **events_post**: Create an event
+//! * **events_id_patch**: Update an event
+//! * **events_id_events_users_post**: Subscribe a user to an event
+//! * **events_users_id_delete**: Unsubscribe a user from an event
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{TimeDelta, Utc};
+//! use libft_api::{prelude::*, info::ft_campus_id::GYEONGSAN};
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let begin_at = Utc::now() + TimeDelta::days(7);
+//!     let response = session
+//!         .events_post(FtApiEventsPostRequest::new(
+//!             FtApiEventsPostBody::new(
+//!                 "Weekly meetup".to_string(),
+//!                 "Come and share your projects".to_string(),
+//!                 "Cluster 1".to_string(),
+//!                 FtEventKind::new("meet_up".to_string()),
+//!                 FtDateTimeUtc::new(begin_at),
+//!                 FtDateTimeUtc::new(begin_at + TimeDelta::hours(2)),
+//!             )
+//!             .with_max_people(40)
+//!             .with_campus_ids(vec![FtCampusId::new(GYEONGSAN)]),
+//!         ))
+//!         .await?;
+//!     println!("Created event {}", response.event.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod events;
+pub use events::*;
+mod events_id;
+pub use events_id::*;
+mod events_users;
+pub use events_users::*;
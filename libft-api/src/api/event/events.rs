@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsPostRequest {
+    pub event: FtApiEventsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsPostBody {
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    pub kind: FtEventKind,
+    pub begin_at: FtDateTimeUtc,
+    pub end_at: FtDateTimeUtc,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_people: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campus_ids: Option<Vec<FtCampusId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursus_ids: Option<Vec<FtCursusId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiEventsPostResponse {
+    pub event: FtEvent,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Creates an event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn events_post(
+        &self,
+        req: FtApiEventsPostRequest,
+    ) -> ClientResult<FtApiEventsPostResponse> {
+        let url = "events";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiEventsPostRequest::new(
+            FtApiEventsPostBody::new(
+                "Welcome breakfast".to_string(),
+                "Meet the new cohort".to_string(),
+                "Cluster 1".to_string(),
+                FtEventKind::new("association".to_string()),
+                FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 3, 3, 0, 30, 0).unwrap()),
+                FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 3, 3, 1, 30, 0).unwrap()),
+            )
+            .with_campus_ids(vec![FtCampusId::new(69)]),
+        );
+
+        assert_eq!(
+            r#"{"event":{"name":"Welcome breakfast","description":"Meet the new cohort","location":"Cluster 1","kind":"association","begin_at":"2025-03-03T00:30:00Z","end_at":"2025-03-03T01:30:00Z","campus_ids":[69]}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsIdPatchRequest {
+    pub id: FtEventId,
+    pub event: FtApiEventsIdPatchBody,
+}
+
+/// Fields of an event that can be updated. Unset fields are left untouched.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsIdPatchBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<FtEventKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_people: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campus_ids: Option<Vec<FtCampusId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursus_ids: Option<Vec<FtCursusId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiEventsIdPatchResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Updates an event.
+    ///
+    /// Only the fields set on `FtApiEventsIdPatchBody` are sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn events_id_patch(
+        &self,
+        req: FtApiEventsIdPatchRequest,
+    ) -> ClientResult<FtApiEventsIdPatchResponse> {
+        let url = &format!("events/{}", req.id.value());
+        let body = serde_json::json!({ "event": req.event });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
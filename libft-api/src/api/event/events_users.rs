@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsIdEventsUsersPostRequest {
+    pub event_id: FtEventId,
+    pub events_user: FtApiEventsIdEventsUsersPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsIdEventsUsersPostBody {
+    pub user_id: FtUserId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiEventsIdEventsUsersPostResponse {
+    pub events_user: FtEventsUser,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiEventsUsersIdDeleteRequest {
+    pub id: FtEventsUserId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiEventsUsersIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Subscribes a user to an event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn events_id_events_users_post(
+        &self,
+        req: FtApiEventsIdEventsUsersPostRequest,
+    ) -> ClientResult<FtApiEventsIdEventsUsersPostResponse> {
+        let url = &format!("events/{}/events_users", req.event_id.value());
+        let body = serde_json::json!({ "events_user": req.events_user });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+
+    /// Unsubscribes a user from an event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn events_users_id_delete(
+        &self,
+        req: FtApiEventsUsersIdDeleteRequest,
+    ) -> ClientResult<FtApiEventsUsersIdDeleteResponse> {
+        let url = &format!("events_users/{}", req.id.value());
+        let body = serde_json::json!({});
+
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
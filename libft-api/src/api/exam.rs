@@ -1,12 +1,14 @@
 //! API endpoints related to exam information.
 //!
 //! This module provides access to the 42 Intra API endpoints that deal with exam data.
-//! It includes functionality for retrieving exam information and managing exam-user associations.
+//! It includes functionality for retrieving exam information and managing exam-user associations
+//! (registering and unregistering students).
 //!
 //! # Endpoints
 //!
 //! * **exams**: Retrieve a list of exams with filtering, pagination, and sorting options
 //! * **exams_users_post**: Create an association between a user and an exam
+//! * **exams_users_id_delete**: Remove an association between a user and an exam
 //!
 //! # Example
 //!
@@ -38,3 +40,5 @@
 
 mod exams;
 pub use exams::*;
+mod exams_users_id;
+pub use exams_users_id::*;
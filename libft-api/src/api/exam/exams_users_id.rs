@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiExamsUsersIdDeleteRequest {
+    pub id: FtExamUserId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiExamsUsersIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Unregisters a user from an exam.
+    pub async fn exams_users_id_delete(
+        &self,
+        req: FtApiExamsUsersIdDeleteRequest,
+    ) -> ClientResult<FtApiExamsUsersIdDeleteResponse> {
+        let url = &format!("exams_users/{}", req.id.value());
+        let body = serde_json::json!({});
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
@@ -0,0 +1,32 @@
+//! API endpoints related to experience points.
+//!
+//! This module provides access to the 42 Intra API endpoints that grant experience points (XP)
+//! to users.
+//!
+//! # Endpoints
+//!
+//! * **experiences_post**: Grant experience points to a user in a cursus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     session
+//!         .experiences_post(FtApiExperiencesPostRequest::new(
+//!             FtApiExperiencesPostBody::new(FtUserId::new(212_750), FtCursusId::new(21), 1000)
+//!                 .with_reason("Hackathon winner".to_string()),
+//!         ))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod experiences;
+pub use experiences::*;
@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiExperiencesPostRequest {
+    pub experience: FtApiExperiencesPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiExperiencesPostBody {
+    pub user_id: FtUserId,
+    pub cursus_id: FtCursusId,
+    pub amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_id: Option<FtSkillId>,
+    /// The id of the object granting the experience, e.g. an event id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experiancable_id: Option<i64>,
+    /// The type of the object granting the experience, e.g. `Event`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experiancable_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiExperiencesPostResponse {
+    pub experience: FtExperience,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Grants experience points to a user in a cursus.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn experiences_post(
+        &self,
+        req: FtApiExperiencesPostRequest,
+    ) -> ClientResult<FtApiExperiencesPostResponse> {
+        let url = "experiences";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiExperiencesPostRequest::new(
+            FtApiExperiencesPostBody::new(
+                FtUserId::new(212_750),
+                FtCursusId::new(crate::info::FT_CURSUS_ID),
+                1000,
+            )
+            .with_experiancable_id(29_107)
+            .with_experiancable_type("Event".to_string()),
+        );
+
+        assert_eq!(
+            format!(
+                r#"{{"experience":{{"user_id":212750,"cursus_id":{},"amount":1000,"experiancable_id":29107,"experiancable_type":"Event"}}}}"#,
+                crate::info::FT_CURSUS_ID
+            ),
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! API endpoints related to feedbacks.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the feedbacks left
+//! after an evaluation, e.g. to compute the average rating of a corrector.
+//!
+//! # Endpoints
+//!
+//! * **feedbacks**: Retrieve a list of feedbacks with user/scale team filters, pagination, and sorting options
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//! use rvstruct::ValueStruct;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .feedbacks(FtApiFeedbacksRequest::new().with_user_id(FtUserId::new(12345)))
+//!         .await?;
+//!     let ratings: Vec<i32> = response
+//!         .feedbacks
+//!         .iter()
+//!         .filter_map(|feedback| feedback.rating.as_ref().map(|rating| *rating.value()))
+//!         .collect();
+//!     println!("{} ratings", ratings.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod feedbacks;
+pub use feedbacks::*;
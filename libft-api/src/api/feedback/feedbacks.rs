@@ -0,0 +1,125 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiFeedbacksRequest {
+    pub user_id: Option<FtUserId>,
+    pub scale_team_id: Option<FtScaleTeamId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiFeedbacksResponse {
+    pub feedbacks: Vec<FtFeedback>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the feedbacks of the whole intra.
+    ///
+    /// `user_id` and `scale_team_id` are shortcuts for `filter[user_id]` and for the
+    /// `filter[feedbackable_type]=ScaleTeam` / `filter[feedbackable_id]` pair.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiFeedbacksRequest` struct containing the query parameters.
+    ///     - `user_id` (optional): Only feedbacks written by this user.
+    ///     - `scale_team_id` (optional): Only feedbacks left on this scale team.
+    ///     - `sort`, `range`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiFeedbacksResponse>`: Contains a vector of `FtFeedback` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn feedbacks(
+        &self,
+        req: FtApiFeedbacksRequest,
+    ) -> ClientResult<FtApiFeedbacksResponse> {
+        let url = "feedbacks";
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(user_id) = req.user_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::UserId,
+                vec![user_id.to_string()],
+            ));
+        }
+        if let Some(scale_team_id) = req.scale_team_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::FeedbackableType,
+                vec!["ScaleTeam".to_string()],
+            ));
+            filter.push(FtFilterOption::new(
+                FtFilterField::FeedbackableId,
+                vec![scale_team_id.to_string()],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .feedbacks(
+                FtApiFeedbacksRequest::new()
+                    .with_user_id(FtUserId::new(crate::info::TEST_USER_YONDOO_ID))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,32 @@
+//! API endpoints related to gitlab users.
+//!
+//! This module provides access to the 42 Intra API endpoints that link intra users to their
+//! vogsphere gitlab accounts.
+//!
+//! # Endpoints
+//!
+//! * **gitlab_users**: Retrieve a list of gitlab accounts, optionally for one user
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .gitlab_users(FtApiGitlabUsersRequest::new().with_per_page(100))
+//!         .await?;
+//!     for gitlab_user in response.gitlab_users {
+//!         println!("{:?} -> {:?}", gitlab_user.user_id, gitlab_user.gitlab_id);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod gitlab_users;
+pub use gitlab_users::*;
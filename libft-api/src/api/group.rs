@@ -7,6 +7,7 @@
 //!
 //! * **groups**: Retrieve a list of groups with optional filtering by user ID and pagination options
 //! * **groups_users_post**: Create an association between a user and a group
+//! * **groups_users_id_delete**: Remove an association between a user and a group
 //!
 //! # Example
 //!
@@ -41,3 +42,5 @@
 
 mod groups;
 pub use groups::*;
+mod groups_users;
+pub use groups_users::*;
@@ -8,6 +8,9 @@ use libft_api_derive::HasVector;
 #[derive(Debug, Serialize, Deserialize, Builder)]
 pub struct FtApiGroupsRequest {
     pub user_id: Option<FtUserId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
     pub page: Option<u16>,
     pub per_page: Option<u8>,
 }
@@ -17,7 +20,7 @@ pub struct FtApiGroupsUsersPostRequest {
     pub groups_user: FtApiGroupsUsersPostBody,
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize, Builder)]
 pub struct FtApiGroupsUsersPostBody {
     pub group_id: FtGroupId,
     pub user_id: FtUserId,
@@ -25,7 +28,7 @@ pub struct FtApiGroupsUsersPostBody {
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
 pub struct FtApiGroupsUsersPostResponse {
-    pub id: i32,
+    pub id: FtGroupsUserId,
     pub user_id: FtUserId,
     pub group: FtGroup,
 }
@@ -47,12 +50,19 @@ where
     ///
     /// # Query Parameters
     /// - `user_id`: Optional user ID to filter groups associated with a specific user
+    /// - `sort`: Optional sort fields, one of `id`, `name`, `created_at`, `updated_at`
+    /// - `range`: Optional ranges, on `id`, `name`, `created_at`, `updated_at`
+    /// - `filter`: Optional filters, on `id`, `name`, `created_at`, `updated_at`
     /// - `page`: Optional page number for pagination
     /// - `per_page`: Optional number of items per page for pagination
     ///
     /// # Returns
     /// - `ClientResult<FtApiGroupsResponse>`: Contains a vector of `FtGroup` objects
     ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    ///
     /// # Example
     /// ```rust
     /// use libft_api::prelude::*;
@@ -77,13 +87,33 @@ where
     pub async fn groups(&self, req: FtApiGroupsRequest) -> ClientResult<FtApiGroupsResponse> {
         let url = "groups";
 
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
         let params = vec![
             to_param!(req, page),
             to_param!(req, per_page),
             to_param!(req, user_id),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
         ];
 
-        self.http_session_api.http_get(url, &params).await
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
     }
 
     /// Creates a group-user association in the 42 Intra API.
@@ -125,11 +155,26 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiGroupsUsersPostRequest::new(FtApiGroupsUsersPostBody::new(
+            FtGroupId::new(FT_GROUP_ID_TEST_ACCOUNT),
+            FtUserId::new(212_750),
+        ));
+
+        assert_eq!(
+            format!(
+                r#"{{"groups_user":{{"group_id":{FT_GROUP_ID_TEST_ACCOUNT},"user_id":212750}}}}"#
+            ),
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+
     // #[tokio::test]
     // async fn post_groups() {
     //     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
@@ -154,6 +199,7 @@ mod tests {
     // }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn get_groups() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiGroupsUsersIdDeleteRequest {
+    pub id: FtGroupsUserId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiGroupsUsersIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Removes a user from a group by deleting the group-user association.
+    ///
+    /// The association id is the `id` returned by `groups_users_post`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn groups_users_id_delete(
+        &self,
+        req: FtApiGroupsUsersIdDeleteRequest,
+    ) -> ClientResult<FtApiGroupsUsersIdDeleteResponse> {
+        let url = &format!("groups_users/{}", req.id.value());
+        let body = serde_json::json!({});
+
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
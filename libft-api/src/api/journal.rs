@@ -0,0 +1,36 @@
+//! API endpoints related to journals.
+//!
+//! Journals are the activity log of the intra: every change to a team, a close, an evaluation and
+//! so on is recorded with the user it concerns. Campus-scoped journals are available through
+//! `campus_id_journals` in the campus module.
+//!
+//! # Endpoints
+//!
+//! * **journals**: Retrieve the journals of the whole intra over a date range
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .journals(
+//!             FtApiJournalsRequest::new("2025-01-01".to_string(), "2025-01-31".to_string())
+//!                 .with_user_id(FtUserId::new(212_750)),
+//!         )
+//!         .await?;
+//!     for journal in response.journals {
+//!         println!("{:?} {:?}: {:?}", journal.event_at, journal.item_type, journal.reason);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod journals;
+pub use journals::*;
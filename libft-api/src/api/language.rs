@@ -0,0 +1,30 @@
+//! API endpoints related to languages.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the languages of the
+//! intra, globally or for a given campus.
+//!
+//! # Endpoints
+//!
+//! * **languages**: Retrieve a list of languages, optionally scoped to a campus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session.languages(FtApiLanguagesRequest::new()).await?;
+//!     for language in response.languages {
+//!         println!("{:?}: {}", language.id, language.identifier);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod languages;
+pub use languages::*;
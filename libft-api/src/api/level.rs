@@ -0,0 +1,36 @@
+//! API endpoints related to levels.
+//!
+//! This module provides access to the 42 Intra API endpoints that expose the XP table, i.e. the
+//! experience needed to reach each level of a cursus.
+//!
+//! # Endpoints
+//!
+//! * **levels**: Retrieve the XP table, optionally of a given cursus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .levels(
+//!             FtApiLevelsRequest::new()
+//!                 .with_cursus_id(FtCursusId::new(21))
+//!                 .with_per_page(100),
+//!         )
+//!         .await?;
+//!     for level in response.levels {
+//!         println!("level {}: {} xp", level.lvl, level.xp);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod levels;
+pub use levels::*;
@@ -0,0 +1,40 @@
+//! API endpoints related to locations (workstation sessions).
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with locations, globally or
+//! for a given campus.
+//!
+//! # Endpoints
+//!
+//! * **locations**: Retrieve a list of locations with active/host/user filters, pagination, and sorting options
+//! * **locations_id_close**: End an active location
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     // Which workstation is this student on right now?
+//!     let response = session
+//!         .locations(
+//!             FtApiLocationsRequest::new()
+//!                 .with_user_id(FtUserId::new(12345))
+//!                 .with_active(true),
+//!         )
+//!         .await?;
+//!     if let Some(location) = response.locations.first() {
+//!         println!("Logged on {}", location.host);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod locations;
+pub use locations::*;
+mod locations_id;
+pub use locations_id::*;
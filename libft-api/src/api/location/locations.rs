@@ -0,0 +1,152 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiLocationsRequest {
+    pub campus_id: Option<FtCampusId>,
+    pub user_id: Option<FtUserId>,
+    pub active: Option<bool>,
+    pub host: Option<FtHost>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiLocationsResponse {
+    pub locations: Vec<FtLocation>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves locations, globally or for a single campus.
+    ///
+    /// When `campus_id` is set, the locations of that campus are listed (`/campus/:campus_id/locations`).
+    /// `user_id`, `active` and `host` are shortcuts for `filter[user_id]`, `filter[active]` and
+    /// `filter[host]`, so "which workstation is this student on right now" is a single call.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiLocationsRequest` struct containing the query parameters.
+    ///     - `campus_id` (optional): Only locations of this campus.
+    ///     - `user_id` (optional): Only locations of this user.
+    ///     - `active` (optional): Only ongoing (`true`) or ended (`false`) locations.
+    ///     - `host` (optional): Only locations on this workstation.
+    ///     - `sort`, `range`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiLocationsResponse>`: Contains a vector of `FtLocation` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn locations(
+        &self,
+        req: FtApiLocationsRequest,
+    ) -> ClientResult<FtApiLocationsResponse> {
+        let url = &match &req.campus_id {
+            Some(campus_id) => format!("campus/{campus_id}/locations"),
+            None => "locations".to_string(),
+        };
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(user_id) = req.user_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::UserId,
+                vec![user_id.to_string()],
+            ));
+        }
+        if let Some(active) = req.active {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Active,
+                vec![active.to_string()],
+            ));
+        }
+        if let Some(host) = req.host {
+            filter.push(FtFilterOption::new(FtFilterField::Host, vec![host.0]));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .locations(FtApiLocationsRequest::new().with_per_page(1))
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn active_on_campus() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .locations(
+                FtApiLocationsRequest::new()
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_active(true)
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
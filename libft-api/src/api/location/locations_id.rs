@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use chrono::Utc;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiLocationsIdCloseRequest {
+    pub id: FtLocationId,
+    /// When the location ends, now if unset.
+    pub end_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiLocationsIdCloseResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Ends an active location, e.g. a ghost session left behind by a crashed workstation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn locations_id_close(
+        &self,
+        req: FtApiLocationsIdCloseRequest,
+    ) -> ClientResult<FtApiLocationsIdCloseResponse> {
+        let url = &format!("locations/{}", req.id.value());
+        let end_at = req.end_at.unwrap_or_else(|| FtDateTimeUtc::new(Utc::now()));
+        let body = serde_json::json!({ "location": { "end_at": end_at } });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::test_support::*;
+    use chrono::TimeZone;
+
+    #[tokio::test]
+    async fn close_location_offline() {
+        let mock = FtMockServer::start().await;
+        mock.mock_json("PATCH", "locations/42", serde_json::json!({}))
+            .await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let end_at = FtDateTimeUtc::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        let res = session
+            .locations_id_close(
+                FtApiLocationsIdCloseRequest::new(FtLocationId::new(42)).with_end_at(end_at),
+            )
+            .await;
+        assert!(res.is_ok(), "Failed to close location: {:?}", res);
+
+        let requests = mock.server().received_requests().await.unwrap();
+        let patch = requests
+            .iter()
+            .find(|req| req.method.as_str() == "PATCH")
+            .unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&patch.body).unwrap(),
+            serde_json::json!({ "location": { "end_at": "2024-03-01T12:00:00Z" } })
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! API endpoints related to notes.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the administrative
+//! notes left on user profiles.
+//!
+//! # Endpoints
+//!
+//! * **notes_post**: Leave a note on a user's profile
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .notes_post(FtApiNotesPostRequest::new(FtApiNotesPostBody::new(
+//!             FtUserId::new(212_750),
+//!             "Absence".to_string(),
+//!             "No location logged for 14 days.".to_string(),
+//!         )))
+//!         .await?;
+//!     println!("Created note {:?}", response.note.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod notes;
+pub use notes::*;
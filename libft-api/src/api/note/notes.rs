@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiNotesPostRequest {
+    pub note: FtApiNotesPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiNotesPostBody {
+    pub user_id: FtUserId,
+    pub subject: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<FtNoteKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campus_id: Option<FtCampusId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiNotesPostResponse {
+    pub note: FtNote,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Leaves an administrative note on a user's profile.
+    ///
+    /// Notes are visible to the staff only, which makes them a good audit trail for automated
+    /// warnings.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn notes_post(
+        &self,
+        req: FtApiNotesPostRequest,
+    ) -> ClientResult<FtApiNotesPostResponse> {
+        let url = "notes";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiNotesPostRequest::new(
+            FtApiNotesPostBody::new(
+                FtUserId::new(212_750),
+                "Absence".to_string(),
+                "No location logged for 14 days.".to_string(),
+            )
+            .with_kind(FtNoteKind::new("warning".to_string())),
+        );
+
+        assert_eq!(
+            r#"{"note":{"user_id":212750,"subject":"Absence","content":"No location logged for 14 days.","kind":"warning"}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+}
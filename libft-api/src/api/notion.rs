@@ -0,0 +1,37 @@
+//! API endpoints related to e-learning notions.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the e-learning
+//! content: notions, their subnotions, and the videos and attachments of each subnotion.
+//!
+//! # Endpoints
+//!
+//! * **notions**: Retrieve a list of notions, optionally of a given cursus
+//! * **notions_id_subnotions**: Get the subnotions of a specific notion with their attachments
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .notions_id_subnotions(FtApiNotionsIdSubnotionsRequest::new(FtNotionId::new(40)))
+//!         .await?;
+//!     for subnotion in response.subnotions {
+//!         for attachment in subnotion.attachments.unwrap_or_default() {
+//!             println!("{} / {} ({:?})", subnotion.name, attachment.name, attachment.kind);
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod notions;
+pub use notions::*;
+mod notions_id_subnotions;
+pub use notions_id_subnotions::*;
@@ -0,0 +1,31 @@
+//! API endpoints related to offers.
+//!
+//! This module provides access to the 42 Intra API endpoints of the campus job board, so that it
+//! can be kept in sync with an external recruiting system.
+//!
+//! # Endpoints
+//!
+//! * **offers**: Retrieve a list of job and internship offers
+//! * **offers_post**: Publish a new offer
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session.offers(FtApiOffersRequest::new()).await?;
+//!     for offer in response.offers {
+//!         println!("{}: {:?}", offer.title, offer.contract_type);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod offers;
+pub use offers::*;
@@ -0,0 +1,171 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiOffersRequest {
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiOffersResponse {
+    pub offers: Vec<FtOffer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiOffersPostRequest {
+    pub offer: FtApiOffersPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiOffersPostBody {
+    pub title: String,
+    pub little_description: String,
+    pub big_description: String,
+    pub contract_type: FtOfferContractType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiOffersPostResponse {
+    pub offer: FtOffer,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the offers of the campus job board.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiOffersRequest` object containing the parameters for the API call.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `title`, `contract_type`, `valid_at`, `invalid_at`, `created_at`, `updated_at`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `title`, `contract_type`, `valid_at`, `invalid_at`, `created_at`, `updated_at`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `title`, `contract_type`, `valid_at`, `invalid_at`, `created_at`, `updated_at`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiOffersResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn offers(&self, req: FtApiOffersRequest) -> ClientResult<FtApiOffersResponse> {
+        let url = "offers";
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Publishes an offer on the campus job board.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn offers_post(
+        &self,
+        req: FtApiOffersPostRequest,
+    ) -> ClientResult<FtApiOffersPostResponse> {
+        let url = "offers";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiOffersPostRequest::new(FtApiOffersPostBody::new(
+            "Backend developer intern".to_string(),
+            "Six month internship".to_string(),
+            "Rust and PostgreSQL on a payments platform.".to_string(),
+            FtOfferContractType::Internship,
+        ));
+
+        assert_eq!(
+            r#"{"offer":{"title":"Backend developer intern","little_description":"Six month internship","big_description":"Rust and PostgreSQL on a payments platform.","contract_type":"internship"}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .offers(FtApiOffersRequest::new().with_per_page(1))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,32 @@
+//! API endpoints related to pools.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the point pools of each
+//! campus and cursus.
+//!
+//! # Endpoints
+//!
+//! * **pools**: Retrieve a list of pools, optionally for one campus or cursus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .pools(FtApiPoolsRequest::new().with_campus_id(FtCampusId::new(69)))
+//!         .await?;
+//!     for pool in response.pools {
+//!         println!("{:?}: {}/{}", pool.id, pool.current_points, pool.max_points);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod pools;
+pub use pools::*;
@@ -0,0 +1,137 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiPoolsRequest {
+    pub campus_id: Option<FtCampusId>,
+    pub cursus_id: Option<FtCursusId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiPoolsResponse {
+    pub pools: Vec<FtPool>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the pools of the intra.
+    ///
+    /// The `campus_id` and `cursus_id` shortcuts are pushed onto `filter`, so that the pool of a
+    /// campus can be resolved by id instead of being hard-coded.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiPoolsRequest` object containing the parameters for the API call.
+    ///     - `campus_id` (optional): `FtCampusId`
+    ///       Only pools of this campus.
+    ///     - `cursus_id` (optional): `FtCursusId`
+    ///       Only pools of this cursus.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `current_points`, `max_points`, `cursus_id`, `campus_id`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `current_points`, `max_points`, `cursus_id`, `campus_id`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `current_points`, `max_points`, `cursus_id`, `campus_id`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiPoolsResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn pools(&self, req: FtApiPoolsRequest) -> ClientResult<FtApiPoolsResponse> {
+        let url = "pools";
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CampusId,
+                vec![campus_id.to_string()],
+            ));
+        }
+        if let Some(cursus_id) = req.cursus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CursusId,
+                vec![cursus_id.to_string()],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .pools(
+                FtApiPoolsRequest::new()
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN)),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
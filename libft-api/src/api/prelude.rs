@@ -1,8 +1,10 @@
 //! The prelude module for API endpoints in the `libft-api` crate.
 //!
 //! This module provides convenient glob imports for all API endpoint types, requests, and responses
-//! from the various API domain modules (campus, cursus, exam, group, project, project_session,
-//! project_user, scale_team, and user). By importing everything in this module, users can access
+//! from the various API domain modules (achievement, app, attachment, bloc, broadcast, campus, close, coalition, community_service,
+//! cursus, cursus_user, event, exam, experience, feedback, gitlab_user, group, journal, language, level, location, note, notion, offer,
+//! pool, product, project, project_session, project_user, quest, scale, scale_team, skill, slot, tag, team, title, transaction,
+//! translation, user, user_candidature, and waitlist). By importing everything in this module, users can access
 //! all API-related functionality without needing to import individual modules.
 //!
 //! The prelude includes:
@@ -31,14 +33,89 @@
 //! }
 //! ```
 
+#[cfg(feature = "achievements")]
+pub use super::achievement::*;
+#[cfg(feature = "apps")]
+pub use super::app::*;
+#[cfg(feature = "attachments")]
+pub use super::attachment::*;
+#[cfg(feature = "blocs")]
+pub use super::bloc::*;
+#[cfg(feature = "broadcasts")]
+pub use super::broadcast::*;
+#[cfg(feature = "campus")]
 pub use super::campus::*;
+#[cfg(feature = "closes")]
+pub use super::close::*;
+#[cfg(feature = "coalitions")]
+pub use super::coalition::*;
+#[cfg(feature = "community_services")]
+pub use super::community_service::*;
+#[cfg(feature = "cursus")]
 pub use super::cursus::*;
+#[cfg(feature = "cursus_users")]
+pub use super::cursus_user::*;
+#[cfg(feature = "events")]
+pub use super::event::*;
+#[cfg(feature = "exams")]
 pub use super::exam::*;
+#[cfg(feature = "experiences")]
+pub use super::experience::*;
+#[cfg(feature = "feedbacks")]
+pub use super::feedback::*;
+#[cfg(feature = "gitlab_users")]
+pub use super::gitlab_user::*;
+#[cfg(feature = "groups")]
 pub use super::group::*;
+#[cfg(feature = "journals")]
+pub use super::journal::*;
+#[cfg(feature = "languages")]
+pub use super::language::*;
+#[cfg(feature = "levels")]
+pub use super::level::*;
+#[cfg(feature = "locations")]
+pub use super::location::*;
+#[cfg(feature = "notes")]
+pub use super::note::*;
+#[cfg(feature = "notions")]
+pub use super::notion::*;
+#[cfg(feature = "offers")]
+pub use super::offer::*;
+#[cfg(feature = "pools")]
+pub use super::pool::*;
+#[cfg(feature = "products")]
+pub use super::product::*;
+#[cfg(feature = "projects")]
 pub use super::project::*;
+#[cfg(feature = "project_sessions")]
 pub use super::project_session::*;
+#[cfg(feature = "projects_users")]
 pub use super::project_user::*;
+#[cfg(feature = "quests")]
+pub use super::quest::*;
+#[cfg(feature = "scales")]
+pub use super::scale::*;
+#[cfg(feature = "scale_teams")]
 pub use super::scale_team::*;
+#[cfg(feature = "skills")]
+pub use super::skill::*;
+#[cfg(feature = "slots")]
+pub use super::slot::*;
+#[cfg(feature = "tags")]
+pub use super::tag::*;
+#[cfg(feature = "teams")]
+pub use super::team::*;
+#[cfg(feature = "titles")]
+pub use super::title::*;
+#[cfg(feature = "transactions")]
+pub use super::transaction::*;
+#[cfg(feature = "translations")]
+pub use super::translation::*;
+#[cfg(feature = "users")]
 pub use super::user::*;
+#[cfg(feature = "user_candidatures")]
+pub use super::user_candidature::*;
+#[cfg(feature = "waitlists")]
+pub use super::waitlist::*;
 
 pub use super::HasVec;
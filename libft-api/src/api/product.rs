@@ -0,0 +1,36 @@
+//! API endpoints related to the campus shop.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the products of the
+//! campus shops and the commands (purchases) made with wallet points.
+//!
+//! # Endpoints
+//!
+//! * **products**: Retrieve a list of products, optionally scoped to a campus
+//! * **products_id_commands**: Get the commands of a specific product
+//! * **products_id_commands_post**: Buy a product for a user
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .products(FtApiProductsRequest::new().with_campus_id(FtCampusId::new(69)))
+//!         .await?;
+//!     for product in response.products {
+//!         println!("{} ({} points)", product.name, product.price);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod products;
+pub use products::*;
+mod products_id_commands;
+pub use products_id_commands::*;
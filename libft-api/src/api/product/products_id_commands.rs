@@ -0,0 +1,163 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rvstruct::ValueStruct;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProductsIdCommandsRequest {
+    pub product_id: FtProductId,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiProductsIdCommandsResponse {
+    pub commands: Vec<FtCommand>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProductsIdCommandsPostRequest {
+    pub product_id: FtProductId,
+    pub command: FtApiProductsIdCommandsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProductsIdCommandsPostBody {
+    /// The buyer, whose wallet is debited of the product price.
+    pub user_id: FtUserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiProductsIdCommandsPostResponse {
+    pub command: FtCommand,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the commands (purchases) of a product.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiProductsIdCommandsRequest` object containing the parameters for the API call.
+    ///     - `product_id` (required): `FtProductId`
+    ///       The product id.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiProductsIdCommandsResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn products_id_commands(
+        &self,
+        req: FtApiProductsIdCommandsRequest,
+    ) -> ClientResult<FtApiProductsIdCommandsResponse> {
+        let url = &format!("products/{}/commands", req.product_id.value());
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Buys a product for a user.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn products_id_commands_post(
+        &self,
+        req: FtApiProductsIdCommandsPostRequest,
+    ) -> ClientResult<FtApiProductsIdCommandsPostResponse> {
+        let url = &format!("products/{}/commands", req.product_id.value());
+        let body = serde_json::json!({ "command": req.command });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body = FtApiProductsIdCommandsPostBody::new(FtUserId::new(212_750))
+            .with_comment("Size L".to_string());
+
+        assert_eq!(
+            r#"{"user_id":212750,"comment":"Size L"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .products_id_commands(
+                FtApiProductsIdCommandsRequest::new(FtProductId::new(412)).with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -6,6 +6,11 @@
 //! # Endpoints
 //!
 //! * **projects**: Retrieve a list of projects with filtering, pagination, and sorting options
+//! * **projects_id**: Get a single project by its id or slug
+//! * **projects_id_project_sessions**: Get the project sessions of a specific project
+//! * **projects_id_register_post**: Register a user on a specific project
+//! * **projects_id_scales**: Get the evaluation scales attached to a specific project
+//! * **projects_id_slots**: Get the evaluation slots opened for a specific project
 //! * **projects_id_teams**: Get teams associated with a specific project
 //! * **project_data**: Additional project-related data access
 //!
@@ -36,5 +41,15 @@ pub use project_data::*;
 mod project_data;
 pub use projects::*;
 mod projects;
+pub use projects_id::*;
+mod projects_id;
+pub use projects_id_project_sessions::*;
+mod projects_id_project_sessions;
+pub use projects_id_register::*;
+mod projects_id_register;
+pub use projects_id_scales::*;
+mod projects_id_scales;
+pub use projects_id_slots::*;
+mod projects_id_slots;
 pub use projects_id_teams::*;
 mod projects_id_teams;
@@ -1,4 +1,5 @@
 use rsb_derive::Builder;
+use rvstruct::ValueStruct;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
@@ -31,12 +32,17 @@ where
     /// # Parameters
     /// - `req`: A `FtApiProjectRequest` struct containing the query parameters.
     ///
+    /// When `cursus_id` is set, the projects of that cursus are listed (`/cursus/:cursus_id/projects`).
+    /// Otherwise, when `project_id` is set, the children of that project are listed
+    /// (`/projects/:project_id/projects`).
+    ///
     /// # Query Parameters
-    /// - `cursus_id`: Optional cursus ID to filter projects by cursus
-    /// - `project_id`: Optional project ID to filter results
+    /// - `cursus_id`: Optional cursus ID to restrict projects to a cursus
+    /// - `project_id`: Optional parent project ID to list its children
     /// - `sort`: Optional vector of sort options
     /// - `range`: Optional vector of range options
-    /// - `filter`: Optional vector of filter options
+    /// - `filter`: Optional vector of filter options (`FtFilterField::Campus` restricts the
+    ///   result to projects available on a campus)
     /// - `page`: Optional page number for pagination
     /// - `per_page`: Optional number of items per page for pagination
     ///
@@ -61,11 +67,28 @@ where
     ///         .await?;
     ///     println!("Found {} projects", projects.projects.len());
     ///
+    ///     // Get the projects of the common core cursus available on a campus
+    ///     let projects = session
+    ///         .projects(
+    ///             FtApiProjectRequest::new()
+    ///                 .with_cursus_id(FtCursusId::new(21))
+    ///                 .with_filter(vec![FtFilterOption::new(
+    ///                     FtFilterField::Campus,
+    ///                     vec!["69".to_string()],
+    ///                 )])
+    ///         )
+    ///         .await?;
+    ///     println!("Found {} projects", projects.projects.len());
+    ///
     ///     Ok(())
     /// }
     /// ```
     pub async fn projects(&self, req: FtApiProjectRequest) -> ClientResult<FtApiProjectResponse> {
-        let url = "projects";
+        let url = &match (&req.cursus_id, &req.project_id) {
+            (Some(cursus_id), _) => format!("cursus/{}/projects", cursus_id.value()),
+            (None, Some(project_id)) => format!("projects/{}/projects", project_id.value()),
+            (None, None) => "projects".to_string(),
+        };
 
         let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
         let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
@@ -96,12 +119,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn projects() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -118,4 +142,27 @@ mod tests {
 
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_of_cursus() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects(
+                FtApiProjectRequest::new()
+                    .with_cursus_id(FtCursusId::new(crate::info::FT_CURSUS_ID))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
 }
@@ -0,0 +1,91 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdRequest {
+    pub id: FtProjectIdentifier,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiProjectsIdResponse {
+    pub project: FtProject,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves a single project from the 42 Intra API.
+    ///
+    /// The project can be identified either by its id or by its slug, which makes it possible to
+    /// resolve a slug such as `libft` into its numeric id.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsIdResponse>`: Contains the `FtProject`
+    ///
+    /// # Example
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example() -> ClientResult<()> {
+    ///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+    ///     let client = FtClient::new(FtClientReqwestConnector::new());
+    ///     let session = client.open_session(token);
+    ///
+    ///     let res = session
+    ///         .projects_id(FtApiProjectsIdRequest::new(FtProjectIdentifier::Slug(
+    ///             FtSlug::new("libft".to_string()),
+    ///         )))
+    ///         .await?;
+    ///     println!("libft has id {}", res.project.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn projects_id(
+        &self,
+        req: FtApiProjectsIdRequest,
+    ) -> ClientResult<FtApiProjectsIdResponse> {
+        let url = &format!(
+            "projects/{}",
+            match req.id {
+                FtProjectIdentifier::Slug(slug) => slug.to_string(),
+                FtProjectIdentifier::ProjectId(project_id) => project_id.to_string(),
+            }
+        );
+
+        self.http_session_api
+            .http_get(url, &FT_HTTP_EMPTY_GET_PARAMS.clone())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_by_slug() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id(FtApiProjectsIdRequest::new(FtProjectIdentifier::Slug(
+                FtSlug::new("libft".to_string()),
+            )))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdProjectSessionsRequest {
+    pub project_id: FtProjectId,
+    pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiProjectsIdProjectSessionsResponse {
+    pub project_sessions: Vec<FtProjectSession>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the project sessions of a project.
+    ///
+    /// Use it to discover the right `FtProjectSessionId` at runtime instead of relying on the
+    /// `ft_project_session_ids` constants. `cursus_id` and `campus_id` are shortcuts for the
+    /// corresponding `filter[cursus_id]` and `filter[campus_id]` options.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiProjectsIdProjectSessionsRequest` struct containing the query parameters.
+    ///     - `project_id`: The project ID.
+    ///     - `cursus_id` (optional): Only project sessions of this cursus.
+    ///     - `campus_id` (optional): Only project sessions of this campus.
+    ///     - `sort`, `range`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsIdProjectSessionsResponse>`: Contains a vector of `FtProjectSession` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn projects_id_project_sessions(
+        &self,
+        req: FtApiProjectsIdProjectSessionsRequest,
+    ) -> ClientResult<FtApiProjectsIdProjectSessionsResponse> {
+        let url = format!("projects/{}/project_sessions", req.project_id);
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(cursus_id) = req.cursus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CursusId,
+                vec![cursus_id.to_string()],
+            ));
+        }
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CampusId,
+                vec![campus_id.to_string()],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(&url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_project_sessions_basic_test() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id_project_sessions(
+                FtApiProjectsIdProjectSessionsRequest::new(FtProjectId::new(1314)).with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_project_sessions_campus_cursus_test() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id_project_sessions(
+                FtApiProjectsIdProjectSessionsRequest::new(FtProjectId::new(1314))
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_cursus_id(FtCursusId::new(crate::info::FT_CURSUS_ID))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
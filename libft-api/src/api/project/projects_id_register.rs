@@ -0,0 +1,80 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdRegisterPostRequest {
+    pub project_id: FtProjectId,
+    pub body: FtApiProjectsIdRegisterPostBody,
+}
+
+/// Who to register on the project.
+///
+/// Without `user_id` the owner of the token is registered, staff tokens can register anybody.
+/// `team_name` names the team created by the registration.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdRegisterPostBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<FtUserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<FtTeamName>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiProjectsIdRegisterPostResponse {
+    pub projects_user: FtProjectsUser,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Registers a user on a project, creating the corresponding `projects_user`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example() -> ClientResult<()> {
+    ///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+    ///     let client = FtClient::new(FtClientReqwestConnector::new());
+    ///     let session = client.open_session(token);
+    ///
+    ///     for user_id in [212_750, 212_751].map(FtUserId::new) {
+    ///         session
+    ///             .projects_id_register_post(FtApiProjectsIdRegisterPostRequest::new(
+    ///                 FtProjectId::new(1314),
+    ///                 FtApiProjectsIdRegisterPostBody::new().with_user_id(user_id),
+    ///             ))
+    ///             .await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn projects_id_register_post(
+        &self,
+        req: FtApiProjectsIdRegisterPostRequest,
+    ) -> ClientResult<FtApiProjectsIdRegisterPostResponse> {
+        let url = &format!("projects/{}/register", req.project_id.value());
+
+        self.http_session_api.http_post(url, &req.body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_body_serde() {
+        let body = FtApiProjectsIdRegisterPostBody::new().with_user_id(FtUserId::new(212_750));
+
+        assert_eq!(
+            r#"{"user_id":212750}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
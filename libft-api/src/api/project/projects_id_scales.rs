@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdScalesRequest {
+    pub project_id: FtProjectId,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiProjectsIdScalesResponse {
+    pub scales: Vec<FtScale>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the evaluation scales attached to a project.
+    ///
+    /// Use it to pick the right `FtScaleId` before calling `scale_teams_id_patch`.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiProjectsIdScalesRequest` struct containing the query parameters.
+    ///     - `project_id`: The project ID.
+    ///     - `filter` (optional): Must be one of `id`, `evaluation_id`, `name`, `created_at`,
+    ///       `updated_at`, `is_primary`, `correction_number`, `duration`, `manual_subscription`,
+    ///       `cursus_id`, `free`.
+    ///     - `range` (optional): Must be one of `id`, `evaluation_id`, `name`, `created_at`,
+    ///       `updated_at`, `correction_number`, `duration`.
+    ///     - `sort`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsIdScalesResponse>`: Contains a vector of `FtScale` objects, each
+    ///   with its number of correctors, duration and whether it is the primary scale of the project
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn projects_id_scales(
+        &self,
+        req: FtApiProjectsIdScalesRequest,
+    ) -> ClientResult<FtApiProjectsIdScalesResponse> {
+        let url = format!("projects/{}/scales", req.project_id);
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(&url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_scales_basic_test() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id_scales(
+                FtApiProjectsIdScalesRequest::new(FtProjectId::new(1314)).with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
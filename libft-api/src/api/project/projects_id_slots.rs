@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectsIdSlotsRequest {
+    pub project_id: FtProjectId,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiProjectsIdSlotsResponse {
+    pub slots: Vec<FtSlot>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the evaluation slots opened for a project.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiProjectsIdSlotsRequest` struct containing the query parameters.
+    ///     - `project_id`: The project ID.
+    ///     - `filter` (optional): Must be one of `id`, `user_id`, `begin_at`, `end_at`, `future`, `end`,
+    ///       `campus_id`.
+    ///     - `range` (optional): Must be one of `id`, `user_id`, `begin_at`, `end_at`.
+    ///     - `sort`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsIdSlotsResponse>`: Contains a vector of `FtSlot` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn projects_id_slots(
+        &self,
+        req: FtApiProjectsIdSlotsRequest,
+    ) -> ClientResult<FtApiProjectsIdSlotsResponse> {
+        let url = format!("projects/{}/slots", req.project_id);
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(&url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_slots_basic_test() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id_slots(
+                FtApiProjectsIdSlotsRequest::new(FtProjectId::new(1314))
+                    .with_filter(vec![FtFilterOption::new(
+                        FtFilterField::Future,
+                        vec!["true".to_string()],
+                    )])
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 pub struct FtApiProjectsIdTeamsRequest {
     pub project_id: FtProjectId,
     pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+    pub status: Option<FtStatus>,
     pub sort: Option<Vec<FtSortOption>>,
     pub range: Option<Vec<FtRangeOption>>,
     pub filter: Option<Vec<FtFilterOption>>,
@@ -25,13 +27,51 @@ impl<FCHC> FtClientSession<'_, FCHC>
 where
     FCHC: FtClientHttpConnector + Send + Sync,
 {
+    /// Retrieves the teams of a project.
+    ///
+    /// This complements `project_sessions_id_teams` for cases where the project session id isn't
+    /// known. `cursus_id`, `campus_id` and `status` are shortcuts for the corresponding
+    /// `filter[cursus]`, `filter[campus]` and `filter[status]` options.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiProjectsIdTeamsRequest` struct containing the query parameters.
+    ///     - `project_id`: The project ID.
+    ///     - `cursus_id` (optional): Only teams of this cursus.
+    ///     - `campus_id` (optional): Only teams of this campus.
+    ///     - `status` (optional): Only teams in this status (`in_progress`, `waiting_for_correction`,
+    ///       `finished`, ...).
+    ///     - `sort`, `range`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsIdTeamsResponse>`: Contains a vector of `FtTeam` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
     pub async fn projects_id_teams(
         &self,
         req: FtApiProjectsIdTeamsRequest,
     ) -> ClientResult<FtApiProjectsIdTeamsResponse> {
         let url = format!("projects/{}/teams", req.project_id);
 
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(cursus_id) = req.cursus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Cursus,
+                vec![cursus_id.to_string()],
+            ));
+        }
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Campus,
+                vec![campus_id.to_string()],
+            ));
+        }
+        if let Some(status) = req.status {
+            filter.push(FtFilterOption::new(FtFilterField::Status, vec![status.0]));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
         let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
 
         let params = vec![
@@ -60,12 +100,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn projects_id_teams_basic_test() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -84,4 +125,28 @@ mod tests {
 
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn projects_id_teams_status_campus_test() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_id_teams(
+                FtApiProjectsIdTeamsRequest::new(FtProjectId::new(1314))
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_status(FtStatus::new("finished".to_string()))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
 }
@@ -1,10 +1,16 @@
 //! API endpoints related to project session information.
 //!
 //! This module provides access to the 42 Intra API endpoints that deal with project session data.
-//! It includes functionality for retrieving teams and scale teams associated with specific project sessions.
+//! It includes functionality for retrieving a project session, its project data, and the teams and scale
+//! teams associated with it.
 //!
 //! # Endpoints
 //!
+//! * **project_sessions_id**: Retrieve a specific project session with its settings (solo, max people, durations)
+//! * **project_sessions_id_evaluations**: Retrieve the evaluations configured on a specific project session
+//! * **project_sessions_id_project_data**: Retrieve the project data of a specific project session
+//! * **project_sessions_id_project_sessions_rules**: Retrieve the rules of a specific project session
+//! * **project_sessions_rules_id_params_project_sessions_rules**: Retrieve the parameters of a project session rule
 //! * **project_sessions_id_teams**: Retrieve teams associated with a specific project session
 //! * **project_sessions_id_scale_teams**: Retrieve scale teams (evaluation teams) associated with a specific project session
 //!
@@ -31,7 +37,17 @@
 //! }
 //! ```
 
+mod project_sessions_id;
+pub use project_sessions_id::*;
+mod project_sessions_id_evaluations;
+pub use project_sessions_id_evaluations::*;
+mod project_sessions_id_project_data;
+pub use project_sessions_id_project_data::*;
+mod project_sessions_id_project_sessions_rules;
+pub use project_sessions_id_project_sessions_rules::*;
 mod project_sessions_id_scale_teams;
 pub use project_sessions_id_scale_teams::*;
 mod project_sessions_id_teams;
 pub use project_sessions_id_teams::*;
+mod project_sessions_rules_id_params_project_sessions_rules;
+pub use project_sessions_rules_id_params_project_sessions_rules::*;
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectSessionsIdRequest {
+    pub project_session_id: FtProjectSessionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiProjectSessionsIdResponse {
+    pub project_session: FtProjectSession,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves a single project session.
+    ///
+    /// The returned `FtProjectSession` carries the session settings such as `solo`, `max_people`,
+    /// `duration_days`, `terminating_after` and the attached scales.
+    pub async fn project_sessions_id(
+        &self,
+        req: FtApiProjectSessionsIdRequest,
+    ) -> ClientResult<FtApiProjectSessionsIdResponse> {
+        let url = &format!("project_sessions/{}", req.project_session_id);
+
+        self.http_session_api
+            .http_get(url, &FT_HTTP_EMPTY_GET_PARAMS.clone())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::models::project_session::ft_project_session_ids::c_piscine::C_PISCINE_RUSH_02;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+        let client = FtClient::new(FtClientReqwestConnector::new());
+        let session = client.open_session(token);
+
+        let res = session
+            .project_sessions_id(FtApiProjectSessionsIdRequest::new(FtProjectSessionId::new(
+                C_PISCINE_RUSH_02,
+            )))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,97 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiProjectSessionsIdProjectSessionsRulesRequest {
+    pub project_session_id: FtProjectSessionId,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiProjectSessionsIdProjectSessionsRulesResponse {
+    pub project_sessions_rules: Vec<FtProjectSessionsRule>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the rules (group size, retries, prerequisites, ...) of a project session.
+    ///
+    /// Each `FtProjectSessionsRule` carries the `FtRule` it applies and its parameters.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn project_sessions_id_project_sessions_rules(
+        &self,
+        req: FtApiProjectSessionsIdProjectSessionsRulesRequest,
+    ) -> ClientResult<FtApiProjectSessionsIdProjectSessionsRulesResponse> {
+        let url = &format!(
+            "project_sessions/{}/project_sessions_rules",
+            req.project_session_id
+        );
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::models::project_session::ft_project_session_ids::c_piscine::C_PISCINE_RUSH_02;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+        let client = FtClient::new(FtClientReqwestConnector::new());
+        let session = client.open_session(token);
+
+        let res = session
+            .project_sessions_id_project_sessions_rules(
+                FtApiProjectSessionsIdProjectSessionsRulesRequest::new(FtProjectSessionId::new(
+                    C_PISCINE_RUSH_02,
+                )),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -24,9 +24,37 @@ impl<FCHC> FtClientSession<'_, FCHC>
 where
     FCHC: FtClientHttpConnector + Send + Sync,
 {
+    #[deprecated(note = "use `project_sessions_id_scale_teams` instead")]
     pub async fn project_sessions_scale_teams(
         &self,
         request: FtApiProjectSessionsScaleTeamsRequest,
+    ) -> ClientResult<FtApiProjectSessionsScaleTeamsResponse> {
+        self.project_sessions_id_scale_teams(request).await
+    }
+
+    /// Retrieves the scale teams (defenses) of a single project session.
+    ///
+    /// Cheaper than filtering the global `scale_teams` when monitoring the defenses of one
+    /// session, e.g. during a rush weekend.
+    ///
+    /// # Parameters
+    /// - `request`: A `FtApiProjectSessionsScaleTeamsRequest` struct containing the query parameters.
+    ///     - `project_session_id`: The project session ID.
+    ///     - `filter` (optional): Must be one of `id`, `user_id`, `begin_at`, `created_at`,
+    ///       `updated_at`, `scale_id`, `team_id`, `comment`, `old_feedback`, `feedback_rating`,
+    ///       `final_mark`, `truant_id`, `flag_id`, `token`, `ip`, `internship_id`, `filled_at`,
+    ///       `campus_id`, `cursus_id`, `future`, `filled`.
+    ///     - `sort`, `range`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectSessionsScaleTeamsResponse>`: Contains a vector of `FtScaleTeam` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn project_sessions_id_scale_teams(
+        &self,
+        request: FtApiProjectSessionsScaleTeamsRequest,
     ) -> ClientResult<FtApiProjectSessionsScaleTeamsResponse> {
         let url = &format!(
             "project_sessions/{}/scale_teams",
@@ -62,13 +90,14 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use ft_project_session_ids::ft_cursus::inner::LIBFT;
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -82,7 +111,7 @@ mod tests {
             .with_per_page(1);
 
         let session = client.open_session(token);
-        let res = session.project_sessions_scale_teams(req).await;
+        let res = session.project_sessions_id_scale_teams(req).await;
         assert!(res.is_ok());
     }
 }
@@ -60,13 +60,14 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use crate::prelude::ft_project_session_ids::ft_cursus::inner::LIBFT;
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -85,6 +86,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn with_filter() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -20,7 +20,7 @@
 //!
 //!     // Get project-user associations for a specific user
 //!     let response = session
-//!         .projects_uesrs(
+//!         .projects_users(
 //!             FtApiProjectsUsersRequest::new()
 //!                 .with_filter(vec![
 //!                     FtFilterOption::new(FtFilterField::UserId, vec!["12345".to_owned()])
@@ -31,7 +31,7 @@
 //!
 //!     // Create a new project-user association (if you have the appropriate permissions)
 //!     // let new_assoc = session
-//!     //     .projects_uesrs_post(FtApiProjectsUsersPostRequest::new(
+//!     //     .projects_users_post(FtApiProjectsUsersPostRequest::new(
 //!     //         FtApiProjectsUsersPostBody {
 //!     //             project_id: FtProjectId::new(123),
 //!     //             user_id: FtUserId::new(12345),
@@ -24,6 +24,9 @@ pub struct FtApiProjectsUsersPostResponse {
 pub struct FtApiProjectsUsersRequest {
     pub user_id: Option<Vec<FtUserId>>,
     pub project_id: Option<Vec<FtProjectId>>,
+    pub campus_id: Option<FtCampusId>,
+    pub cursus_id: Option<FtCursusId>,
+    pub marked: Option<bool>,
     pub sort: Option<Vec<FtSortOption>>,
     pub range: Option<Vec<FtRangeOption>>,
     pub filter: Option<Vec<FtFilterOption>>,
@@ -41,51 +44,138 @@ impl<FCHC> FtClientSession<'_, FCHC>
 where
     FCHC: FtClientHttpConnector + Send + Sync,
 {
+    #[deprecated(note = "use `projects_users_post` instead")]
     pub async fn projects_uesrs_post(
         &self,
         req: FtApiProjectsUsersPostRequest,
+    ) -> ClientResult<FtApiProjectsUsersPostResponse> {
+        self.projects_users_post(req).await
+    }
+
+    pub async fn projects_users_post(
+        &self,
+        req: FtApiProjectsUsersPostRequest,
     ) -> ClientResult<FtApiProjectsUsersPostResponse> {
         let url = "projects_users";
 
         self.http_session_api.http_post(url, &req).await
     }
 
+    #[deprecated(note = "use `projects_users` instead")]
     pub async fn projects_uesrs(
         &self,
         req: FtApiProjectsUsersRequest,
+    ) -> ClientResult<FtApiProjectsUsersResponse> {
+        self.projects_users(req).await
+    }
+
+    /// Retrieves the projects users of the whole intra.
+    ///
+    /// This is the single call behind progress dashboards, instead of fanning out
+    /// `users_id_projects_users` over every user. The typed fields are shortcuts for the
+    /// corresponding filters.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiProjectsUsersRequest` struct containing the query parameters.
+    ///     - `user_id` (optional): `filter[user_id]`, only projects users of these users.
+    ///     - `project_id` (optional): `filter[project_id]`, only projects users of these projects.
+    ///     - `campus_id` (optional): `filter[campus]`, only projects users of this campus.
+    ///     - `cursus_id` (optional): `filter[cursus]`, only projects users of this cursus.
+    ///     - `marked` (optional): `filter[marked]`, only (un)marked projects users.
+    ///     - `range` (optional): e.g. `FtRangeField::MarkedAt` to select on the marking date.
+    ///     - `sort`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiProjectsUsersResponse>`: Contains a vector of `FtProjectsUser` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn projects_users(
+        &self,
+        req: FtApiProjectsUsersRequest,
     ) -> ClientResult<FtApiProjectsUsersResponse> {
         let url = "projects_users";
 
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
-        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
-
-        let params = vec![
-            to_param!(req, page),
-            to_param!(req, per_page),
-            (
-                "sort".to_string(),
-                req.sort.as_ref().map(|v| {
-                    v.iter()
-                        .map(|v| {
-                            format!(
-                                "{}{}",
-                                if v.descending { "-" } else { "" },
-                                serde_plain::to_string(&v.field).unwrap()
-                            )
-                        })
-                        .collect::<Vec<_>>()
-                        .join(",")
-                }),
-            ),
-        ];
+        self.http_session_api
+            .http_get(url, &projects_users_params(req))
+            .await
+    }
+
+    /// `projects_users`, returning the page undecoded, to deserialize its records one at a time.
+    pub async fn projects_users_raw(
+        &self,
+        req: FtApiProjectsUsersRequest,
+    ) -> ClientResult<FtRawPage<FtProjectsUser>> {
+        let url = "projects_users";
 
         self.http_session_api
-            .http_get(url, &[filters, range, params].concat())
+            .http_get_raw(url, &projects_users_params(req))
             .await
     }
 }
 
-#[cfg(test)]
+/// The query parameters of `projects_users`.
+fn projects_users_params(req: FtApiProjectsUsersRequest) -> Vec<(String, Option<String>)> {
+    let mut filter = req.filter.unwrap_or_default();
+    if let Some(user_id) = req.user_id {
+        filter.push(FtFilterOption::new(
+            FtFilterField::UserId,
+            user_id.iter().map(ToString::to_string).collect(),
+        ));
+    }
+    if let Some(project_id) = req.project_id {
+        filter.push(FtFilterOption::new(
+            FtFilterField::ProjectId,
+            project_id.iter().map(ToString::to_string).collect(),
+        ));
+    }
+    if let Some(campus_id) = req.campus_id {
+        filter.push(FtFilterOption::new(
+            FtFilterField::Campus,
+            vec![campus_id.to_string()],
+        ));
+    }
+    if let Some(cursus_id) = req.cursus_id {
+        filter.push(FtFilterOption::new(
+            FtFilterField::Cursus,
+            vec![cursus_id.to_string()],
+        ));
+    }
+    if let Some(marked) = req.marked {
+        filter.push(FtFilterOption::new(
+            FtFilterField::Marked,
+            vec![marked.to_string()],
+        ));
+    }
+
+    let filters = convert_filter_option_to_tuple(filter).unwrap();
+    let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+    let params = vec![
+        to_param!(req, page),
+        to_param!(req, per_page),
+        (
+            "sort".to_string(),
+            req.sort.as_ref().map(|v| {
+                v.iter()
+                    .map(|v| {
+                        format!(
+                            "{}{}",
+                            if v.descending { "-" } else { "" },
+                            serde_plain::to_string(&v.field).unwrap()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        ),
+    ];
+
+    [filters, range, params].concat()
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use crate::info::ft_cursus::COMMON_CORE_SUBJECTS;
@@ -93,6 +183,7 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -108,7 +199,7 @@ mod tests {
             .map(|id| id.to_string())
             .collect();
         let res = session
-            .projects_uesrs(
+            .projects_users(
                 FtApiProjectsUsersRequest::new()
                     .with_per_page(1)
                     .with_filter(vec![
@@ -121,4 +212,33 @@ mod tests {
 
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn marked_on_campus() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .projects_users(
+                FtApiProjectsUsersRequest::new()
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_cursus_id(FtCursusId::new(crate::info::FT_CURSUS_ID))
+                    .with_marked(true)
+                    .with_range(vec![FtRangeOption::new(
+                        FtRangeField::MarkedAt,
+                        vec!["2025-01-01".to_owned(), "2025-02-01".to_owned()],
+                    )])
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
 }
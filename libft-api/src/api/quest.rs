@@ -0,0 +1,32 @@
+//! API endpoints related to quests.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the quests, the sets of
+//! projects that make up the milestones of a cursus.
+//!
+//! # Endpoints
+//!
+//! * **quests**: Retrieve a list of quests, optionally of a given cursus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .quests(FtApiQuestsRequest::new().with_cursus_id(FtCursusId::new(21)))
+//!         .await?;
+//!     for quest in response.quests {
+//!         println!("{:?}: {}", quest.id, quest.name);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod quests;
+pub use quests::*;
@@ -0,0 +1,35 @@
+//! API endpoints related to scales (evaluation sheets).
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with scales, including the
+//! sections and questions that make up an evaluation sheet.
+//!
+//! # Endpoints
+//!
+//! * **scales**: Retrieve a list of scales with filtering, pagination, and sorting options
+//! * **scales_id**: Get a specific scale with its sections and questions
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .scales_id(FtApiScalesIdRequest::new(FtScaleId::new(45833)))
+//!         .await?;
+//!     for section in response.scale.sections.unwrap_or_default() {
+//!         println!("{:?}: {} questions", section.name, section.questions.unwrap_or_default().len());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod scales;
+pub use scales::*;
+mod scales_id;
+pub use scales_id::*;
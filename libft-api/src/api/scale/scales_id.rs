@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScalesIdRequest {
+    pub id: FtScaleId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiScalesIdResponse {
+    pub scale: FtScale,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves a single scale with its sections and questions.
+    ///
+    /// Useful to render or diff an evaluation sheet, or to make sure a scale id exists before
+    /// calling `scale_teams_id_patch`.
+    pub async fn scales_id(
+        &self,
+        req: FtApiScalesIdRequest,
+    ) -> ClientResult<FtApiScalesIdResponse> {
+        let url = &format!("scales/{}", req.id.value());
+
+        self.http_session_api
+            .http_get(url, &FT_HTTP_EMPTY_GET_PARAMS.clone())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+        let client = FtClient::new(FtClientReqwestConnector::new());
+        let session = client.open_session(token);
+
+        let res = session
+            .scales_id(FtApiScalesIdRequest::new(FtScaleId::new(45833)))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
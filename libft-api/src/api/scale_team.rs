@@ -7,7 +7,13 @@
 //! # Endpoints
 //!
 //! * **scale_teams**: Retrieve a list of scale teams with filtering, pagination, and sorting options
+//! * **scale_teams_post**: Create a single scale team with an explicit scale
 //! * **scale_teams_multiple_create_post**: Create multiple scale teams at once
+//! * **scale_teams_id**: Get a specific scale team by its ID
+//! * **scale_teams_id_patch**: Change the scale of a scale team
+//! * **scale_teams_id_update**: Update a scale team (begin_at, comment, feedback, final_mark, answers, flag)
+//! * **scale_teams_id_delete**: Cancel a scale team
+//! * **scale_teams_id_feedbacks_post**: Leave a feedback on a scale team
 //!
 //! # Example
 //!
@@ -48,3 +54,5 @@ mod scale_teams;
 pub use scale_teams::*;
 mod scale_teams_id;
 pub use scale_teams_id::*;
+mod scale_teams_id_feedbacks;
+pub use scale_teams_id_feedbacks::*;
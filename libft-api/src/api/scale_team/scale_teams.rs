@@ -34,19 +34,210 @@ pub struct FtApiScaleTeamsMultipleCreateBody {
 #[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
 #[serde(transparent)]
 pub struct FtApiScaleTeamsMultipleCreateResponse {
-    pub scale_teams: Vec<FtScaleTeam>,
+    pub scale_teams: Vec<FtApiScaleTeamsMultipleCreateItem>,
+}
+
+/// The result for one body of a multiple create: the booked scale team, or why it was refused.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FtApiScaleTeamsMultipleCreateItem {
+    Created(Box<FtScaleTeam>),
+    Failed(FtApiScaleTeamsMultipleCreateError),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FtApiScaleTeamsMultipleCreateError {
+    pub error: Option<String>,
+    /// Validation errors, usually a map from field to messages.
+    pub errors: Option<serde_json::Value>,
+    pub message: Option<String>,
+}
+
+impl FtApiScaleTeamsMultipleCreateError {
+    fn missing() -> Self {
+        Self {
+            error: None,
+            errors: None,
+            message: Some("missing from the response".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for FtApiScaleTeamsMultipleCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let errors = self.errors.as_ref().map(ToString::to_string);
+        let parts = [self.error.as_ref(), errors.as_ref(), self.message.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            write!(f, "unknown error")
+        } else {
+            write!(f, "{}", parts.join(": "))
+        }
+    }
+}
+
+/// What `scale_teams_multiple_create_post` booked, and which bodies to retry.
+#[derive(Debug, Default, PartialEq)]
+pub struct FtScaleTeamsMultipleCreateReport {
+    pub created: Vec<FtScaleTeam>,
+    pub failed: Vec<(
+        FtApiScaleTeamsMultipleCreateBody,
+        FtApiScaleTeamsMultipleCreateError,
+    )>,
+    /// Errors of the response that could not be paired with a body, when the API did not return
+    /// one item per body.
+    pub unattributed: Vec<FtApiScaleTeamsMultipleCreateError>,
+    /// The bodies not sent because the client is in dry-run mode.
+    pub simulated: Vec<FtApiScaleTeamsMultipleCreateBody>,
+}
+
+impl FtScaleTeamsMultipleCreateReport {
+    /// Pairs the request bodies with the response items.
+    ///
+    /// Items are matched by position when the API returns one per body; otherwise the created
+    /// scale teams are matched by team and corrector, the bodies left over are failed, and the
+    /// errors of the response are kept in `unattributed`, as nothing tells which body they are
+    /// about.
+    pub fn from_response(
+        bodies: Vec<FtApiScaleTeamsMultipleCreateBody>,
+        items: Vec<FtApiScaleTeamsMultipleCreateItem>,
+    ) -> Self {
+        let mut report = Self::default();
+        if bodies.len() == items.len() {
+            for (body, item) in bodies.into_iter().zip(items) {
+                match item {
+                    FtApiScaleTeamsMultipleCreateItem::Created(scale_team) => {
+                        report.created.push(*scale_team);
+                    }
+                    FtApiScaleTeamsMultipleCreateItem::Failed(err) => {
+                        report.failed.push((body, err));
+                    }
+                }
+            }
+            return report;
+        }
+
+        for item in items {
+            match item {
+                FtApiScaleTeamsMultipleCreateItem::Created(scale_team) => {
+                    report.created.push(*scale_team);
+                }
+                FtApiScaleTeamsMultipleCreateItem::Failed(err) => report.unattributed.push(err),
+            }
+        }
+        let mut matched = vec![false; report.created.len()];
+        for body in bodies {
+            let position = report
+                .created
+                .iter()
+                .zip(&matched)
+                .position(|(scale_team, matched)| !matched && books(scale_team, &body));
+            match position {
+                Some(position) => matched[position] = true,
+                None => report
+                    .failed
+                    .push((body, FtApiScaleTeamsMultipleCreateError::missing())),
+            }
+        }
+        report
+    }
+
+    /// Whether every body was booked.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    pub fn created_ids(&self) -> Vec<FtScaleTeamId> {
+        self.created
+            .iter()
+            .map(|scale_team| scale_team.id.clone())
+            .collect()
+    }
+
+    /// A request booking only the failed bodies again, `None` if every body was booked.
+    pub fn into_retry_request(self) -> Option<FtApiScaleTeamsMultipleCreateRequest> {
+        (!self.failed.is_empty()).then(|| {
+            FtApiScaleTeamsMultipleCreateRequest::new(
+                self.failed.into_iter().map(|(body, _)| body).collect(),
+            )
+        })
+    }
+}
+
+fn books(scale_team: &FtScaleTeam, body: &FtApiScaleTeamsMultipleCreateBody) -> bool {
+    let team_id = scale_team.team.as_ref().map(|team| &team.id);
+    let corrector_id = match &scale_team.corrector {
+        FtCorrector::User(user) => user.id,
+        FtCorrector::String(_) => None,
+    };
+    team_id == Some(&body.team_id) && corrector_id.is_none_or(|id| id == body.user_id)
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsPostRequest {
+    pub scale_team: FtApiScaleTeamsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsPostBody {
+    pub scale_id: FtScaleId,
+    pub team_id: FtTeamId,
+    pub user_id: FtUserId,
+    pub begin_at: FtDateTimeUtc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiScaleTeamsPostResponse {
+    pub scale_team: FtScaleTeam,
 }
 
 impl<FCHC> FtClientSession<'_, FCHC>
 where
     FCHC: FtClientHttpConnector + Send + Sync,
 {
+    /// Books several scale teams at once, reporting which bodies were refused.
+    ///
+    /// Bodies refused by the API do not fail the call: they are listed in
+    /// `FtScaleTeamsMultipleCreateReport::failed`, and `into_retry_request` books them again. In
+    /// dry-run mode nothing is booked, and every body is listed in
+    /// `FtScaleTeamsMultipleCreateReport::simulated`.
     pub async fn scale_teams_multiple_create_post(
         &self,
         req: FtApiScaleTeamsMultipleCreateRequest,
-    ) -> ClientResult<FtApiScaleTeamsMultipleCreateResponse> {
+    ) -> ClientResult<FtScaleTeamsMultipleCreateReport> {
         let url = "scale_teams/multiple_create";
 
+        let res: FtApiScaleTeamsMultipleCreateResponse =
+            match self.http_session_api.http_post(url, &req).await {
+                Err(err) if err.is_dry_run() => {
+                    return Ok(FtScaleTeamsMultipleCreateReport {
+                        simulated: req.scale_teams,
+                        ..Default::default()
+                    })
+                }
+                res => res?,
+            };
+        Ok(FtScaleTeamsMultipleCreateReport::from_response(
+            req.scale_teams,
+            res.scale_teams,
+        ))
+    }
+
+    /// Books a single scale team with an explicit scale.
+    ///
+    /// Unlike `scale_teams_multiple_create_post`, the scale used for the evaluation is chosen by
+    /// the caller through `scale_id`.
+    pub async fn scale_teams_post(
+        &self,
+        req: FtApiScaleTeamsPostRequest,
+    ) -> ClientResult<FtApiScaleTeamsPostResponse> {
+        let url = "scale_teams";
+
         self.http_session_api.http_post(url, &req).await
     }
 
@@ -56,43 +247,185 @@ where
     ) -> ClientResult<FtApiScaleTeamsResponse> {
         let url = "scale_teams";
 
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
-        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
-
-        let params = vec![
-            to_param!(req, page),
-            to_param!(req, per_page),
-            (
-                "sort".to_string(),
-                req.sort.as_ref().map(|v| {
-                    v.iter()
-                        .map(|v| {
-                            format!(
-                                "{}{}",
-                                if v.descending { "-" } else { "" },
-                                serde_plain::to_string(&v.field).unwrap()
-                            )
-                        })
-                        .collect::<Vec<_>>()
-                        .join(",")
-                }),
-            ),
-        ];
+        self.http_session_api
+            .http_get(url, &scale_teams_params(req))
+            .await
+    }
+
+    /// `scale_teams`, returning the page undecoded, to deserialize its records one at a time.
+    pub async fn scale_teams_raw(
+        &self,
+        req: FtApiScaleTeamsRequest,
+    ) -> ClientResult<FtRawPage<FtScaleTeam>> {
+        let url = "scale_teams";
 
         self.http_session_api
-            .http_get(url, &[filters, range, params].concat())
+            .http_get_raw(url, &scale_teams_params(req))
             .await
     }
 }
 
-#[cfg(test)]
+/// The query parameters of `scale_teams`.
+fn scale_teams_params(req: FtApiScaleTeamsRequest) -> Vec<(String, Option<String>)> {
+    let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+    let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+    let params = vec![
+        to_param!(req, page),
+        to_param!(req, per_page),
+        (
+            "sort".to_string(),
+            req.sort.as_ref().map(|v| {
+                v.iter()
+                    .map(|v| {
+                        format!(
+                            "{}{}",
+                            if v.descending { "-" } else { "" },
+                            serde_plain::to_string(&v.field).unwrap()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        ),
+    ];
+
+    [filters, range, params].concat()
+}
+
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use crate::info::ft_campus_id::GYEONGSAN;
 
     use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiScaleTeamsPostRequest::new(FtApiScaleTeamsPostBody::new(
+            FtScaleId::new(45833),
+            FtTeamId::new(6_298_862),
+            FtUserId::new(174_094),
+            FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 1, 28, 7, 0, 0).unwrap()),
+        ));
+
+        assert_eq!(
+            r#"{"scale_team":{"scale_id":45833,"team_id":6298862,"user_id":174094,"begin_at":"2025-01-28T07:00:00Z"}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+
+    fn body(team_id: i32, user_id: i32) -> FtApiScaleTeamsMultipleCreateBody {
+        FtApiScaleTeamsMultipleCreateBody {
+            begin_at: FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 1, 28, 7, 0, 0).unwrap()),
+            user_id: FtUserId::new(user_id),
+            team_id: FtTeamId::new(team_id),
+        }
+    }
+
+    fn items(raw: serde_json::Value) -> Vec<FtApiScaleTeamsMultipleCreateItem> {
+        serde_json::from_value(raw).unwrap()
+    }
+
+    fn scale_team(id: i32, team_id: i32, corrector_id: i32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "scale_id": 1,
+            "created_at": "2025-01-20T00:00:00.000Z",
+            "updated_at": "2025-01-20T00:00:00.000Z",
+            "begin_at": "2025-01-28T07:00:00.000Z",
+            "corrector": { "id": corrector_id, "login": "corrector" },
+            "correcteds": [],
+            "truant": {},
+            "team": { "id": team_id }
+        })
+    }
+
+    #[test]
+    fn multiple_create_report_by_position() {
+        let report = FtScaleTeamsMultipleCreateReport::from_response(
+            vec![body(10, 1), body(11, 2)],
+            items(serde_json::json!([
+                scale_team(100, 10, 1),
+                { "errors": { "begin_at": ["is not available"] } }
+            ])),
+        );
+
+        assert_eq!(report.created_ids(), vec![FtScaleTeamId::new(100)]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(
+            report.failed[0].1.to_string(),
+            r#"{"begin_at":["is not available"]}"#
+        );
+        let retry = report.into_retry_request().unwrap();
+        assert_eq!(retry.scale_teams, vec![body(11, 2)]);
+    }
+
+    #[test]
+    fn multiple_create_report_by_team() {
+        let report = FtScaleTeamsMultipleCreateReport::from_response(
+            vec![body(10, 1), body(11, 2), body(12, 3)],
+            items(serde_json::json!([scale_team(101, 11, 2)])),
+        );
+
+        assert_eq!(report.created_ids(), vec![FtScaleTeamId::new(101)]);
+        assert_eq!(
+            report
+                .failed
+                .iter()
+                .map(|(body, _)| body.team_id.clone())
+                .collect::<Vec<_>>(),
+            vec![FtTeamId::new(10), FtTeamId::new(12)]
+        );
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn multiple_create_report_keeps_unattributed_errors() {
+        let report = FtScaleTeamsMultipleCreateReport::from_response(
+            vec![body(10, 1), body(11, 2), body(12, 3)],
+            items(serde_json::json!([
+                scale_team(101, 11, 2),
+                { "errors": { "begin_at": ["is not available"] } }
+            ])),
+        );
+
+        assert_eq!(report.failed.len(), 2);
+        assert!(report
+            .failed
+            .iter()
+            .all(|(_, err)| *err == FtApiScaleTeamsMultipleCreateError::missing()));
+        assert_eq!(report.unattributed.len(), 1);
+        assert_eq!(
+            report.unattributed[0].to_string(),
+            r#"{"begin_at":["is not available"]}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn multiple_create_dry_run_lists_simulated_bodies() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let client = mock.client().with_dry_run(true);
+        let session = client.open_session(FtMockServer::token());
+
+        let report = session
+            .scale_teams_multiple_create_post(FtApiScaleTeamsMultipleCreateRequest::new(vec![
+                body(10, 1),
+                body(11, 2),
+            ]))
+            .await
+            .unwrap();
+        assert_eq!(report.simulated, vec![body(10, 1), body(11, 2)]);
+        assert!(report.created.is_empty());
+        assert!(report.is_complete());
+        assert!(mock.server().received_requests().await.unwrap().is_empty());
+    }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn with_filter() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -20,7 +20,50 @@ pub struct FtApiScaleTeamsIdPatchRequest {
     pub scale_id: FtScaleId,
 }
 
-pub struct FtApiScaleTeamsIdPatchBody {}
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsIdUpdateRequest {
+    pub id: FtScaleTeamId,
+    pub scale_team: FtApiScaleTeamsIdUpdateBody,
+}
+
+/// Fields of a scale team that can be updated. Unset fields are left untouched.
+///
+/// `answers_attributes` fills the questions of the scale, `flag_id` sets the outcome flag
+/// (`ok`, `cheat`, `norme`, ...) of the evaluation.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsIdUpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale_id: Option<FtScaleId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<FtScaleTeamComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<FtScaleTeamFeedback>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_mark: Option<FtFinalMark>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag_id: Option<FtFlagId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answers_attributes: Option<Vec<FtApiScaleTeamsAnswerAttributes>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsAnswerAttributes {
+    pub question_id: FtScaleQuestionId,
+    pub value: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiScaleTeamsIdUpdateResponse {}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsIdDeleteRequest {
+    pub id: FtScaleTeamId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiScaleTeamsIdDeleteResponse {}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FtApiScaleTeamsIdPatchResponse {}
@@ -42,6 +85,28 @@ where
         self.http_session_api.http_patch(url, &body).await
     }
 
+    /// Updates a scale team with a JSON body, e.g. to fill an evaluation programmatically.
+    ///
+    /// Only the fields set on `FtApiScaleTeamsIdUpdateBody` are sent to the API.
+    pub async fn scale_teams_id_update(
+        &self,
+        req: FtApiScaleTeamsIdUpdateRequest,
+    ) -> ClientResult<FtApiScaleTeamsIdUpdateResponse> {
+        let url = &format!("scale_teams/{}", req.id.value());
+        let body = serde_json::json!({ "scale_team": req.scale_team });
+        self.http_session_api.http_patch(url, &body).await
+    }
+
+    /// Cancels a scale team, e.g. one booked by mistake with `scale_teams_multiple_create_post`.
+    pub async fn scale_teams_id_delete(
+        &self,
+        req: FtApiScaleTeamsIdDeleteRequest,
+    ) -> ClientResult<FtApiScaleTeamsIdDeleteResponse> {
+        let url = &format!("scale_teams/{}", req.id.value());
+        let body = serde_json::json!({});
+        self.http_session_api.http_delete(url, &body).await
+    }
+
     pub async fn scale_teams_id(
         &self,
         req: FtApiScaleTeamsIdRequest,
@@ -53,11 +118,58 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
+    use crate::test_support::*;
+
+    #[test]
+    fn update_body_serde() {
+        let body = FtApiScaleTeamsIdUpdateBody::new()
+            .with_comment(FtScaleTeamComment::new("Well done".to_string()))
+            .with_final_mark(FtFinalMark::new(100))
+            .with_flag_id(FtFlagId::new(9))
+            .with_answers_attributes(vec![FtApiScaleTeamsAnswerAttributes::new(
+                FtScaleQuestionId::new(42),
+                1,
+            )]);
+
+        assert_eq!(
+            r#"{"comment":"Well done","final_mark":100,"flag_id":9,"answers_attributes":[{"question_id":42,"value":1}]}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn switch_scale_id_offline() {
+        let mock = FtMockServer::start().await;
+        mock.mock_json(
+            "PATCH",
+            &format!("scale_teams/{FT_MOCK_SCALE_TEAM_ID}"),
+            serde_json::json!({}),
+        )
+        .await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let res = session
+            .scale_teams_id_patch(FtApiScaleTeamsIdPatchRequest::new(
+                FtScaleTeamId::new(FT_MOCK_SCALE_TEAM_ID),
+                FtScaleId::new(55193),
+            ))
+            .await;
+        assert!(res.is_ok(), "Failed to switch to new scale_id: {:?}", res);
+
+        let requests = mock.server().received_requests().await.unwrap();
+        let patch = requests
+            .iter()
+            .find(|req| req.method.as_str() == "PATCH")
+            .unwrap();
+        assert_eq!(patch.url.query(), Some("scale_team[scale_id]=55193"));
+    }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn test_switch_scale_id() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -101,6 +213,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn scale_teams_id() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsIdFeedbacksPostRequest {
+    pub scale_team_id: FtScaleTeamId,
+    pub feedback: FtApiScaleTeamsIdFeedbacksPostBody,
+}
+
+/// The feedback given by the corrected side after a defense.
+///
+/// Intra computes the overall rating from `feedback_details_attributes` when they are given
+/// (`nice`, `rigorous`, `interested`, `punctuality`), `rating` can be set directly otherwise.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiScaleTeamsIdFeedbacksPostBody {
+    pub comment: FtComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<FtRating>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback_details_attributes: Option<Vec<FtApiFeedbackDetailAttributes>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiFeedbackDetailAttributes {
+    pub kind: String,
+    pub rate: i8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiScaleTeamsIdFeedbacksPostResponse {
+    pub feedback: FtFeedback,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Leaves a feedback on a scale team.
+    pub async fn scale_teams_id_feedbacks_post(
+        &self,
+        req: FtApiScaleTeamsIdFeedbacksPostRequest,
+    ) -> ClientResult<FtApiScaleTeamsIdFeedbacksPostResponse> {
+        let url = &format!("scale_teams/{}/feedbacks", req.scale_team_id.value());
+        let body = serde_json::json!({ "feedback": req.feedback });
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body = FtApiScaleTeamsIdFeedbacksPostBody::new(FtComment::new("Thanks!".to_string()))
+            .with_feedback_details_attributes(vec![FtApiFeedbackDetailAttributes::new(
+                "nice".to_string(),
+                4,
+            )]);
+
+        assert_eq!(
+            r#"{"comment":"Thanks!","feedback_details_attributes":[{"kind":"nice","rate":4}]}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
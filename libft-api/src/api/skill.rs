@@ -0,0 +1,32 @@
+//! API endpoints related to skills.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the skills a cursus
+//! evaluates, so the skill ids found in `FtCursusUser::skills` can be mapped to names.
+//!
+//! # Endpoints
+//!
+//! * **skills**: Retrieve a list of skills, optionally of a given cursus
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .skills(FtApiSkillsRequest::new().with_cursus_id(FtCursusId::new(21)))
+//!         .await?;
+//!     for skill in response.skills {
+//!         println!("{:?}: {:?}", skill.id, skill.name);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod skills;
+pub use skills::*;
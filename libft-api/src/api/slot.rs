@@ -0,0 +1,40 @@
+//! API endpoints related to evaluation slots.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the evaluation
+//! availabilities of users.
+//!
+//! # Endpoints
+//!
+//! * **slots**: Retrieve a list of slots with filtering, pagination, and sorting options
+//! * **slots_post**: Open evaluation slots for a user between two datetimes
+//! * **slots_id_delete**: Delete a slot
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{TimeDelta, Utc};
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let begin_at = Utc::now() + TimeDelta::hours(1);
+//!     let response = session
+//!         .slots_post(FtApiSlotsPostRequest::new(FtApiSlotsPostBody::new(
+//!             FtUserId::new(12345),
+//!             FtDateTimeUtc::new(begin_at),
+//!             FtDateTimeUtc::new(begin_at + TimeDelta::hours(2)),
+//!         )))
+//!         .await?;
+//!     println!("Opened {} slots", response.slots.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod slots;
+pub use slots::*;
+mod slots_id;
+pub use slots_id::*;
@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiSlotsRequest {
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiSlotsResponse {
+    pub slots: Vec<FtSlot>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiSlotsPostRequest {
+    pub slot: FtApiSlotsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiSlotsPostBody {
+    pub user_id: FtUserId,
+    pub begin_at: FtDateTimeUtc,
+    pub end_at: FtDateTimeUtc,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Opens evaluation slots for a user between `begin_at` and `end_at`.
+    ///
+    /// The API splits the availability into several slots, which are all returned.
+    pub async fn slots_post(&self, req: FtApiSlotsPostRequest) -> ClientResult<FtApiSlotsResponse> {
+        let url = "slots";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+
+    /// Retrieves the evaluation slots of the whole intra.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiSlotsRequest` struct containing the query parameters.
+    ///     - `filter` (optional): Must be one of `id`, `user_id`, `begin_at`, `end_at`, `future`, `end`,
+    ///       `campus_id`.
+    ///     - `range` (optional): Must be one of `id`, `user_id`, `begin_at`, `end_at`.
+    ///     - `sort`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiSlotsResponse>`: Contains a vector of `FtSlot` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn slots(&self, req: FtApiSlotsRequest) -> ClientResult<FtApiSlotsResponse> {
+        let url = "slots";
+
+        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiSlotsPostRequest::new(FtApiSlotsPostBody::new(
+            FtUserId::new(174_094),
+            FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 3, 10, 5, 0, 0).unwrap()),
+            FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 3, 10, 6, 0, 0).unwrap()),
+        ));
+
+        assert_eq!(
+            r#"{"slot":{"user_id":174094,"begin_at":"2025-03-10T05:00:00Z","end_at":"2025-03-10T06:00:00Z"}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .slots(
+                FtApiSlotsRequest::new()
+                    .with_filter(vec![FtFilterOption::new(
+                        FtFilterField::Future,
+                        vec!["true".to_string()],
+                    )])
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
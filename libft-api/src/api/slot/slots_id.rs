@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiSlotsIdDeleteRequest {
+    pub id: FtSlotId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiSlotsIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Deletes an evaluation slot. Booked slots can't be deleted.
+    pub async fn slots_id_delete(
+        &self,
+        req: FtApiSlotsIdDeleteRequest,
+    ) -> ClientResult<FtApiSlotsIdDeleteResponse> {
+        let url = &format!("slots/{}", req.id.value());
+        let body = serde_json::json!({});
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
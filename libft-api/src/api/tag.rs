@@ -0,0 +1,30 @@
+//! API endpoints related to tags.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the tags used by the
+//! forum and the notions.
+//!
+//! # Endpoints
+//!
+//! * **tags**: Retrieve a list of tags, optionally of a given kind
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session.tags(FtApiTagsRequest::new()).await?;
+//!     for tag in response.tags {
+//!         println!("{:?}: {}", tag.id, tag.name);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod tags;
+pub use tags::*;
@@ -0,0 +1,49 @@
+//! API endpoints related to team information.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with teams, independently
+//! of the project or project session they belong to.
+//!
+//! # Endpoints
+//!
+//! * **teams**: Retrieve a list of teams with filtering (status, project, campus), pagination, and sorting options
+//! * **teams_id**: Get a specific team by its ID
+//! * **teams_id_patch**: Update a team (closed_at, final_mark, status, terminating_at)
+//! * **teams_id_teams_users**: Get the members of a specific team
+//! * **teams_id_teams_uploads_post**: Push an automatic mark and comment for a team
+//! * **teams_users_post**: Add a user to a team
+//! * **teams_users_id_delete**: Remove a user from a team
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::{prelude::*, info::ft_campus_id::GYEONGSAN};
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     // Get the teams waiting for a correction on a campus
+//!     let response = session
+//!         .teams(
+//!             FtApiTeamsRequest::new()
+//!                 .with_campus_id(FtCampusId::new(GYEONGSAN))
+//!                 .with_status(FtStatus::new("waiting_for_correction".to_string())),
+//!         )
+//!         .await?;
+//!     println!("Found {} teams", response.teams.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod teams;
+pub use teams::*;
+mod teams_id;
+pub use teams_id::*;
+mod teams_id_teams_users;
+pub use teams_id_teams_users::*;
+mod teams_id_teams_uploads;
+pub use teams_id_teams_uploads::*;
+mod teams_users;
+pub use teams_users::*;
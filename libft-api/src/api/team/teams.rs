@@ -0,0 +1,153 @@
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsRequest {
+    pub project_id: Option<FtProjectId>,
+    pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+    pub status: Option<FtStatus>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiTeamsResponse {
+    pub teams: Vec<FtTeam>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the teams of the whole intra.
+    ///
+    /// `project_id`, `cursus_id`, `campus_id` and `status` are shortcuts for the corresponding
+    /// `filter[project_id]`, `filter[cursus]`, `filter[campus]` and `filter[status]` options.
+    ///
+    /// # Parameters
+    /// - `req`: A `FtApiTeamsRequest` struct containing the query parameters.
+    ///     - `project_id` (optional): Only teams of this project.
+    ///     - `cursus_id` (optional): Only teams of this cursus.
+    ///     - `campus_id` (optional): Only teams of this campus.
+    ///     - `status` (optional): Only teams in this status (`in_progress`, `waiting_for_correction`,
+    ///       `finished`, ...).
+    ///     - `sort`, `range`, `filter`, `page`, `per_page` (optional): The usual listing options.
+    ///
+    /// # Returns
+    /// - `ClientResult<FtApiTeamsResponse>`: Contains a vector of `FtTeam` objects
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn teams(&self, req: FtApiTeamsRequest) -> ClientResult<FtApiTeamsResponse> {
+        let url = "teams";
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(project_id) = req.project_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::ProjectId,
+                vec![project_id.to_string()],
+            ));
+        }
+        if let Some(cursus_id) = req.cursus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Cursus,
+                vec![cursus_id.to_string()],
+            ));
+        }
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::Campus,
+                vec![campus_id.to_string()],
+            ));
+        }
+        if let Some(status) = req.status {
+            filter.push(FtFilterOption::new(FtFilterField::Status, vec![status.0]));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .teams(FtApiTeamsRequest::new().with_per_page(1))
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn status_campus() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .teams(
+                FtApiTeamsRequest::new()
+                    .with_project_id(FtProjectId::new(1314))
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_status(FtStatus::new("finished".to_string()))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
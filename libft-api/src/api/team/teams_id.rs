@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsIdRequest {
+    pub id: FtTeamId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiTeamsIdResponse {
+    pub team: FtTeam,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsIdPatchRequest {
+    pub id: FtTeamId,
+    pub team: FtApiTeamsIdPatchBody,
+}
+
+/// Fields of a team that can be updated. Unset fields are left untouched.
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsIdPatchBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<FtDateTimeUtc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_mark: Option<FtFinalMark>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FtStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminating_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiTeamsIdPatchResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves a single team, including its users, scale teams and uploads.
+    pub async fn teams_id(&self, req: FtApiTeamsIdRequest) -> ClientResult<FtApiTeamsIdResponse> {
+        let url = &format!("teams/{}", req.id.value());
+
+        self.http_session_api
+            .http_get(url, &FT_HTTP_EMPTY_GET_PARAMS.clone())
+            .await
+    }
+
+    /// Updates a team, e.g. to close a stale team or to correct its final mark.
+    ///
+    /// Only the fields set on `FtApiTeamsIdPatchBody` are sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn teams_id_patch(
+        &self,
+        req: FtApiTeamsIdPatchRequest,
+    ) -> ClientResult<FtApiTeamsIdPatchResponse> {
+        let url = &format!("teams/{}", req.id.value());
+        let body = serde_json::json!({ "team": req.team });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patch_body_serde() {
+        let body = FtApiTeamsIdPatchBody::new()
+            .with_final_mark(FtFinalMark::new(100))
+            .with_status(FtStatus::new("finished".to_string()));
+
+        assert_eq!(
+            r#"{"final_mark":100,"status":"finished"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+        let client = FtClient::new(FtClientReqwestConnector::new());
+        let session = client.open_session(token);
+
+        let res = session
+            .teams_id(FtApiTeamsIdRequest::new(FtTeamId::new(6_298_862)))
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
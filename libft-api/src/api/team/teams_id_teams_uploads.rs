@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsIdTeamsUploadsPostRequest {
+    pub team_id: FtTeamId,
+    pub teams_upload: FtApiTeamsIdTeamsUploadsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsIdTeamsUploadsPostBody {
+    pub final_mark: FtFinalMark,
+    pub comment: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiTeamsIdTeamsUploadsPostResponse {
+    pub teams_upload: FtTeamUpload,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Pushes the result of an automatic evaluation (moulinette) for a team.
+    ///
+    /// The mark and the comment are sent as JSON, which is all the endpoint needs when no file
+    /// is attached to the upload.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn teams_id_teams_uploads_post(
+        &self,
+        req: FtApiTeamsIdTeamsUploadsPostRequest,
+    ) -> ClientResult<FtApiTeamsIdTeamsUploadsPostResponse> {
+        let url = &format!("teams/{}/teams_uploads", req.team_id.value());
+        let body = serde_json::json!({ "teams_upload": req.teams_upload });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body =
+            FtApiTeamsIdTeamsUploadsPostBody::new(FtFinalMark::new(100), "All tests passed".into());
+
+        assert_eq!(
+            r#"{"final_mark":100,"comment":"All tests passed"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsUsersPostRequest {
+    pub teams_user: FtApiTeamsUsersPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsUsersPostBody {
+    pub team_id: FtTeamId,
+    pub user_id: FtUserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leader: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiTeamsUsersPostResponse {
+    pub teams_user: FtTeamsUser,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTeamsUsersIdDeleteRequest {
+    pub id: FtTeamsUserId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiTeamsUsersIdDeleteResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Adds a user to a team.
+    ///
+    /// Set `leader` to make the new member the leader of the team.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn teams_users_post(
+        &self,
+        req: FtApiTeamsUsersPostRequest,
+    ) -> ClientResult<FtApiTeamsUsersPostResponse> {
+        let url = "teams_users";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+
+    /// Removes a user from a team.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn teams_users_id_delete(
+        &self,
+        req: FtApiTeamsUsersIdDeleteRequest,
+    ) -> ClientResult<FtApiTeamsUsersIdDeleteResponse> {
+        let url = &format!("teams_users/{}", req.id.value());
+        let body = serde_json::json!({});
+
+        self.http_session_api.http_delete(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiTeamsUsersPostRequest::new(FtApiTeamsUsersPostBody::new(
+            FtTeamId::new(6_298_862),
+            FtUserId::new(174_094),
+        ));
+
+        assert_eq!(
+            r#"{"teams_user":{"team_id":6298862,"user_id":174094}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+}
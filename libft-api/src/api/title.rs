@@ -0,0 +1,36 @@
+//! API endpoints related to titles.
+//!
+//! This module provides access to the 42 Intra API endpoints that grant titles to users and
+//! select the one displayed on their profile.
+//!
+//! # Endpoints
+//!
+//! * **titles_users_post**: Grant a title to a user
+//! * **titles_users_id_patch**: Update a granted title, e.g. to select it
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     for user_id in [212_750, 180_844] {
+//!         session
+//!             .titles_users_post(FtApiTitlesUsersPostRequest::new(
+//!                 FtApiTitlesUsersPostBody::new(FtUserId::new(user_id), FtTitleId::new(1107)),
+//!             ))
+//!             .await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod titles_users;
+pub use titles_users::*;
+mod titles_users_id;
+pub use titles_users_id::*;
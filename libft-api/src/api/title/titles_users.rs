@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTitlesUsersPostRequest {
+    pub titles_user: FtApiTitlesUsersPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTitlesUsersPostBody {
+    pub user_id: FtUserId,
+    pub title_id: FtTitleId,
+    /// Whether the title is displayed on the user's profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiTitlesUsersPostResponse {
+    pub titles_user: FtTitleUser,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Grants a title to a user.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn titles_users_post(
+        &self,
+        req: FtApiTitlesUsersPostRequest,
+    ) -> ClientResult<FtApiTitlesUsersPostResponse> {
+        let url = "titles_users";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiTitlesUsersPostRequest::new(
+            FtApiTitlesUsersPostBody::new(FtUserId::new(212_750), FtTitleId::new(1107))
+                .with_selected(true),
+        );
+
+        assert_eq!(
+            r#"{"titles_user":{"user_id":212750,"title_id":1107,"selected":true}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+}
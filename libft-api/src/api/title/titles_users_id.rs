@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTitlesUsersIdPatchRequest {
+    pub id: FtTitleUserId,
+    pub titles_user: FtApiTitlesUsersIdPatchBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTitlesUsersIdPatchBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FtApiTitlesUsersIdPatchResponse {}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Updates a title granted to a user, e.g. to display it on their profile.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn titles_users_id_patch(
+        &self,
+        req: FtApiTitlesUsersIdPatchRequest,
+    ) -> ClientResult<FtApiTitlesUsersIdPatchResponse> {
+        let url = &format!("titles_users/{}", req.id.value());
+        let body = serde_json::json!({ "titles_user": req.titles_user });
+
+        self.http_session_api.http_patch(url, &body).await
+    }
+}
@@ -0,0 +1,32 @@
+//! API endpoints related to wallet transactions.
+//!
+//! This module provides access to the 42 Intra API endpoints that read and create the
+//! transactions making up the users' wallets.
+//!
+//! # Endpoints
+//!
+//! * **transactions**: Retrieve a list of transactions with filtering, pagination, and sorting options
+//! * **transactions_post**: Add or remove points from a user's wallet
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .transactions(FtApiTransactionsRequest::new().with_user_id(FtUserId::new(212_750)))
+//!         .await?;
+//!     let wallet: i32 = response.transactions.iter().map(|t| t.value).sum();
+//!     println!("Wallet: {wallet}");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod transactions;
+pub use transactions::*;
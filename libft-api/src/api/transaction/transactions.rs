@@ -0,0 +1,185 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTransactionsRequest {
+    pub user_id: Option<FtUserId>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiTransactionsResponse {
+    pub transactions: Vec<FtTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTransactionsPostRequest {
+    pub transaction: FtApiTransactionsPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiTransactionsPostBody {
+    pub user_id: FtUserId,
+    /// The amount of wallet points, negative to remove points.
+    pub value: i32,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactable_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactable_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiTransactionsPostResponse {
+    pub transaction: FtTransaction,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the wallet transactions of the whole intra.
+    ///
+    /// `user_id` is a shortcut for the corresponding `filter[user_id]` option.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiTransactionsRequest` object containing the parameters for the API call.
+    ///     - `user_id` (optional): `FtUserId`
+    ///       Only transactions of this user's wallet.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `value`, `user_id`, `transactable_id`, `transactable_type`,
+    ///       `created_at`, `updated_at`, `reason`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `value`, `user_id`, `transactable_id`, `transactable_type`,
+    ///       `created_at`, `updated_at`, `reason`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `value`, `user_id`, `transactable_id`, `transactable_type`,
+    ///       `created_at`, `updated_at`, `reason`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiTransactionsResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn transactions(
+        &self,
+        req: FtApiTransactionsRequest,
+    ) -> ClientResult<FtApiTransactionsResponse> {
+        let url = "transactions";
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(user_id) = req.user_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::UserId,
+                vec![user_id.to_string()],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+
+    /// Adds or removes points from a user's wallet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn transactions_post(
+        &self,
+        req: FtApiTransactionsPostRequest,
+    ) -> ClientResult<FtApiTransactionsPostResponse> {
+        let url = "transactions";
+
+        self.http_session_api.http_post(url, &req).await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_serde() {
+        let req = FtApiTransactionsPostRequest::new(FtApiTransactionsPostBody::new(
+            FtUserId::new(212_750),
+            -20,
+            "Piscine t-shirt".to_string(),
+        ));
+
+        assert_eq!(
+            r#"{"transaction":{"user_id":212750,"value":-20,"reason":"Piscine t-shirt"}}"#,
+            serde_json::to_string(&req).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .transactions(
+                FtApiTransactionsRequest::new()
+                    .with_user_id(FtUserId::new(crate::info::TEST_USER_YONDOO_ID))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,32 @@
+//! API endpoints related to translations.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the localized strings of
+//! projects, quests and other translatable objects.
+//!
+//! # Endpoints
+//!
+//! * **translations**: Retrieve a list of translations with filtering, pagination, and sorting options
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .translations(FtApiTranslationsRequest::new().with_per_page(10))
+//!         .await?;
+//!     for translation in response.translations {
+//!         println!("{} {}: {:?}", translation.translatable_type, translation.translatable_id, translation.fields);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod translations;
+pub use translations::*;
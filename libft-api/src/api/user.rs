@@ -16,10 +16,11 @@
 //! * **users_id_projects_users**: Get project associations for a specific user
 //! * **users_id_correction_point_historics**: Get correction point history for a specific user
 //! * **users_id_correction_points_add**: Add correction points to a specific user
+//! * **users_id_closes_post**: Close the account of a specific user
 //!
 //! # Example
 //!
-//! ```rust
+//! ```rust,no_run
 //! use libft_api::prelude::*;
 //! use rvstruct::ValueStruct;
 //!
@@ -52,6 +53,8 @@ mod users_id;
 pub use users_id::*;
 mod users_id_correction_point_historics;
 pub use users_id_correction_point_historics::*;
+mod users_id_closes;
+pub use users_id_closes::*;
 mod users_id_correction_points_add;
 pub use users_id_correction_points_add::*;
 mod users_id_locations;
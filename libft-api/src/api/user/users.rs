@@ -59,7 +59,7 @@ where
     /// - `ClientResult<FtApiUserPostsResponse>`: Contains the created `FtUser` object
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
     /// use libft_api::prelude::*;
     ///
     /// # async fn run() -> ClientResult<()> {
@@ -149,41 +149,57 @@ where
     /// ```
     pub async fn users(&self, req: FtApiUsersRequest) -> ClientResult<FtApiUsersResponse> {
         let url = "users";
-        let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
-        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
-
-        let params = vec![
-            to_param!(req, page),
-            to_param!(req, per_page),
-            (
-                "sort".to_string(),
-                req.sort.as_ref().map(|v| {
-                    v.iter()
-                        .map(|v| {
-                            format!(
-                                "{}{}",
-                                if v.descending { "-" } else { "" },
-                                serde_plain::to_string(&v.field).unwrap()
-                            )
-                        })
-                        .collect::<Vec<_>>()
-                        .join(",")
-                }),
-            ),
-        ];
 
         self.http_session_api
-            .http_get(url, &[filters, range, params].concat())
+            .http_get(url, &users_params(req))
             .await
     }
+
+    /// `users`, returning the page undecoded, to deserialize its records one at a time.
+    pub async fn users_raw(&self, req: FtApiUsersRequest) -> ClientResult<FtRawPage<FtUser>> {
+        let url = "users";
+
+        self.http_session_api
+            .http_get_raw(url, &users_params(req))
+            .await
+    }
+}
+
+/// The query parameters of `users`.
+fn users_params(req: FtApiUsersRequest) -> Vec<(String, Option<String>)> {
+    let filters = convert_filter_option_to_tuple(req.filter.unwrap_or_default()).unwrap();
+    let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+    let params = vec![
+        to_param!(req, page),
+        to_param!(req, per_page),
+        (
+            "sort".to_string(),
+            req.sort.as_ref().map(|v| {
+                v.iter()
+                    .map(|v| {
+                        format!(
+                            "{}{}",
+                            if v.descending { "-" } else { "" },
+                            serde_plain::to_string(&v.field).unwrap()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        ),
+    ];
+
+    [filters, range, params].concat()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
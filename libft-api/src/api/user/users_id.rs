@@ -107,14 +107,60 @@ where
             .http_get(url, &[filters, range, params].concat())
             .await
     }
+
+    /// Retrieves the users identified by `ids`, running up to `concurrency` requests at a time.
+    ///
+    /// The results come back in the order of `ids`. Rate-limited requests are retried, and the
+    /// error of a user that cannot be fetched is returned in its place; see [`fetch_many`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example() -> ClientResult<()> {
+    ///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+    ///     let client = FtClient::with_ratelimits(FtClientReqwestConnector::new(), 8, 1600);
+    ///     let session = client.open_session(token);
+    ///
+    ///     let ids = [FtUserId::new(12345), FtUserId::new(12346)];
+    ///     for (id, user) in ids.iter().zip(session.fetch_users(ids, 8).await) {
+    ///         match user {
+    ///             Ok(user) => println!("{id}: {:?}", user.login),
+    ///             Err(err) => eprintln!("{id}: {err}"),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_users(
+        &self,
+        ids: impl IntoIterator<Item = FtUserId>,
+        concurrency: usize,
+    ) -> Vec<ClientResult<FtUser>> {
+        let client = self.http_session_api.client;
+        fetch_many_with_hooks(
+            ids,
+            concurrency,
+            client.hooks(),
+            &client.cancellation,
+            |id| async move {
+                self.users_id(FtApiUsersIdRequest::new(FtUserIdentifier::UserId(id)))
+                    .await
+                    .map(|res| res.user)
+            },
+        )
+        .await
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
 
     use super::*;
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn basic() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,64 @@
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiUsersIdClosesPostRequest {
+    pub user_id: FtUserId,
+    pub close: FtApiUsersIdClosesPostBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiUsersIdClosesPostBody {
+    pub kind: FtCloseKind,
+    pub reason: String,
+    /// The staff member closing the account, defaults to the owner of the token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closer_id: Option<FtUserId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[serde(transparent)]
+pub struct FtApiUsersIdClosesPostResponse {
+    pub close: FtClose,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Closes the account of a user.
+    ///
+    /// Use `closes_id_unclose` with the returned close id to reopen the account.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn users_id_closes_post(
+        &self,
+        req: FtApiUsersIdClosesPostRequest,
+    ) -> ClientResult<FtApiUsersIdClosesPostResponse> {
+        let url = &format!("users/{}/closes", req.user_id.value());
+        let body = serde_json::json!({ "close": req.close });
+
+        self.http_session_api.http_post(url, &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_body_serde() {
+        let body =
+            FtApiUsersIdClosesPostBody::new(FtCloseKind::NonAdmitted, "End of piscine".to_string());
+
+        assert_eq!(
+            r#"{"kind":"non_admitted","reason":"End of piscine"}"#,
+            serde_json::to_string(&body).unwrap()
+        );
+    }
+}
@@ -31,7 +31,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use super::*;
 
@@ -49,6 +49,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn correction_points_add_test() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -60,12 +60,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use crate::prelude::*;
 
     /// Checks the filter[active] is working properly.
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn is_active() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -58,12 +58,13 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod tests {
     use crate::prelude::*;
     use chrono::{Days, Local};
 
     #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
     async fn specific_date_range() {
         let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
             .await
@@ -0,0 +1,37 @@
+//! API endpoints related to user candidatures.
+//!
+//! This module provides access to the 42 Intra API endpoints that list the admission files
+//! filled in by applicants before their piscine.
+//!
+//! # Endpoints
+//!
+//! * **user_candidatures**: Retrieve a list of candidatures, optionally for one campus and piscine
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .user_candidatures(
+//!             FtApiUserCandidaturesRequest::new()
+//!                 .with_campus_id(FtCampusId::new(69))
+//!                 .with_pool_month(FtPoolMonth::July)
+//!                 .with_pool_year(FtPoolYear::new("2024".to_string())),
+//!         )
+//!         .await?;
+//!     for candidature in response.user_candidatures {
+//!         println!("{:?}: {:?}", candidature.user_id, candidature.piscine_date);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod user_candidatures;
+pub use user_candidatures::*;
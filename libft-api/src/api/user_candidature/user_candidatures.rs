@@ -0,0 +1,151 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::to_param;
+use libft_api_derive::HasVector;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+pub struct FtApiUserCandidaturesRequest {
+    pub campus_id: Option<FtCampusId>,
+    pub pool_month: Option<FtPoolMonth>,
+    pub pool_year: Option<FtPoolYear>,
+    pub sort: Option<Vec<FtSortOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub page: Option<u16>,
+    pub per_page: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, HasVector)]
+#[serde(transparent)]
+pub struct FtApiUserCandidaturesResponse {
+    pub user_candidatures: Vec<FtUserCandidature>,
+}
+
+impl<FCHC> FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Retrieves the user candidatures (piscine admission files) of the intra.
+    ///
+    /// The `campus_id`, `pool_month` and `pool_year` shortcuts are pushed onto `filter`, so that
+    /// the candidatures of one piscine can be fetched directly.
+    ///
+    /// # Parameters
+    ///
+    /// - `req`: An `FtApiUserCandidaturesRequest` object containing the parameters for the API call.
+    ///     - `campus_id` (optional): `FtCampusId`
+    ///       Only candidatures of this campus.
+    ///     - `pool_month` (optional): `FtPoolMonth`
+    ///       Only candidatures for the piscine of this month.
+    ///     - `pool_year` (optional): `FtPoolYear`
+    ///       Only candidatures for the piscine of this year.
+    ///     - `sort` (optional): `Vec<FtSortOption>`
+    ///       Must be one of: `id`, `user_id`, `birth_date`, `piscine_date`, `meeting_date`, `created_at`, `updated_at`.
+    ///       The sort field.
+    ///     - `filter` (optional): `Vec<FtFilterOption>`
+    ///       Must be one of: `id`, `user_id`, `campus_id`, `pool_month`, `pool_year`, `piscine_date`, `created_at`, `updated_at`.
+    ///       Filtering on one or more fields.
+    ///     - `range` (optional): `Vec<FtRangeOption>`
+    ///       Must be one of: `id`, `user_id`, `birth_date`, `piscine_date`, `meeting_date`, `created_at`, `updated_at`.
+    ///       Select on a particular range.
+    ///     - `page` (optional): `u16`
+    ///       The current page number.
+    ///     - `per_page` (optional): `u8`
+    ///       The number of items per page, defaults to 30, maximum 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClientResult<FtApiUserCandidaturesResponse>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or the response cannot be parsed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the conversion of filter or range options fails, or if serialization of sort fields fails.
+    pub async fn user_candidatures(
+        &self,
+        req: FtApiUserCandidaturesRequest,
+    ) -> ClientResult<FtApiUserCandidaturesResponse> {
+        let url = "user_candidatures";
+
+        let mut filter = req.filter.unwrap_or_default();
+        if let Some(campus_id) = req.campus_id {
+            filter.push(FtFilterOption::new(
+                FtFilterField::CampusId,
+                vec![campus_id.to_string()],
+            ));
+        }
+        if let Some(pool_month) = req.pool_month {
+            filter.push(FtFilterOption::new(
+                FtFilterField::PoolMonth,
+                vec![serde_plain::to_string(&pool_month).unwrap()],
+            ));
+        }
+        if let Some(pool_year) = req.pool_year {
+            filter.push(FtFilterOption::new(
+                FtFilterField::PoolYear,
+                vec![pool_year.0],
+            ));
+        }
+
+        let filters = convert_filter_option_to_tuple(filter).unwrap();
+        let range = convert_range_option_to_tuple(req.range.unwrap_or_default()).unwrap();
+
+        let params = vec![
+            to_param!(req, page),
+            to_param!(req, per_page),
+            (
+                "sort".to_string(),
+                req.sort.as_ref().map(|v| {
+                    v.iter()
+                        .map(|v| {
+                            format!(
+                                "{}{}",
+                                if v.descending { "-" } else { "" },
+                                serde_plain::to_string(&v.field).unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }),
+            ),
+        ];
+
+        self.http_session_api
+            .http_get(url, &[filters, range, params].concat())
+            .await
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "online-tests"), ignore = "needs live API credentials")]
+    async fn basic() {
+        let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+            .await
+            .unwrap();
+
+        let client = FtClient::new(FtClientReqwestConnector::with_connector(
+            reqwest::Client::new(),
+        ));
+
+        let session = client.open_session(token);
+        let res = session
+            .user_candidatures(
+                FtApiUserCandidaturesRequest::new()
+                    .with_campus_id(FtCampusId::new(crate::info::ft_campus_id::GYEONGSAN))
+                    .with_pool_year(FtPoolYear::new("2024".to_string()))
+                    .with_per_page(1),
+            )
+            .await;
+
+        assert!(res.is_ok());
+    }
+}
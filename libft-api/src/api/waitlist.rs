@@ -0,0 +1,33 @@
+//! API endpoints related to waitlists.
+//!
+//! This module provides access to the 42 Intra API endpoints that deal with the waitlists of full
+//! events and exams.
+//!
+//! # Endpoints
+//!
+//! * **waitlists**: Retrieve a list of waitlists, optionally for a given event or exam
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let response = session
+//!         .waitlists(
+//!             FtApiWaitlistsRequest::new()
+//!                 .with_waitlistable(FtWaitlistable::Event(FtEventId::new(29_107))),
+//!         )
+//!         .await?;
+//!     println!("Found {} waitlists", response.waitlists.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod waitlists;
+pub use waitlists::*;
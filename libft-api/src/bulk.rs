@@ -0,0 +1,292 @@
+//! Bulk maintenance helpers built on the mutating endpoints.
+//!
+//! Each helper first builds a plan listing every change it would make, so the changes can be
+//! reviewed (the plan implements `Display`) before `apply` sends them. Mutations stopped by the
+//! rate limit or a server error can be kept in an `FtOutbox` and replayed on the next run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{TimeDelta, Utc};
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let filter = FtStaleTeamsFilter::new()
+//!         .with_campus_id(FtCampusId::new(ft_campus_id::GYEONGSAN))
+//!         .with_cursus_id(FtCursusId::new(FT_PISCINE_CURSUS_ID))
+//!         .with_updated_before(Utc::now() - TimeDelta::days(14));
+//!     let plan = close_stale_teams(&session, filter, FtFinalMarkPolicy::Zero).await?;
+//!     println!("{plan}");
+//!
+//!     let report = plan.apply(&session).await;
+//!     for (team_id, err) in &report.failed {
+//!         eprintln!("team {team_id}: {err}");
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub mod coalition;
+pub use coalition::*;
+pub mod outbox;
+pub use outbox::*;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "teams")]
+use futures::TryStreamExt;
+use rsb_derive::Builder;
+use rvstruct::ValueStruct;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+/// Which teams `close_stale_teams` closes.
+///
+/// Teams already closed are always skipped.
+#[derive(Debug, Clone, Builder)]
+pub struct FtStaleTeamsFilter {
+    pub project_id: Option<FtProjectId>,
+    pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+    /// Only teams not updated since this date.
+    pub updated_before: Option<DateTime<Utc>>,
+    pub filter: Option<Vec<FtFilterOption>>,
+    pub range: Option<Vec<FtRangeOption>>,
+}
+
+/// What happens to the final mark of a closed team.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FtFinalMarkPolicy {
+    /// Leave the final mark untouched.
+    Keep,
+    /// Set the final mark to 0.
+    Zero,
+    /// Set the final mark to the given value.
+    Set(i32),
+}
+
+/// A team `close_stale_teams` would close.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtTeamClosing {
+    pub team_id: FtTeamId,
+    pub name: Option<FtTeamName>,
+    pub status: Option<FtStatus>,
+    pub final_mark: Option<FtFinalMark>,
+    /// The final mark after closing, `None` when left untouched.
+    pub new_final_mark: Option<FtFinalMark>,
+    pub closed_at: DateTime<Utc>,
+}
+
+/// The changes planned by `close_stale_teams`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtTeamClosingPlan {
+    pub closings: Vec<FtTeamClosing>,
+}
+
+/// The outcome of `FtTeamClosingPlan::apply`.
+#[derive(Debug, Default)]
+pub struct FtTeamClosingReport {
+    pub closed: Vec<FtTeamId>,
+    pub failed: Vec<(FtTeamId, FtClientError)>,
+    /// Teams left open because the cancellation token of the client was cancelled.
+    pub cancelled: Vec<FtTeamId>,
+    /// Teams left open because the client is in dry-run mode.
+    pub simulated: Vec<FtTeamId>,
+}
+
+/// Lists the teams matching `filter` and plans their closing, the final mark following `policy`.
+///
+/// Nothing is changed until `FtTeamClosingPlan::apply` is called. Once the cancellation token of
+/// the client is cancelled, the plan covers the pages fetched so far.
+///
+/// # Errors
+///
+/// Returns an error if a page of teams cannot be fetched.
+#[cfg(feature = "teams")]
+pub async fn close_stale_teams<FCHC>(
+    session: &FtClientSession<'_, FCHC>,
+    filter: FtStaleTeamsFilter,
+    policy: FtFinalMarkPolicy,
+) -> ClientResult<FtTeamClosingPlan>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    const PER_PAGE: u8 = 100;
+
+    let closed_at = Utc::now();
+    let cancellation = session.http_session_api.client.cancellation.clone();
+    let teams = paginate_until(PER_PAGE, cancellation, |page| {
+        let mut req = FtApiTeamsRequest::new()
+            .with_page(page)
+            .with_per_page(PER_PAGE);
+        req.project_id = filter.project_id.clone();
+        req.cursus_id = filter.cursus_id.clone();
+        req.campus_id = filter.campus_id.clone();
+        req.filter = filter.filter.clone();
+        req.range = filter.range.clone();
+        session.teams(req)
+    })
+    .try_collect::<Vec<_>>()
+    .await?;
+
+    let closings = teams
+        .into_iter()
+        .filter(|team| is_stale(team, filter.updated_before))
+        .map(|team| FtTeamClosing {
+            team_id: team.id,
+            name: team.name,
+            status: team.status,
+            new_final_mark: match policy {
+                FtFinalMarkPolicy::Keep => None,
+                FtFinalMarkPolicy::Zero => Some(FtFinalMark::new(0)),
+                FtFinalMarkPolicy::Set(mark) => Some(FtFinalMark::new(mark)),
+            },
+            final_mark: team.final_mark,
+            closed_at,
+        })
+        .collect();
+    Ok(FtTeamClosingPlan { closings })
+}
+
+#[cfg(feature = "teams")]
+fn is_stale(team: &FtTeam, updated_before: Option<DateTime<Utc>>) -> bool {
+    if team.closed == Some(true) || team.closed_at.is_some() {
+        return false;
+    }
+    match (updated_before, &team.updated_at) {
+        (Some(updated_before), Some(updated_at)) => *updated_at.value() < updated_before,
+        (Some(_), None) => false,
+        (None, _) => true,
+    }
+}
+
+impl FtTeamClosingPlan {
+    pub fn is_empty(&self) -> bool {
+        self.closings.is_empty()
+    }
+
+    /// Closes every planned team, one `teams_id_patch` call each.
+    ///
+    /// A failed call does not stop the others; it is reported in `FtTeamClosingReport::failed`.
+    /// Once the cancellation token of the client is cancelled, the teams not yet closed are
+    /// reported in `FtTeamClosingReport::cancelled`, and in dry-run mode every team is reported
+    /// in `FtTeamClosingReport::simulated`.
+    #[cfg(feature = "teams")]
+    pub async fn apply<FCHC>(self, session: &FtClientSession<'_, FCHC>) -> FtTeamClosingReport
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        let mut report = FtTeamClosingReport::default();
+        for closing in self.closings {
+            if session.http_session_api.client.is_cancelled() {
+                report.cancelled.push(closing.team_id);
+                continue;
+            }
+            let mut body = FtApiTeamsIdPatchBody::new()
+                .with_closed_at(FtDateTimeUtc::new(closing.closed_at))
+                .with_status(FtStatus::new("finished".to_string()));
+            body.final_mark = closing.new_final_mark;
+
+            match session
+                .teams_id_patch(FtApiTeamsIdPatchRequest::new(closing.team_id.clone(), body))
+                .await
+            {
+                Ok(_) => report.closed.push(closing.team_id),
+                Err(err) if err.is_dry_run() => report.simulated.push(closing.team_id),
+                Err(err) => report.failed.push((closing.team_id, err)),
+            }
+        }
+        report
+    }
+}
+
+impl Display for FtTeamClosingPlan {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for closing in &self.closings {
+            let mark =
+                |mark: Option<&FtFinalMark>| mark.map_or("-".to_string(), ToString::to_string);
+            writeln!(
+                f,
+                "team {} ({}): status {} -> finished, final mark {} -> {}",
+                closing.team_id,
+                closing
+                    .name
+                    .as_ref()
+                    .map_or("unnamed", |name| name.value().as_str()),
+                closing
+                    .status
+                    .as_ref()
+                    .map_or("-", |status| status.value().as_str()),
+                mark(closing.final_mark.as_ref()),
+                mark(
+                    closing
+                        .new_final_mark
+                        .as_ref()
+                        .or(closing.final_mark.as_ref())
+                ),
+            )?;
+        }
+        write!(f, "{} team(s) to close", self.closings.len())
+    }
+}
+
+#[cfg(all(test, feature = "teams"))]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn team(id: i32, closed: bool, updated_at: &str) -> FtTeam {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": format!("team{id}"),
+            "status": "in_progress",
+            "final_mark": null,
+            "closed": closed,
+            "updated_at": updated_at,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn stale_teams() {
+        let updated_before = Some(Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap());
+
+        assert!(is_stale(
+            &team(1, false, "2025-01-15T00:00:00.000Z"),
+            updated_before
+        ));
+        assert!(!is_stale(
+            &team(2, false, "2025-02-15T00:00:00.000Z"),
+            updated_before
+        ));
+        assert!(!is_stale(
+            &team(3, true, "2025-01-15T00:00:00.000Z"),
+            updated_before
+        ));
+    }
+
+    #[test]
+    fn plan_display() {
+        let plan = FtTeamClosingPlan {
+            closings: vec![FtTeamClosing {
+                team_id: FtTeamId::new(1),
+                name: Some(FtTeamName::new("team1".to_string())),
+                status: Some(FtStatus::new("in_progress".to_string())),
+                final_mark: None,
+                new_final_mark: Some(FtFinalMark::new(0)),
+                closed_at: Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap(),
+            }],
+        };
+
+        assert_eq!(
+            plan.to_string(),
+            "team 1 (team1): status in_progress -> finished, final mark - -> 0\n1 team(s) to close"
+        );
+    }
+}
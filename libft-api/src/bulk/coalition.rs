@@ -0,0 +1,497 @@
+use chrono::{DateTime, Utc};
+#[cfg(all(feature = "users", feature = "coalitions"))]
+use rvstruct::ValueStruct;
+use serde::Serialize;
+#[cfg(all(feature = "users", feature = "coalitions"))]
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::export::csv::to_csv_string;
+use crate::export::{FtCoalitionAwardAuditColumn, FtExportColumn, FtExportError};
+use crate::prelude::*;
+
+/// Points to award to the coalition of a student, negative to remove points.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtCoalitionAward {
+    pub login: FtLoginId,
+    pub points: i32,
+    pub reason: String,
+}
+
+impl FtCoalitionAward {
+    pub fn new(login: FtLoginId, points: i32, reason: impl Into<String>) -> Self {
+        Self {
+            login,
+            points,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// An award resolved to the coalitions user it is posted for.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtCoalitionScoring {
+    pub award: FtCoalitionAward,
+    pub user_id: FtUserId,
+    pub coalition_id: FtCoalitionId,
+    pub coalitions_user_id: FtCoalitionsUserId,
+}
+
+/// The scores planned by `plan_coalition_awards`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtCoalitionAwardPlan {
+    pub scorings: Vec<FtCoalitionScoring>,
+    /// Awards whose login could not be resolved to a coalitions user, with the reason.
+    pub unresolved: Vec<(FtCoalitionAward, String)>,
+    /// Awards left unresolved because the cancellation token of the client was cancelled.
+    pub cancelled: Vec<FtCoalitionAward>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCoalitionAwardStatus {
+    Scored,
+    Unresolved,
+    Failed,
+    /// Not posted because the cancellation token of the client was cancelled.
+    Cancelled,
+    /// Not posted because the client is in dry-run mode.
+    Simulated,
+}
+
+/// One row of the audit trail of `FtCoalitionAwardPlan::apply`.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct FtCoalitionAwardAudit {
+    pub login: FtLoginId,
+    pub user_id: Option<FtUserId>,
+    pub coalition_id: Option<FtCoalitionId>,
+    pub coalitions_user_id: Option<FtCoalitionsUserId>,
+    pub points: i32,
+    pub reason: String,
+    pub status: FtCoalitionAwardStatus,
+    pub score_id: Option<FtScoreId>,
+    pub error: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// The outcome of `FtCoalitionAwardPlan::apply`, one audit row per award.
+#[derive(Debug, Default)]
+pub struct FtCoalitionAwardReport {
+    pub audit: Vec<FtCoalitionAwardAudit>,
+}
+
+/// Resolves each award to the coalitions user of its login.
+///
+/// When a student belongs to several coalitions (e.g. piscine and cursus), only those of
+/// `coalition_ids` are considered if given, and the most recent one wins. Unknown logins and
+/// students without a matching coalition are kept in `FtCoalitionAwardPlan::unresolved` instead
+/// of failing the whole plan, and the awards left once the cancellation token of the client is
+/// cancelled in `FtCoalitionAwardPlan::cancelled`.
+///
+/// Nothing is posted until `FtCoalitionAwardPlan::apply` is called.
+///
+/// # Errors
+///
+/// Returns the error of a lookup failing for another reason, e.g. a rate limit or a network
+/// error, rather than dropping the login from the plan.
+///
+/// # Example
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let awards = vec![
+///         FtCoalitionAward::new(FtLoginId::new("hdoo".to_string()), 42, "Hackathon winner"),
+///         FtCoalitionAward::new(FtLoginId::new("gyeon".to_string()), 10, "Hackathon staff"),
+///     ];
+///     let plan = plan_coalition_awards(&session, awards, None).await?;
+///     println!("{plan}");
+///
+///     let report = plan.apply(&session).await;
+///     std::fs::write("hackathon_audit.csv", report.to_audit_csv()?)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(all(feature = "users", feature = "coalitions"))]
+pub async fn plan_coalition_awards<FCHC>(
+    session: &FtClientSession<'_, FCHC>,
+    awards: Vec<FtCoalitionAward>,
+    coalition_ids: Option<&[FtCoalitionId]>,
+) -> ClientResult<FtCoalitionAwardPlan>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    let mut resolved = HashMap::<FtLoginId, Result<(FtUserId, FtCoalitionsUser), String>>::new();
+    let mut plan = FtCoalitionAwardPlan {
+        scorings: Vec::new(),
+        unresolved: Vec::new(),
+        cancelled: Vec::new(),
+    };
+
+    for award in awards {
+        if session.http_session_api.client.is_cancelled() {
+            plan.cancelled.push(award);
+            continue;
+        }
+        if !resolved.contains_key(&award.login) {
+            let coalitions_user = resolve(session, &award.login, coalition_ids).await?;
+            resolved.insert(award.login.clone(), coalitions_user);
+        }
+        match &resolved[&award.login] {
+            Ok((user_id, coalitions_user)) => plan.scorings.push(FtCoalitionScoring {
+                user_id: *user_id,
+                coalition_id: coalitions_user.coalition_id.clone(),
+                coalitions_user_id: coalitions_user.id.clone(),
+                award,
+            }),
+            Err(reason) => plan.unresolved.push((award, reason.clone())),
+        }
+    }
+    Ok(plan)
+}
+
+#[cfg(all(feature = "users", feature = "coalitions"))]
+async fn resolve<FCHC>(
+    session: &FtClientSession<'_, FCHC>,
+    login: &FtLoginId,
+    coalition_ids: Option<&[FtCoalitionId]>,
+) -> ClientResult<Result<(FtUserId, FtCoalitionsUser), String>>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    let user = match session
+        .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::Login(
+            login.clone(),
+        )))
+        .await
+    {
+        Ok(res) => res.user,
+        Err(FtClientError::HttpError(err)) if err.status_code == http::StatusCode::NOT_FOUND => {
+            return Ok(Err(FtClientError::HttpError(err).to_string()));
+        }
+        Err(err) => return Err(err),
+    };
+    let Some(user_id) = user.id else {
+        return Ok(Err("user without id".to_string()));
+    };
+
+    let coalitions_users = session
+        .coalitions_users(
+            FtApiCoalitionsUsersRequest::new()
+                .with_user_id(user_id)
+                .with_per_page(100),
+        )
+        .await?
+        .coalitions_users;
+    Ok(select_coalitions_user(coalitions_users, coalition_ids)
+        .map(|coalitions_user| (user_id, coalitions_user))
+        .ok_or_else(|| "no matching coalition".to_string()))
+}
+
+#[cfg(all(feature = "users", feature = "coalitions"))]
+fn select_coalitions_user(
+    coalitions_users: Vec<FtCoalitionsUser>,
+    coalition_ids: Option<&[FtCoalitionId]>,
+) -> Option<FtCoalitionsUser> {
+    coalitions_users
+        .into_iter()
+        .filter(|coalitions_user| {
+            coalition_ids
+                .is_none_or(|coalition_ids| coalition_ids.contains(&coalitions_user.coalition_id))
+        })
+        .max_by_key(|coalitions_user| *coalitions_user.id.value())
+}
+
+impl FtCoalitionAwardPlan {
+    pub fn is_empty(&self) -> bool {
+        self.scorings.is_empty() && self.unresolved.is_empty() && self.cancelled.is_empty()
+    }
+
+    /// Posts every planned score, one `coalitions_id_scores_post` call each.
+    ///
+    /// A failed call does not stop the others. Every award, unresolved ones included, gets a row
+    /// in `FtCoalitionAwardReport::audit`; those cancelled while planning, or not posted once the
+    /// cancellation token of the client is cancelled, get the `Cancelled` status. In dry-run
+    /// mode, the planned scores get the `Simulated` status.
+    #[cfg(feature = "coalitions")]
+    pub async fn apply<FCHC>(self, session: &FtClientSession<'_, FCHC>) -> FtCoalitionAwardReport
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        let mut report = FtCoalitionAwardReport::default();
+        for scoring in self.scorings {
+            let (status, score_id, error) = if session.http_session_api.client.is_cancelled() {
+                (FtCoalitionAwardStatus::Cancelled, None, None)
+            } else {
+                let body = FtApiCoalitionsIdScoresPostBody::new(
+                    scoring.award.reason.clone(),
+                    scoring.award.points,
+                )
+                .with_coalitions_user_id(scoring.coalitions_user_id.clone());
+                let res = session
+                    .coalitions_id_scores_post(FtApiCoalitionsIdScoresPostRequest::new(
+                        scoring.coalition_id.clone(),
+                        body,
+                    ))
+                    .await;
+                match res {
+                    Ok(res) => (FtCoalitionAwardStatus::Scored, Some(res.score.id), None),
+                    Err(err) if err.is_dry_run() => (FtCoalitionAwardStatus::Simulated, None, None),
+                    Err(err) => (FtCoalitionAwardStatus::Failed, None, Some(err.to_string())),
+                }
+            };
+            report.audit.push(FtCoalitionAwardAudit {
+                login: scoring.award.login,
+                user_id: Some(scoring.user_id),
+                coalition_id: Some(scoring.coalition_id),
+                coalitions_user_id: Some(scoring.coalitions_user_id),
+                points: scoring.award.points,
+                reason: scoring.award.reason,
+                status,
+                score_id,
+                error,
+                at: Utc::now(),
+            });
+        }
+        for (award, reason) in self.unresolved {
+            report.audit.push(FtCoalitionAwardAudit {
+                login: award.login,
+                user_id: None,
+                coalition_id: None,
+                coalitions_user_id: None,
+                points: award.points,
+                reason: award.reason,
+                status: FtCoalitionAwardStatus::Unresolved,
+                score_id: None,
+                error: Some(reason),
+                at: Utc::now(),
+            });
+        }
+        for award in self.cancelled {
+            report.audit.push(FtCoalitionAwardAudit {
+                login: award.login,
+                user_id: None,
+                coalition_id: None,
+                coalitions_user_id: None,
+                points: award.points,
+                reason: award.reason,
+                status: FtCoalitionAwardStatus::Cancelled,
+                score_id: None,
+                error: None,
+                at: Utc::now(),
+            });
+        }
+        report
+    }
+}
+
+impl FtCoalitionAwardReport {
+    /// The audit rows with the given status.
+    pub fn with_status(
+        &self,
+        status: FtCoalitionAwardStatus,
+    ) -> impl Iterator<Item = &FtCoalitionAwardAudit> {
+        self.audit.iter().filter(move |row| row.status == status)
+    }
+
+    /// The audit trail as CSV, every column included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a row cannot be serialized.
+    pub fn to_audit_csv(&self) -> Result<String, FtExportError> {
+        to_csv_string(&self.audit, FtCoalitionAwardAuditColumn::ALL)
+    }
+}
+
+impl Display for FtCoalitionAwardPlan {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for scoring in &self.scorings {
+            writeln!(
+                f,
+                "{} ({}): {:+} to coalition {} ({})",
+                scoring.award.login,
+                scoring.user_id,
+                scoring.award.points,
+                scoring.coalition_id,
+                scoring.award.reason,
+            )?;
+        }
+        for (award, reason) in &self.unresolved {
+            writeln!(
+                f,
+                "{}: {:+} skipped, {reason} ({})",
+                award.login, award.points, award.reason
+            )?;
+        }
+        for award in &self.cancelled {
+            writeln!(
+                f,
+                "{}: {:+} cancelled ({})",
+                award.login, award.points, award.reason
+            )?;
+        }
+        write!(
+            f,
+            "{} score(s) to post, {} unresolved",
+            self.scorings.len(),
+            self.unresolved.len()
+        )?;
+        if !self.cancelled.is_empty() {
+            write!(f, ", {} cancelled", self.cancelled.len())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "users", feature = "coalitions"))]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn coalitions_user(id: i32, coalition_id: i32) -> FtCoalitionsUser {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "coalition_id": coalition_id,
+            "user_id": 180_844,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn select_latest_matching_coalition() {
+        let coalitions_users = || {
+            vec![
+                coalitions_user(10, 309),
+                coalitions_user(30, 52),
+                coalitions_user(20, 310),
+            ]
+        };
+
+        let latest = select_coalitions_user(coalitions_users(), None).unwrap();
+        assert_eq!(latest.id, FtCoalitionsUserId::new(30));
+
+        let gyeongsan = [309, 310, 311, 312].map(FtCoalitionId::new);
+        let selected = select_coalitions_user(coalitions_users(), Some(&gyeongsan)).unwrap();
+        assert_eq!(selected.id, FtCoalitionsUserId::new(20));
+
+        assert!(select_coalitions_user(Vec::new(), None).is_none());
+    }
+
+    #[test]
+    fn audit_csv() {
+        let report = FtCoalitionAwardReport {
+            audit: vec![FtCoalitionAwardAudit {
+                login: FtLoginId::new("hdoo".to_string()),
+                user_id: Some(FtUserId::new(180_844)),
+                coalition_id: Some(FtCoalitionId::new(310)),
+                coalitions_user_id: Some(FtCoalitionsUserId::new(20)),
+                points: 42,
+                reason: "Hackathon, 1st place".to_string(),
+                status: FtCoalitionAwardStatus::Scored,
+                score_id: Some(FtScoreId::new(7)),
+                error: None,
+                at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+            }],
+        };
+
+        assert_eq!(
+            report.with_status(FtCoalitionAwardStatus::Failed).count(),
+            0
+        );
+        assert_eq!(
+            report.to_audit_csv().unwrap(),
+            "login,user_id,coalition_id,coalitions_user_id,points,reason,status,score_id,error,at\n\
+             hdoo,180844,310,20,42,\"Hackathon, 1st place\",scored,7,,2025-03-01T12:00:00Z\n"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn cancelled_awards_are_audited_as_cancelled() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let cancellation = CancellationToken::new();
+        let client = mock.client().with_cancellation(cancellation.clone());
+        let session = client.open_session(FtMockServer::token());
+        cancellation.cancel();
+
+        let award = FtCoalitionAward::new(FtLoginId::new("hdoo".to_string()), 42, "Hackathon");
+        let plan = plan_coalition_awards(&session, vec![award.clone()], None)
+            .await
+            .unwrap();
+        assert_eq!(plan.cancelled, vec![award]);
+        assert!(plan.unresolved.is_empty());
+
+        let report = plan.apply(&session).await;
+        assert_eq!(
+            report
+                .with_status(FtCoalitionAwardStatus::Cancelled)
+                .count(),
+            1
+        );
+        assert!(mock.server().received_requests().await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn rate_limited_lookups_fail_the_plan() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        mock.mock_rate_limited("users/nobody", 1, 1).await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let award = |login: &str| FtCoalitionAward::new(FtLoginId::new(login.to_string()), 1, "");
+        let res = plan_coalition_awards(&session, vec![award("nobody")], None).await;
+        assert!(
+            matches!(res, Err(FtClientError::RateLimitError(_))),
+            "{res:?}"
+        );
+
+        // Once the rate limit is over, the unknown login is unresolved.
+        let plan = plan_coalition_awards(&session, vec![award("nobody")], None)
+            .await
+            .unwrap();
+        assert_eq!(plan.unresolved.len(), 1);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn dry_run_awards_are_audited_as_simulated() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let client = mock.client().with_dry_run(true);
+        let session = client.open_session(FtMockServer::token());
+
+        let plan = FtCoalitionAwardPlan {
+            scorings: vec![FtCoalitionScoring {
+                award: FtCoalitionAward::new(FtLoginId::new("hdoo".to_string()), 42, "Hackathon"),
+                user_id: FtUserId::new(180_844),
+                coalition_id: FtCoalitionId::new(310),
+                coalitions_user_id: FtCoalitionsUserId::new(20),
+            }],
+            unresolved: Vec::new(),
+            cancelled: Vec::new(),
+        };
+        let report = plan.apply(&session).await;
+        assert_eq!(
+            report
+                .with_status(FtCoalitionAwardStatus::Simulated)
+                .count(),
+            1
+        );
+        assert_eq!(report.audit[0].error, None);
+        assert!(mock.server().received_requests().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,475 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::common::write_atomic;
+use crate::prelude::*;
+
+/// The HTTP method of a queued mutation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FtOutboxMethod {
+    Post,
+    Patch,
+}
+
+/// A mutation that could not be sent, as stored in the outbox file.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FtOutboxEntry {
+    pub method: FtOutboxMethod,
+    /// The path relative to the API root, e.g. `scale_teams/multiple_create`.
+    pub path: String,
+    pub body: serde_json::Value,
+    pub queued_at: DateTime<Utc>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// The request may have been applied, e.g. it timed out once sent: `replay` leaves the entry
+    /// alone until `resolve` settles it.
+    #[serde(default)]
+    pub outcome_unknown: bool,
+}
+
+/// What `FtOutbox::replay` did.
+#[derive(Debug, Default)]
+pub struct FtOutboxReplayReport {
+    pub sent: usize,
+    /// Entries the API rejected for good (e.g. `422`), removed from the outbox.
+    pub failed: Vec<(FtOutboxEntry, FtClientError)>,
+    /// Entries still queued, because the rate limit or a server error stopped the replay.
+    pub remaining: usize,
+    /// Entries among `remaining` waiting for a manual decision, their request possibly applied.
+    pub outcome_unknown: usize,
+}
+
+#[derive(Debug)]
+pub enum FtOutboxError {
+    Io(std::io::Error),
+    Serde(serde_json::Error),
+}
+
+impl Display for FtOutboxError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            FtOutboxError::Io(err) => write!(f, "outbox I/O error: {err}"),
+            FtOutboxError::Serde(err) => write!(f, "outbox serialization error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FtOutboxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FtOutboxError::Io(err) => Some(err),
+            FtOutboxError::Serde(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for FtOutboxError {
+    fn from(err: std::io::Error) -> Self {
+        FtOutboxError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for FtOutboxError {
+    fn from(err: serde_json::Error) -> Self {
+        FtOutboxError::Serde(err)
+    }
+}
+
+impl From<FtOutboxError> for FtClientError {
+    fn from(err: FtOutboxError) -> Self {
+        FtClientError::SystemError(
+            FtSystemError::new()
+                .with_message("cannot persist the outbox".to_string())
+                .with_cause(Box::new(err)),
+        )
+    }
+}
+
+/// A file-backed queue of POST/PATCH bodies that failed on the rate limit or a server error.
+///
+/// Entries are appended to the file as JSON lines as soon as they are queued, so a run that is
+/// interrupted midway loses nothing: the next run calls `replay` before starting over.
+///
+/// # Example
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// async fn book(planner: FtSlotPlanner, team_ids: &[FtTeamId]) -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let mut outbox = FtOutbox::open("scale_teams.outbox")?;
+///     let report = outbox.replay(&session).await?;
+///     println!("{} replayed, {} still queued", report.sent, report.remaining);
+///
+///     for request in planner.plan(team_ids).into_requests() {
+///         let created: Option<FtApiScaleTeamsMultipleCreateResponse> = outbox
+///             .send(&session, FtOutboxMethod::Post, "scale_teams/multiple_create", &request)
+///             .await?;
+///         if created.is_none() {
+///             eprintln!("queued for the next run");
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FtOutbox {
+    path: PathBuf,
+    entries: Vec<FtOutboxEntry>,
+}
+
+impl FtOutbox {
+    /// Opens the outbox stored at `path`, empty if the file does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or holds something else than outbox entries.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, FtOutboxError> {
+        let path = path.into();
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(raw) => raw
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &[FtOutboxEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Queues a mutation and appends it to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `body` cannot be serialized or the file cannot be written.
+    pub fn push<RQ: Serialize>(
+        &mut self,
+        method: FtOutboxMethod,
+        path: &str,
+        body: &RQ,
+        error: Option<&FtClientError>,
+    ) -> Result<(), FtOutboxError> {
+        let entry = FtOutboxEntry {
+            method,
+            path: path.to_string(),
+            body: serde_json::to_value(body)?,
+            queued_at: Utc::now(),
+            attempts: u32::from(error.is_some()),
+            last_error: error.map(ToString::to_string),
+            outcome_unknown: error.is_some_and(|err| is_outcome_unknown(method, err)),
+        };
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Sends a mutation, queuing it instead if the rate limit or a server error stops it.
+    ///
+    /// A network error once the request was sent, or a server error replying to a `POST`, leaves
+    /// unknown whether the API applied it: the mutation is queued with `outcome_unknown` set
+    /// rather than sent again. Returns `None` when
+    /// the mutation was queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns the API error if the mutation is rejected for good (e.g. `422`), or a system
+    /// error if the outbox cannot be written. In dry-run mode, nothing is queued and the
+    /// `FtClientError::DryRun` of the request is returned.
+    pub async fn send<FCHC, RQ, RS>(
+        &mut self,
+        session: &FtClientSession<'_, FCHC>,
+        method: FtOutboxMethod,
+        path: &str,
+        body: &RQ,
+    ) -> ClientResult<Option<RS>>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+        RQ: Serialize + Send + Sync,
+        RS: for<'de> Deserialize<'de> + Send,
+    {
+        match request(session, method, path, body).await {
+            Ok(res) => Ok(Some(res)),
+            Err(err) if is_outcome_unknown(method, &err) || is_transient(&err) => {
+                self.push(method, path, body, Some(&err))?;
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sends the queued mutations in order.
+    ///
+    /// The replay stops at the first rate limit or server error, keeping that entry and the
+    /// following ones for the next replay. Entries whose outcome is unknown are kept without
+    /// being sent, until `resolve` settles them. Entries the API rejects for good are dropped and
+    /// returned in the report. Once the cancellation token of the client is cancelled, the
+    /// remaining entries are kept as well; in dry-run mode, every entry is logged and kept.
+    ///
+    /// The file is rewritten after each entry sent or dropped, so a replay interrupted midway
+    /// does not send them again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the outbox cannot be rewritten; the file then still holds the entry
+    /// just sent or dropped, and the following ones.
+    pub async fn replay<FCHC>(
+        &mut self,
+        session: &FtClientSession<'_, FCHC>,
+    ) -> Result<FtOutboxReplayReport, FtOutboxError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        let mut report = FtOutboxReplayReport::default();
+        let mut index = 0;
+        while index < self.entries.len() {
+            if session.http_session_api.client.is_cancelled() {
+                break;
+            }
+            let entry = &self.entries[index];
+            if entry.outcome_unknown {
+                index += 1;
+                continue;
+            }
+            crate::metrics::retry("outbox", "transient");
+            let res: ClientResult<serde_json::Value> =
+                request(session, entry.method, &entry.path, &entry.body).await;
+            match res {
+                Ok(_) => {
+                    self.entries.remove(index);
+                    self.save()?;
+                    report.sent += 1;
+                }
+                Err(err) if err.is_dry_run() => index += 1,
+                Err(err) if is_outcome_unknown(entry.method, &err) || is_transient(&err) => {
+                    let entry = &mut self.entries[index];
+                    entry.attempts += 1;
+                    entry.last_error = Some(err.to_string());
+                    entry.outcome_unknown = is_outcome_unknown(entry.method, &err);
+                    self.save()?;
+                    break;
+                }
+                Err(err) => {
+                    let entry = self.entries.remove(index);
+                    self.save()?;
+                    report.failed.push((entry, err));
+                }
+            }
+        }
+        report.remaining = self.entries.len();
+        report.outcome_unknown = self
+            .entries
+            .iter()
+            .filter(|entry| entry.outcome_unknown)
+            .count();
+        Ok(report)
+    }
+
+    /// Settles the entry at `index` whose outcome is unknown, once checked on the Intra: `resend`
+    /// sends it again on the next replay, otherwise it is dropped as applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the outbox cannot be rewritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn resolve(&mut self, index: usize, resend: bool) -> Result<(), FtOutboxError> {
+        if resend {
+            self.entries[index].outcome_unknown = false;
+        } else {
+            self.entries.remove(index);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), FtOutboxError> {
+        let mut contents = Vec::new();
+        for entry in &self.entries {
+            writeln!(contents, "{}", serde_json::to_string(entry)?)?;
+        }
+        write_atomic(&self.path, &contents)?;
+        Ok(())
+    }
+}
+
+async fn request<FCHC, RQ, RS>(
+    session: &FtClientSession<'_, FCHC>,
+    method: FtOutboxMethod,
+    path: &str,
+    body: &RQ,
+) -> ClientResult<RS>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+    RQ: Serialize + Send + Sync,
+    RS: for<'de> Deserialize<'de> + Send,
+{
+    match method {
+        FtOutboxMethod::Post => session.http_session_api.http_post(path, body).await,
+        FtOutboxMethod::Patch => session.http_session_api.http_patch(path, body).await,
+    }
+}
+
+/// Whether a mutation failed without telling if the API applied it, i.e. on a network error once
+/// the request was sent, or on a server error replying to a `POST`, which may have created the
+/// records before failing. Sending it again may apply it twice.
+fn is_outcome_unknown(method: FtOutboxMethod, err: &FtClientError) -> bool {
+    match err {
+        #[cfg(feature = "reqwest")]
+        FtClientError::ReqwestError(err) => !(err.error.is_connect() || err.error.is_builder()),
+        FtClientError::HttpError(err) => {
+            method == FtOutboxMethod::Post && err.status_code.is_server_error()
+        }
+        _ => false,
+    }
+}
+
+/// Whether sending the same request later may succeed.
+pub(crate) fn is_transient(err: &FtClientError) -> bool {
+    match err {
+        FtClientError::RateLimitError(_) => true,
+        #[cfg(feature = "reqwest")]
+        FtClientError::ReqwestError(_) => true,
+        FtClientError::HttpError(err) => err.status_code.is_server_error(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::*;
+
+    #[test]
+    fn push_and_reopen() {
+        let path = std::env::temp_dir().join(format!("ft_outbox_{}.jsonl", std::process::id()));
+        let mut outbox = FtOutbox::open(&path).unwrap();
+        assert!(outbox.is_empty());
+
+        let err = FtClientError::HttpError(FtHttpError::new(StatusCode::BAD_GATEWAY));
+        outbox
+            .push(
+                FtOutboxMethod::Post,
+                "scale_teams/multiple_create",
+                &serde_json::json!({ "scale_teams": [{ "user_id": 1, "team_id": 2 }] }),
+                Some(&err),
+            )
+            .unwrap();
+        outbox
+            .push(
+                FtOutboxMethod::Patch,
+                "teams/2",
+                &serde_json::json!({ "team": { "final_mark": 0 } }),
+                None,
+            )
+            .unwrap();
+
+        let reopened = FtOutbox::open(&path).unwrap();
+        assert_eq!(reopened.entries(), outbox.entries());
+        assert_eq!(reopened.entries()[0].attempts, 1);
+        assert!(reopened.entries()[0].outcome_unknown);
+        assert_eq!(reopened.entries()[1].method, FtOutboxMethod::Patch);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn transient_errors() {
+        let http = |status| FtClientError::HttpError(FtHttpError::new(status));
+        assert!(is_transient(&http(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(is_transient(&FtClientError::RateLimitError(
+            FtRateLimitError::new()
+        )));
+        assert!(!is_transient(&http(StatusCode::UNPROCESSABLE_ENTITY)));
+
+        assert!(is_outcome_unknown(
+            FtOutboxMethod::Post,
+            &http(StatusCode::BAD_GATEWAY)
+        ));
+        assert!(!is_outcome_unknown(
+            FtOutboxMethod::Patch,
+            &http(StatusCode::BAD_GATEWAY)
+        ));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn timed_out_posts_wait_for_a_decision() {
+        use std::time::Duration;
+
+        use wiremock::matchers::method;
+        use wiremock::{Mock, ResponseTemplate};
+
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(201).set_delay(Duration::from_secs(5)))
+            .mount(mock.server())
+            .await;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let client = FtClient::new(
+            FtClientReqwestConnector::with_connector(http).with_ft_api_url(&mock.api_url()),
+        );
+        let session = client.open_session(FtMockServer::token());
+
+        let path =
+            std::env::temp_dir().join(format!("ft_outbox_unknown_{}.jsonl", std::process::id()));
+        let mut outbox = FtOutbox::open(&path).unwrap();
+        let sent: Option<serde_json::Value> = outbox
+            .send(
+                &session,
+                FtOutboxMethod::Post,
+                "notes",
+                &serde_json::json!({ "note": {} }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(sent, None);
+        assert!(outbox.entries()[0].outcome_unknown);
+
+        let report = outbox.replay(&session).await.unwrap();
+        assert_eq!(
+            (report.sent, report.remaining, report.outcome_unknown),
+            (0, 1, 1)
+        );
+        assert_eq!(mock.server().received_requests().await.unwrap().len(), 1);
+
+        outbox.resolve(0, false).unwrap();
+        assert!(FtOutbox::open(&path).unwrap().is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+}
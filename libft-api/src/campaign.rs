@@ -0,0 +1,600 @@
+//! Configuration driven export campaigns.
+//!
+//! Available with the `campaign` feature. A campaign describes a recurring export — which
+//! endpoint, which filters, which date window, where to write — in a TOML or YAML file, and
+//! `FtCampaign::run` executes it: every page of every window is fetched through the client (and
+//! its rate limiter), up to `concurrency` windows at a time, and the records are written with the
+//! `export` module.
+//!
+//! ```toml
+//! endpoint = "scale_teams"
+//! campus_id = 69
+//! output = "scale_teams.csv"
+//! concurrency = 4
+//! dedup = true
+//!
+//! [[filter]]
+//! field = "cursus_id"
+//! value = ["21"]
+//!
+//! [window]
+//! field = "begin_at"
+//! begin_at = "2025-01-01T00:00:00Z"
+//! end_at = "2025-02-01T00:00:00Z"
+//! step_days = 7
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use libft_api::campaign::FtCampaign;
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let campaign = FtCampaign::from_path("campaigns/scale_teams.toml")?;
+//!     let written = campaign.run(&session).await?;
+//!     println!("{written} records written to {}", campaign.output.display());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::{StreamExt, TryStreamExt};
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+
+use crate::common::write_atomic;
+use crate::export::{csv::FtCsvWriter, FtExportError, FtExportRecord};
+use crate::prelude::*;
+
+/// The number of records asked per page by a campaign.
+pub const FT_CAMPAIGN_PER_PAGE: u8 = 100;
+
+/// A recurring export, usually read from a TOML or YAML file.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct FtCampaign {
+    pub name: Option<String>,
+    pub endpoint: FtCampaignEndpoint,
+    pub campus_id: Option<FtCampusId>,
+    #[serde(default)]
+    pub filter: Vec<FtFilterOption>,
+    #[serde(default)]
+    pub range: Vec<FtRangeOption>,
+    pub window: Option<FtCampaignWindow>,
+    pub output: PathBuf,
+    /// Defaults to `csv` when `output` ends with `.csv`, `json` otherwise.
+    pub format: Option<FtCampaignFormat>,
+    /// The number of windows fetched at the same time.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    /// Drops the records fetched twice, by id, e.g. on the boundary of two windows or when the
+    /// collection changes during the run, and logs the pages where records may have been
+    /// skipped.
+    #[serde(default)]
+    pub dedup: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCampaignEndpoint {
+    Users,
+    ProjectsUsers,
+    ScaleTeams,
+    Teams,
+    Locations,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCampaignFormat {
+    Json,
+    Csv,
+}
+
+/// A date window on `field`, split into chunks of `step_days` days when set.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct FtCampaignWindow {
+    pub field: FtRangeField,
+    pub begin_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub step_days: Option<i64>,
+}
+
+fn default_concurrency() -> usize {
+    1
+}
+
+/// The id `dedup` identifies the records of an endpoint by.
+trait FtCampaignRecord {
+    fn campaign_id(&self) -> Option<i64>;
+}
+
+impl FtCampaignRecord for FtUser {
+    fn campaign_id(&self) -> Option<i64> {
+        self.id.map(|id| i64::from(*id.value()))
+    }
+}
+
+impl FtCampaignRecord for FtProjectsUser {
+    fn campaign_id(&self) -> Option<i64> {
+        Some(i64::from(self.id.0))
+    }
+}
+
+impl FtCampaignRecord for FtScaleTeam {
+    fn campaign_id(&self) -> Option<i64> {
+        Some(i64::from(*self.id.value()))
+    }
+}
+
+impl FtCampaignRecord for FtTeam {
+    fn campaign_id(&self) -> Option<i64> {
+        Some(i64::from(self.id.0))
+    }
+}
+
+impl FtCampaignRecord for FtLocation {
+    fn campaign_id(&self) -> Option<i64> {
+        Some(*self.id.value())
+    }
+}
+
+#[derive(Debug)]
+pub enum FtCampaignError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    Yaml(serde_yaml::Error),
+    Client(FtClientError),
+    Export(FtExportError),
+    UnknownFormat(PathBuf),
+    /// The run was cancelled before every page was fetched; `output` was left untouched.
+    Cancelled,
+}
+
+impl Display for FtCampaignError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Ft campaign io error: {err}"),
+            Self::Toml(err) => write!(f, "Ft campaign toml error: {err}"),
+            Self::Yaml(err) => write!(f, "Ft campaign yaml error: {err}"),
+            Self::Client(err) => write!(f, "Ft campaign client error: {err}"),
+            Self::Export(err) => write!(f, "Ft campaign export error: {err}"),
+            Self::UnknownFormat(path) => write!(
+                f,
+                "Ft campaign error: `{}` is neither a toml nor a yaml file",
+                path.display()
+            ),
+            Self::Cancelled => write!(f, "Ft campaign cancelled, output left untouched"),
+        }
+    }
+}
+
+impl std::error::Error for FtCampaignError {}
+
+impl From<std::io::Error> for FtCampaignError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for FtCampaignError {
+    fn from(err: toml::de::Error) -> Self {
+        Self::Toml(err)
+    }
+}
+
+impl From<serde_yaml::Error> for FtCampaignError {
+    fn from(err: serde_yaml::Error) -> Self {
+        Self::Yaml(err)
+    }
+}
+
+impl From<FtClientError> for FtCampaignError {
+    fn from(err: FtClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+impl From<FtExportError> for FtCampaignError {
+    fn from(err: FtExportError) -> Self {
+        Self::Export(err)
+    }
+}
+
+impl From<serde_json::Error> for FtCampaignError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Export(FtExportError::Serde(err))
+    }
+}
+
+impl FtCampaign {
+    /// Parses a campaign written in TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid campaign.
+    pub fn from_toml_str(s: &str) -> Result<Self, FtCampaignError> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Parses a campaign written in YAML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid campaign.
+    pub fn from_yaml_str(s: &str) -> Result<Self, FtCampaignError> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Reads a campaign file, parsed according to its `.toml`, `.yaml` or `.yml` extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, has another extension, or is not a valid
+    /// campaign.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FtCampaignError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml" | "yml") => Self::from_yaml_str(&content),
+            _ => Err(FtCampaignError::UnknownFormat(path.to_path_buf())),
+        }
+    }
+
+    /// The output format, explicit or inferred from the output path.
+    pub fn output_format(&self) -> FtCampaignFormat {
+        self.format.unwrap_or_else(
+            || match self.output.extension().and_then(|ext| ext.to_str()) {
+                Some("csv") => FtCampaignFormat::Csv,
+                _ => FtCampaignFormat::Json,
+            },
+        )
+    }
+
+    /// The ranges of every request batch: `range` plus one chunk of the window each.
+    pub fn ranges(&self) -> Vec<Vec<FtRangeOption>> {
+        let Some(window) = &self.window else {
+            return vec![self.range.clone()];
+        };
+
+        let step = window
+            .step_days
+            .filter(|days| *days > 0)
+            .map(TimeDelta::days)
+            .unwrap_or(window.end_at - window.begin_at);
+        let mut ranges = Vec::new();
+        let mut begin_at = window.begin_at;
+        while begin_at < window.end_at {
+            let end_at = (begin_at + step).min(window.end_at);
+            let mut range = self.range.clone();
+            range.push(FtRangeOption::new(
+                window.field.clone(),
+                vec![begin_at.to_rfc3339(), end_at.to_rfc3339()],
+            ));
+            ranges.push(range);
+            begin_at = end_at;
+        }
+        ranges
+    }
+
+    /// Runs the campaign and returns the number of records written to `output`.
+    ///
+    /// `output` is replaced at once, once every record is written aside, so a failed run leaves the
+    /// previous export in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or the output cannot be written, and
+    /// `FtCampaignError::Cancelled`, writing nothing, once the cancellation token of the client is
+    /// cancelled.
+    pub async fn run<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+    ) -> Result<usize, FtCampaignError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        let campus_id = self.campus_id.clone();
+        let hooks = session.http_session_api.client.hooks();
+        let cancellation = &session.http_session_api.client.cancellation;
+        match self.endpoint {
+            FtCampaignEndpoint::Users => {
+                let users = self
+                    .collect(hooks, cancellation, |page, range| {
+                        let filter = self.filter.clone();
+                        let campus_id = campus_id.clone();
+                        async move {
+                            match campus_id {
+                                Some(campus_id) => session
+                                    .campus_id_users(
+                                        FtApiCampusIdUsersRequest::new(campus_id)
+                                            .with_filter(filter)
+                                            .with_range(range)
+                                            .with_page(page)
+                                            .with_per_page(FT_CAMPAIGN_PER_PAGE),
+                                    )
+                                    .await
+                                    .map(|res| res.users),
+                                None => session
+                                    .users(
+                                        FtApiUsersRequest::new()
+                                            .with_filter(filter)
+                                            .with_range(range)
+                                            .with_page(usize::from(page))
+                                            .with_per_page(FT_CAMPAIGN_PER_PAGE),
+                                    )
+                                    .await
+                                    .map(|res| res.users),
+                            }
+                        }
+                    })
+                    .await?;
+                self.write(&users)
+            }
+            FtCampaignEndpoint::ProjectsUsers => {
+                let projects_users = self
+                    .collect(hooks, cancellation, |page, range| {
+                        let mut req = FtApiProjectsUsersRequest::new()
+                            .with_filter(self.filter.clone())
+                            .with_range(range)
+                            .with_page(page)
+                            .with_per_page(FT_CAMPAIGN_PER_PAGE);
+                        req.campus_id = campus_id.clone();
+                        async move {
+                            session
+                                .projects_users(req)
+                                .await
+                                .map(|res| res.projects_users)
+                        }
+                    })
+                    .await?;
+                self.write(&projects_users)
+            }
+            FtCampaignEndpoint::ScaleTeams => {
+                let mut filter = self.filter.clone();
+                if let Some(campus_id) = &campus_id {
+                    filter.push(FtFilterOption::new(
+                        FtFilterField::CampusId,
+                        vec![campus_id.to_string()],
+                    ));
+                }
+                let scale_teams = self
+                    .collect(hooks, cancellation, |page, range| {
+                        let req = FtApiScaleTeamsRequest::new()
+                            .with_filter(filter.clone())
+                            .with_range(range)
+                            .with_page(page)
+                            .with_per_page(FT_CAMPAIGN_PER_PAGE);
+                        async move { session.scale_teams(req).await.map(|res| res.scale_teams) }
+                    })
+                    .await?;
+                self.write(&scale_teams)
+            }
+            FtCampaignEndpoint::Teams => {
+                let teams = self
+                    .collect(hooks, cancellation, |page, range| {
+                        let mut req = FtApiTeamsRequest::new()
+                            .with_filter(self.filter.clone())
+                            .with_range(range)
+                            .with_page(page)
+                            .with_per_page(FT_CAMPAIGN_PER_PAGE);
+                        req.campus_id = campus_id.clone();
+                        async move { session.teams(req).await.map(|res| res.teams) }
+                    })
+                    .await?;
+                self.write(&teams)
+            }
+            FtCampaignEndpoint::Locations => {
+                let locations = self
+                    .collect(hooks, cancellation, |page, range| {
+                        let mut req = FtApiLocationsRequest::new()
+                            .with_filter(self.filter.clone())
+                            .with_range(range)
+                            .with_page(page)
+                            .with_per_page(FT_CAMPAIGN_PER_PAGE);
+                        req.campus_id = campus_id.clone();
+                        async move { session.locations(req).await.map(|res| res.locations) }
+                    })
+                    .await?;
+                self.write(&locations)
+            }
+        }
+    }
+
+    /// Fetches every page of every window, `concurrency` windows at a time.
+    ///
+    /// Fails with `FtCampaignError::Cancelled` once `cancellation` is cancelled.
+    async fn collect<R, F, Fut>(
+        &self,
+        hooks: &FtClientHooks,
+        cancellation: &CancellationToken,
+        fetch: F,
+    ) -> Result<Vec<R>, FtCampaignError>
+    where
+        R: FtCampaignRecord,
+        F: Fn(u16, Vec<FtRangeOption>) -> Fut,
+        Fut: Future<Output = ClientResult<Vec<R>>>,
+    {
+        let fetch = &fetch;
+        let windows = futures::stream::iter(self.ranges().into_iter().map(|range| async move {
+            let mut records = Vec::new();
+            let mut audit = self.dedup.then(|| FtPageAudit::new(FT_CAMPAIGN_PER_PAGE));
+            let mut pages = pin!(paginate_with_hooks(
+                FT_CAMPAIGN_PER_PAGE,
+                hooks.clone(),
+                cancellation.clone(),
+                "campaign",
+                |page| fetch(page, range.clone()),
+            )
+            .chunks(usize::from(FT_CAMPAIGN_PER_PAGE))
+            .enumerate());
+            while let Some((index, page_records)) = pages.next().await {
+                let page_records = page_records.into_iter().collect::<ClientResult<Vec<_>>>()?;
+                match &mut audit {
+                    Some(audit) => {
+                        records.extend(audit.dedup_page(index + 1, page_records, R::campaign_id));
+                    }
+                    None => records.extend(page_records),
+                }
+            }
+            if !cancellation.is_cancelled() {
+                if let Some(report) = audit.map(|audit| audit.report()) {
+                    if report.has_suspected_gaps() {
+                        tracing::warn!(?range, %report, "records may have been skipped");
+                    }
+                }
+            }
+            Ok::<_, FtClientError>(records)
+        }))
+        .buffered(self.concurrency.max(1))
+        .try_collect::<Vec<_>>()
+        .await?;
+        if cancellation.is_cancelled() {
+            return Err(FtCampaignError::Cancelled);
+        }
+
+        let mut records = windows.into_iter().flatten().collect::<Vec<_>>();
+        if self.dedup {
+            // The windows share their boundaries, so a record may be in two of them.
+            let mut seen = HashSet::new();
+            records.retain(|record| record.campaign_id().is_none_or(|id| seen.insert(id)));
+        }
+        Ok(records)
+    }
+
+    fn write<R>(&self, records: &[R]) -> Result<usize, FtCampaignError>
+    where
+        R: FtExportRecord,
+    {
+        let mut contents = Vec::new();
+        match self.output_format() {
+            FtCampaignFormat::Json => serde_json::to_writer_pretty(&mut contents, records)?,
+            FtCampaignFormat::Csv => {
+                let mut writer = FtCsvWriter::new(&mut contents);
+                writer.write_all(records)?;
+                writer.into_inner()?;
+            }
+        }
+        write_atomic(&self.output, &contents)?;
+
+        Ok(records.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn toml() {
+        let campaign = FtCampaign::from_toml_str(
+            r#"
+endpoint = "scale_teams"
+campus_id = 69
+output = "scale_teams.csv"
+concurrency = 4
+dedup = true
+
+[[filter]]
+field = "cursus_id"
+value = ["21"]
+
+[window]
+field = "begin_at"
+begin_at = "2025-01-01T00:00:00Z"
+end_at = "2025-01-20T00:00:00Z"
+step_days = 7
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(campaign.endpoint, FtCampaignEndpoint::ScaleTeams);
+        assert!(campaign.dedup);
+        assert_eq!(campaign.output_format(), FtCampaignFormat::Csv);
+        assert_eq!(campaign.filter[0].field, FtFilterField::CursusId);
+
+        let ranges = campaign.ranges();
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(
+            ranges[2][0].value,
+            vec![
+                Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0)
+                    .unwrap()
+                    .to_rfc3339(),
+                Utc.with_ymd_and_hms(2025, 1, 20, 0, 0, 0)
+                    .unwrap()
+                    .to_rfc3339(),
+            ]
+        );
+    }
+
+    #[test]
+    fn yaml() {
+        let campaign = FtCampaign::from_yaml_str(
+            r#"
+endpoint: users
+output: users.json
+range:
+  - range: created_at
+    value: ["2024-01-01", "2024-12-31"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(campaign.concurrency, 1);
+        assert!(!campaign.dedup);
+        assert_eq!(campaign.output_format(), FtCampaignFormat::Json);
+        assert_eq!(campaign.ranges(), vec![campaign.range.clone()]);
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod run_tests {
+    use super::*;
+    use crate::test_support::*;
+
+    fn campaign(output: &Path) -> FtCampaign {
+        FtCampaign::from_toml_str(&format!(
+            "endpoint = \"users\"\noutput = {:?}\n",
+            output.display().to_string()
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn cancelled_runs_keep_the_previous_output() {
+        let output = std::env::temp_dir().join(format!("ft_campaign_{}.json", std::process::id()));
+        let mock = FtMockServer::start().await;
+
+        let client = mock.client();
+        let written = campaign(&output)
+            .run(&client.open_session(FtMockServer::token()))
+            .await
+            .unwrap();
+        assert_eq!(written, FT_MOCK_USERS.len());
+        let previous = std::fs::read_to_string(&output).unwrap();
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        let client = mock.client().with_cancellation(cancellation);
+        let res = campaign(&output)
+            .run(&client.open_session(FtMockServer::token()))
+            .await;
+        assert!(matches!(res, Err(FtCampaignError::Cancelled)), "{res:?}");
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), previous);
+        std::fs::remove_file(output).unwrap();
+    }
+}
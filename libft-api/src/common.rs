@@ -5,7 +5,11 @@
 //! * **Error**: Comprehensive error types for various failure scenarios
 //! * **Parameter**: Types and utilities for building API query parameters
 //! * **Rate Limiter**: Automatic rate limiting to stay within API quotas
+//! * **Hooks**: Callbacks on rate-limit waits and retries
 //! * **Paginator**: Utilities for handling paginated API responses
+//! * **Page Audit**: Deduplication by id and gap reports for concurrent paginated pulls
+//! * **Raw Page**: List responses deserialized one record at a time
+//! * **Sync Cursor**: `updated_at` watermarks for incremental fetches
 //!
 //! # Example
 //!
@@ -34,5 +38,22 @@ mod param;
 pub use ratelimiter::*;
 mod ratelimiter;
 
+pub use hooks::*;
+mod hooks;
+
 pub use paginator::*;
 mod paginator;
+
+pub use page_audit::*;
+mod page_audit;
+
+pub use raw_page::*;
+mod raw_page;
+
+pub use sync_cursor::*;
+mod sync_cursor;
+
+pub(crate) use atomic_file::*;
+mod atomic_file;
+
+pub use tokio_util::sync::CancellationToken;
@@ -0,0 +1,37 @@
+use std::io::Write;
+use std::path::Path;
+
+/// Writes `contents` to `path`, replacing the previous file.
+///
+/// The contents are written aside to `path` with a `.tmp` suffix, then renamed over `path`, so a
+/// crash never leaves a truncated file behind.
+pub(crate) fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let mut file = std::fs::File::create(&tmp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    std::fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_sharing_a_stem_do_not_share_the_temporary_file() {
+        let dir = std::env::temp_dir().join(format!("ft_atomic_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let json = dir.join("state.json");
+        let toml = dir.join("state.toml");
+
+        write_atomic(&json, b"{}").unwrap();
+        write_atomic(&toml, b"a = 1").unwrap();
+
+        assert_eq!(std::fs::read(&json).unwrap(), b"{}");
+        assert_eq!(std::fs::read(&toml).unwrap(), b"a = 1");
+        assert!(!dir.join("state.tmp").exists());
+        assert!(!dir.join("state.json.tmp").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,12 +1,13 @@
 use futures::{future::BoxFuture, FutureExt};
-use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use url::Url;
 
-use crate::auth::FtApiToken;
+use crate::auth::{FtApiToken, FT_REDACTED};
 use crate::common::*;
+#[cfg(feature = "reqwest")]
 use crate::connector::*;
+use crate::models::prelude::FtApp;
 
 /// Type alias for client operation results.
 ///
@@ -18,6 +19,7 @@ pub type ClientResult<T> = std::result::Result<T, FtClientError>;
 ///
 /// This is a convenience type alias that represents an FtClient configured with the
 /// FtClientReqwestConnector, which uses the reqwest HTTP client library.
+#[cfg(feature = "reqwest")]
 pub type FtReqwestClient = FtClient<FtClientReqwestConnector>;
 
 /// The main client for interacting with the 42 Intra API.
@@ -49,6 +51,10 @@ where
 {
     pub http_api: FtClientHttpApi<FCHC>,
     pub meta: HeaderMetaData,
+    /// When set, POST/PATCH/DELETE requests are logged and simulated instead of sent.
+    pub dry_run: bool,
+    /// Once cancelled, the loops run with the client stop before their next request.
+    pub cancellation: CancellationToken,
 }
 
 /// The HTTP API client.
@@ -78,7 +84,6 @@ pub struct FtClientHttpApiUri;
 ///
 /// The session is created by calling `FtClient::open_session` and holds a reference
 /// to the parent client and the authentication token.
-#[derive(Debug)]
 pub struct FtClientSession<'a, FCHC>
 where
     FCHC: FtClientHttpConnector + Send,
@@ -91,7 +96,8 @@ where
 /// This structure provides the underlying HTTP functionality for authenticated
 /// API requests. It holds the authentication token and a reference to the parent
 /// client, allowing for authenticated API calls.
-#[derive(Debug)]
+///
+/// Its `Debug` output redacts the token.
 pub struct FtClientHttpSessionApi<'a, FCHC>
 where
     FCHC: FtClientHttpConnector + Send,
@@ -100,6 +106,31 @@ where
     pub client: &'a FtClient<FCHC>,
 }
 
+// The connector is left out of the `Debug` output of sessions, so any connector can be used.
+impl<FCHC> std::fmt::Debug for FtClientSession<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FtClientSession")
+            .field("http_session_api", &self.http_session_api)
+            .finish()
+    }
+}
+
+impl<FCHC> std::fmt::Debug for FtClientHttpSessionApi<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FtClientHttpSessionApi")
+            .field("token", &FT_REDACTED)
+            .field("meta", &self.client.meta)
+            .field("dry_run", &self.client.dry_run)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct FtEnvelopeMessage {
     pub ok: bool,
@@ -143,11 +174,24 @@ pub trait FtClientHttpConnector {
         }
     }
 
+    /// Send an HTTP GET request to the given URI and return the raw response body.
+    ///
+    /// Used to download files (e.g. attachments), and the list responses kept undecoded by
+    /// `FtRawPage`. The token is only sent when the URI points to the API itself, and a `429`
+    /// from the API is returned as a `RateLimitError`.
+    fn http_get_bytes_uri<'a>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+    ) -> BoxFuture<'a, ClientResult<Vec<u8>>>;
+
     /// Send an HTTP POST request to the given URI.
     fn http_post_uri<'a, RQ, RS>(
         &'a self,
         full_uri: Url,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request_body: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -159,6 +203,7 @@ pub trait FtClientHttpConnector {
         &'a self,
         method_relative_uri: &str,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -166,7 +211,7 @@ pub trait FtClientHttpConnector {
         RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
     {
         match self.create_method_uri_path(method_relative_uri) {
-            Ok(full_uri) => self.http_post_uri(full_uri, token, request),
+            Ok(full_uri) => self.http_post_uri(full_uri, token, ratelimiter, request),
             Err(err) => std::future::ready(Err(err)).boxed(),
         }
     }
@@ -176,6 +221,7 @@ pub trait FtClientHttpConnector {
         &'a self,
         full_uri: Url,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request_body: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -187,6 +233,7 @@ pub trait FtClientHttpConnector {
         &'a self,
         method_relative_uri: &str,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -194,7 +241,7 @@ pub trait FtClientHttpConnector {
         RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
     {
         match self.create_method_uri_path(method_relative_uri) {
-            Ok(full_uri) => self.http_patch_uri(full_uri, token, request),
+            Ok(full_uri) => self.http_patch_uri(full_uri, token, ratelimiter, request),
             Err(err) => std::future::ready(Err(err)).boxed(),
         }
     }
@@ -204,6 +251,7 @@ pub trait FtClientHttpConnector {
         &'a self,
         full_uri: Url,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request_body: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -215,6 +263,7 @@ pub trait FtClientHttpConnector {
         &'a self,
         method_relative_uri: &str,
         token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
         request: &'a RQ,
     ) -> BoxFuture<'a, ClientResult<RS>>
     where
@@ -222,7 +271,7 @@ pub trait FtClientHttpConnector {
         RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
     {
         match self.create_method_uri_path(method_relative_uri) {
-            Ok(full_uri) => self.http_delete_uri(full_uri, token, request),
+            Ok(full_uri) => self.http_delete_uri(full_uri, token, ratelimiter, request),
             Err(err) => std::future::ready(Err(err)).boxed(),
         }
     }
@@ -242,6 +291,8 @@ where
         Self {
             http_api: FtClientHttpApi::new(Arc::new(http_connector)),
             meta: HeaderMetaData::new(RateLimiter::new(2, 1200)),
+            dry_run: false,
+            cancellation: CancellationToken::new(),
         }
     }
 
@@ -250,9 +301,129 @@ where
         Self {
             http_api: FtClientHttpApi::new(Arc::new(http_connector)),
             meta: HeaderMetaData::new(RateLimiter::new(secondly, hourly)),
+            dry_run: false,
+            cancellation: CancellationToken::new(),
         }
     }
 
+    /// Create a new `FtClient` with the rate limits reported for an application.
+    ///
+    /// Falls back to the defaults of `FtClient::new` when the application has no limit.
+    pub fn with_app_ratelimits(http_connector: FCHC, app: &FtApp) -> Self {
+        match app.ratelimits() {
+            Some((secondly, hourly)) => Self::with_ratelimits(http_connector, secondly, hourly),
+            None => Self::new(http_connector),
+        }
+    }
+
+    /// Simulates the mutating requests instead of sending them, e.g. to try a bulk scheduler
+    /// against production data.
+    ///
+    /// In dry-run mode, POST/PATCH/DELETE bodies are serialized and logged at `info` level, and
+    /// the call returns `FtClientError::DryRun` holding the request instead of a response, as
+    /// there is none to decode; `is_dry_run` tells it apart from the failures. GET requests are
+    /// sent as usual.
+    ///
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example() -> ClientResult<()> {
+    ///     let client = FtClient::new(FtClientReqwestConnector::new()).with_dry_run(true);
+    ///     let token = FtApiToken::try_get(AuthInfo::build_from_env()?).await?;
+    ///     let session = client.open_session(token);
+    ///
+    ///     // Logged, not sent.
+    ///     let body = FtApiScaleTeamsPostBody::new(
+    ///         FtScaleId::new(45833),
+    ///         FtTeamId::new(6_298_862),
+    ///         FtUserId::new(174_094),
+    ///         FtDateTimeUtc::new(chrono::Utc::now()),
+    ///     );
+    ///     match session.scale_teams_post(FtApiScaleTeamsPostRequest::new(body)).await {
+    ///         Ok(res) => println!("booked {}", res.scale_team.id),
+    ///         Err(err) if err.is_dry_run() => println!("{err}"),
+    ///         Err(err) => return Err(err),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Calls `hooks` when requests of the client wait for the rate limits or are retried.
+    pub fn with_hooks(mut self, hooks: FtClientHooks) -> Self {
+        self.meta.hooks = hooks;
+        self
+    }
+
+    /// The hooks set with `with_hooks`, e.g. to pass them to `FtJobQueue::with_hooks`.
+    pub fn hooks(&self) -> &FtClientHooks {
+        &self.meta.hooks
+    }
+
+    /// Stops the loops run with the client once `cancellation` is cancelled, e.g. on Ctrl-C.
+    ///
+    /// The request in flight is not aborted: the loops check the token before sending the next
+    /// one, i.e. between two pages or two mutations.
+    ///
+    /// * `scroller`, `fetch_users`, `close_stale_teams` and `plan_coalition_awards` return what
+    ///   they fetched so far.
+    /// * `FtTeamClosingPlan::apply` and `FtCoalitionAwardPlan::apply` report the changes not sent
+    ///   as cancelled, and `FtOutbox::replay` keeps them for the next replay.
+    /// * `FtSlotPlanner::fetch` and `FtTruantReport::fetch` fail with a `SystemError`, as a
+    ///   schedule built on part of the slots or truancies would be wrong.
+    /// * `FtCampaign::run` fails with `FtCampaignError::Cancelled` without writing its output, so
+    ///   the previous export is kept.
+    ///
+    /// `paginate_until` and `FtJobQueue::with_cancellation` take the token itself, e.g.
+    /// `client.cancellation.clone()`.
+    ///
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example() {
+    ///     let cancellation = CancellationToken::new();
+    ///     let client = FtClient::new(FtClientReqwestConnector::new())
+    ///         .with_cancellation(cancellation.clone());
+    ///     tokio::spawn(async move {
+    ///         tokio::signal::ctrl_c().await.unwrap();
+    ///         cancellation.cancel();
+    ///     });
+    /// }
+    /// ```
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Whether the token set with `with_cancellation` is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// The live rate-limit state of the client, shared by all its sessions.
+    ///
+    /// Combines what the limiter has left in the current windows with the rate-limit headers of
+    /// the last response, e.g. for a CLI to display the quota or an orchestrator to decide
+    /// whether to start a new batch.
+    ///
+    /// ```rust,no_run
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn example(client: &FtClient<FtClientReqwestConnector>) {
+    ///     let status = client.rate_limit_status();
+    ///     if !status.can_send(500) {
+    ///         println!("{} requests left this hour", status.hourly_remaining);
+    ///     }
+    /// }
+    /// ```
+    pub fn rate_limit_status(&self) -> FtRateLimitStatus {
+        self.meta.ratelimiter.status()
+    }
+
     /// Open a new session for the client.
     pub fn open_session(&'_ self, token: FtApiToken) -> FtClientSession<'_, FCHC> {
         // TODO: Add tracer for LOGGING
@@ -293,6 +464,14 @@ where
             .await
     }
 
+    pub async fn http_get_bytes_uri(&self, full_uri: Url) -> ClientResult<Vec<u8>> {
+        self.client
+            .http_api
+            .connector
+            .http_get_bytes_uri(full_uri, &self.token, &self.client.meta)
+            .await
+    }
+
     pub async fn http_get<'p, RS, PT, TS>(
         &self,
         method_relative_uri: &str,
@@ -310,6 +489,25 @@ where
             .await
     }
 
+    /// Sends a GET request to a list endpoint, and returns its records as a `FtRawPage`.
+    pub async fn http_get_raw<'p, T, PT, TS>(
+        &self,
+        method_relative_uri: &str,
+        params: &'p PT,
+    ) -> ClientResult<FtRawPage<T>>
+    where
+        PT: std::iter::IntoIterator<Item = (String, Option<TS>)> + Clone,
+        TS: AsRef<str> + 'p,
+    {
+        let full_uri = self
+            .client
+            .http_api
+            .connector
+            .create_method_uri_path(method_relative_uri)
+            .and_then(|url| FtClientHttpApiUri::create_url_with_params(url, params))?;
+        FtRawPage::new(self.http_get_bytes_uri(full_uri).await?)
+    }
+
     pub async fn http_post<RQ, RS>(
         &self,
         method_relative_uri: &str,
@@ -319,10 +517,14 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("POST", method_relative_uri, request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_post(method_relative_uri, &self.token, request)
+            .http_post(method_relative_uri, &self.token, &self.client.meta, request)
             .await
     }
 
@@ -331,10 +533,14 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("POST", full_uri.as_str(), request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_post_uri(full_uri, &self.token, request)
+            .http_post_uri(full_uri, &self.token, &self.client.meta, request)
             .await
     }
 
@@ -347,10 +553,14 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("DELETE", method_relative_uri, request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_delete(method_relative_uri, &self.token, request)
+            .http_delete(method_relative_uri, &self.token, &self.client.meta, request)
             .await
     }
 
@@ -359,10 +569,14 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("DELETE", full_uri.as_str(), request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_delete_uri(full_uri, &self.token, request)
+            .http_delete_uri(full_uri, &self.token, &self.client.meta, request)
             .await
     }
 
@@ -375,10 +589,14 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("PATCH", method_relative_uri, request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_patch(method_relative_uri, &self.token, request)
+            .http_patch(method_relative_uri, &self.token, &self.client.meta, request)
             .await
     }
 
@@ -387,20 +605,43 @@ where
         RQ: serde::ser::Serialize + Send + Sync,
         RS: for<'de> serde::de::Deserialize<'de> + Send,
     {
+        if self.client.dry_run {
+            return Self::simulate("PATCH", full_uri.as_str(), request);
+        }
+
         self.client
             .http_api
             .connector
-            .http_patch_uri(full_uri, &self.token, request)
+            .http_patch_uri(full_uri, &self.token, &self.client.meta, request)
             .await
     }
 }
 
-lazy_static! {
-    pub static ref FT_HTTP_EMPTY_GET_PARAMS: Vec<(String, Option<&'static String>)> = vec![];
-    pub static ref FT_HTTP_PAGE_SIZE_100: Vec<(String, Option<&'static str>)> =
-        vec![("page[size]".to_string(), Some("100"))];
+impl<FCHC> FtClientHttpSessionApi<'_, FCHC>
+where
+    FCHC: FtClientHttpConnector + Send,
+{
+    fn simulate<RQ, RS>(method: &str, target: &str, request: &RQ) -> ClientResult<RS>
+    where
+        RQ: serde::ser::Serialize,
+    {
+        let body = serde_json::to_value(request)
+            .map_err(|err| FtClientError::ProtocolError(FtProtocolError::new(err)))?;
+        tracing::info!(ft_method = method, ft_url = target, %body, "Dry run, not sending");
+
+        Err(FtClientError::DryRun(FtDryRun::new(
+            method.to_string(),
+            target.to_string(),
+            body,
+        )))
+    }
 }
 
+pub static FT_HTTP_EMPTY_GET_PARAMS: LazyLock<Vec<(String, Option<&'static String>)>> =
+    LazyLock::new(Vec::new);
+pub static FT_HTTP_PAGE_SIZE_100: LazyLock<Vec<(String, Option<&'static str>)>> =
+    LazyLock::new(|| vec![("page[size]".to_string(), Some("100"))]);
+
 impl FtClientHttpApiUri {
     pub const FT_API_URI_STR: &'static str = "https://api.intra.42.fr/v2";
 
@@ -422,3 +663,108 @@ impl FtClientHttpApiUri {
         Ok(Url::parse_with_params(base_url.as_str(), url_query_params)?)
     }
 }
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn session_debug_redacts_the_token() {
+        let client = FtClient::new(FtClientReqwestConnector::new());
+        let session = client.open_session(crate::test_support::FtMockServer::token());
+
+        let debug = format!("{session:?}");
+        assert!(!debug.contains("mock-access-token"), "{debug}");
+        assert!(debug.contains(FT_REDACTED));
+    }
+
+    #[cfg(feature = "users")]
+    #[tokio::test]
+    async fn rate_limit_status_reflects_the_last_response() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let client = mock.client();
+        assert_eq!(client.rate_limit_status().server, None);
+
+        client
+            .open_session(FtMockServer::token())
+            .users(FtApiUsersRequest::new())
+            .await
+            .unwrap();
+
+        let status = client.rate_limit_status();
+        assert_eq!(status.hourly_limit, FT_MOCK_RATELIMITS.1);
+        assert_eq!(status.hourly_remaining, FT_MOCK_RATELIMITS.1 - 1);
+        assert!(status.can_send(1));
+        let (_, server) = status.server.unwrap();
+        assert_eq!(server.secondly_limit, Some(FT_MOCK_RATELIMITS.0));
+        assert_eq!(server.hourly_remaining, Some(FT_MOCK_RATELIMITS.1 - 1));
+        assert_eq!(server.retry_after, None);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_status_reflects_mutations() {
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        mock.mock_json("POST", "notes", serde_json::json!({})).await;
+        mock.mock_rate_limited("notes/1", 5, 1).await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let _: serde_json::Value = session
+            .http_session_api
+            .http_post("notes", &serde_json::json!({}))
+            .await
+            .unwrap();
+        let status = client.rate_limit_status();
+        assert_eq!(status.hourly_remaining, FT_MOCK_RATELIMITS.1 - 1);
+        let (_, server) = status.server.unwrap();
+        assert_eq!(server.hourly_remaining, Some(FT_MOCK_RATELIMITS.1 - 1));
+
+        let res: ClientResult<serde_json::Value> = session
+            .http_session_api
+            .http_delete("notes/1", &serde_json::json!({}))
+            .await;
+        assert!(
+            matches!(res, Err(FtClientError::RateLimitError(_))),
+            "{res:?}"
+        );
+        let (_, server) = client.rate_limit_status().server.unwrap();
+        assert_eq!(server.retry_after, Some(std::time::Duration::from_secs(5)));
+    }
+
+    #[cfg(feature = "scale_teams")]
+    #[tokio::test]
+    async fn dry_run_does_not_send_mutations() {
+        use chrono::{TimeZone, Utc};
+
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let client = mock.client().with_dry_run(true);
+        let session = client.open_session(FtMockServer::token());
+
+        let body = FtApiScaleTeamsPostBody::new(
+            FtScaleId::new(45833),
+            FtTeamId::new(FT_MOCK_TEAM_IDS[0]),
+            FtUserId::new(FT_MOCK_USERS[2].0),
+            FtDateTimeUtc::new(Utc.with_ymd_and_hms(2025, 1, 28, 7, 0, 0).unwrap()),
+        );
+        let err = session
+            .scale_teams_post(FtApiScaleTeamsPostRequest::new(body))
+            .await
+            .unwrap_err();
+        assert!(err.is_dry_run(), "{err:?}");
+        let FtClientError::DryRun(dry_run) = err else {
+            unreachable!();
+        };
+        assert_eq!(dry_run.method, "POST");
+        assert_eq!(dry_run.url, "scale_teams");
+        assert_eq!(dry_run.body["scale_team"]["scale_id"], 45833);
+
+        assert!(mock.server().received_requests().await.unwrap().is_empty());
+    }
+}
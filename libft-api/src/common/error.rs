@@ -4,19 +4,19 @@ use std::fmt::Formatter;
 use std::time::Duration;
 use url::ParseError;
 
-use reqwest::StatusCode;
+use http::StatusCode;
 
 use crate::auth::TokenError;
 
 #[macro_export]
 macro_rules! enum_into {
-	($vis:vis $enum_ty:ident $($enum_item:ident $(,)?)*) => {
+	($vis:vis $enum_ty:ident $($(#[$attr:meta])* $enum_item:ident $(,)?)*) => {
 		#[derive(Debug)]
 		$vis enum $enum_ty {
-			$($enum_item(${concat(Ft,$enum_item)})),*
+			$($(#[$attr])* $enum_item(${concat(Ft,$enum_item)})),*
 		}
 
-		$(impl From<${concat(Ft,$enum_item)}> for $enum_ty {
+		$($(#[$attr])* impl From<${concat(Ft,$enum_item)}> for $enum_ty {
 			fn from(err: ${concat(Ft,$enum_item)}) -> Self {
 				$enum_ty::$enum_item(err)
 			}
@@ -25,6 +25,7 @@ macro_rules! enum_into {
 }
 
 enum_into!(pub FtClientError
+    #[cfg(feature = "reqwest")]
     ReqwestError
     ApiError
     HttpError
@@ -33,9 +34,42 @@ enum_into!(pub FtClientError
     SystemError
     ProtocolError
     RateLimitError
+    DryRun
 );
 
 impl FtClientError {
+    /// The kind of failure the Intra reported, recognized from the status and body of its
+    /// response; `None` for errors without a response, such as network or JSON errors.
+    ///
+    /// ```rust
+    /// use libft_api::prelude::*;
+    ///
+    /// async fn login_exists(
+    ///     session: &FtClientSession<'_, FtClientReqwestConnector>,
+    ///     login: FtLoginId,
+    /// ) -> ClientResult<bool> {
+    ///     let req = FtApiUsersIdRequest::new(FtUserIdentifier::Login(login));
+    ///     match session.users_id(req).await {
+    ///         Ok(_) => Ok(true),
+    ///         Err(err) if err.kind() == Some(FtApiErrorKind::NotFound) => Ok(false),
+    ///         Err(err) => Err(err),
+    ///     }
+    /// }
+    /// ```
+    pub fn kind(&self) -> Option<FtApiErrorKind> {
+        match self {
+            FtClientError::HttpError(err) => err.kind(),
+            FtClientError::RateLimitError(_) => Some(FtApiErrorKind::RateLimited),
+            _ => None,
+        }
+    }
+
+    /// Whether the request was not sent because the client is in dry-run mode, an outcome
+    /// callers usually count as a success.
+    pub fn is_dry_run(&self) -> bool {
+        matches!(self, FtClientError::DryRun(_))
+    }
+
     fn option_to_string<T: ToString>(value: &Option<T>) -> String {
         value
             .as_ref()
@@ -46,6 +80,7 @@ impl FtClientError {
 impl std::fmt::Display for FtClientError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match *self {
+            #[cfg(feature = "reqwest")]
             FtClientError::ReqwestError(ref err) => err.fmt(f),
             FtClientError::ApiError(ref err) => err.fmt(f),
             FtClientError::HttpError(ref err) => err.fmt(f),
@@ -54,21 +89,65 @@ impl std::fmt::Display for FtClientError {
             FtClientError::SystemError(ref err) => err.fmt(f),
             FtClientError::ProtocolError(ref err) => err.fmt(f),
             FtClientError::RateLimitError(ref err) => err.fmt(f),
+            FtClientError::DryRun(ref err) => err.fmt(f),
         }
     }
 }
 
+impl std::error::Error for FtClientError {}
+
+#[cfg(feature = "reqwest")]
 #[derive(Debug)]
 pub struct FtReqwestError {
     pub error: reqwest::Error,
 }
 
+#[cfg(feature = "reqwest")]
+impl FtReqwestError {
+    /// Wraps `error`, redacting the secrets the URL of the failed request may hold.
+    pub fn new(mut error: reqwest::Error) -> Self {
+        if let Some(url) = error.url_mut() {
+            *url = redacted_url(url);
+        }
+        Self { error }
+    }
+}
+
+/// The query parameters holding credentials, redacted from URLs in errors and logs.
+const FT_SECRET_PARAMS: [&str; 4] = ["access_token", "client_secret", "code", "refresh_token"];
+
+/// `url`, with the values of its credential query parameters (`access_token`, `client_secret`,
+/// ...) replaced by `<redacted>`.
+pub fn redacted_url(url: &url::Url) -> url::Url {
+    let secret = |key: &str| FT_SECRET_PARAMS.contains(&key);
+    if !url.query_pairs().any(|(key, _)| secret(&key)) {
+        return url.clone();
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if secret(&key) {
+                crate::auth::FT_REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    let mut url = url.clone();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url
+}
+
+#[cfg(feature = "reqwest")]
 impl std::fmt::Display for FtReqwestError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Reqwest Error: {}", self.error,)
     }
 }
 
+#[cfg(feature = "reqwest")]
 impl std::error::Error for FtReqwestError {}
 
 #[derive(Debug, Builder)]
@@ -111,6 +190,125 @@ impl std::fmt::Display for FtHttpError {
 
 impl std::error::Error for FtHttpError {}
 
+impl FtHttpError {
+    /// The kind of failure, recognized from the status and the messages of the body.
+    pub fn kind(&self) -> Option<FtApiErrorKind> {
+        FtApiErrorKind::classify(self.status_code, self.http_response_body.as_deref())
+    }
+}
+
+/// The failures the Intra reports, so callers can branch on them rather than on the English
+/// messages of the response bodies.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FtApiErrorKind {
+    /// `401`: the token is invalid, expired or revoked.
+    InvalidToken,
+    /// `403` asking for a scope the token was not granted, e.g. `projects`.
+    InsufficientScope,
+    /// Any other `403`: the application may not access the resource.
+    Forbidden,
+    NotFound,
+    /// "This scale team is already filled", when booking a slot someone else just took.
+    ScaleTeamAlreadyFilled,
+    /// "User already registered", or "has already been taken" on the `user` or `user_id` field,
+    /// e.g. when subscribing a user to an event, an exam or a project twice.
+    AlreadyRegistered,
+    /// Any other `422`, the body listing the invalid fields.
+    Validation,
+    RateLimited,
+    /// A `5xx`, worth retrying later.
+    ServerError,
+}
+
+impl FtApiErrorKind {
+    /// Recognizes the kind of an error response from its status and body.
+    ///
+    /// The messages are the strings of a JSON body, each with the field it validates (e.g.
+    /// `login` in `{"errors": {"login": ["has already been taken"]}}`), or the whole body
+    /// otherwise. Statuses without a known kind give `None`.
+    pub fn classify(status_code: StatusCode, body: Option<&str>) -> Option<Self> {
+        let messages = body.map(error_messages).unwrap_or_default();
+        let mentions = |patterns: &[&str]| {
+            messages
+                .iter()
+                .any(|(_, message)| patterns.iter().any(|p| message.contains(p)))
+        };
+
+        if mentions(&["already filled"]) {
+            return Some(Self::ScaleTeamAlreadyFilled);
+        }
+        let user_taken = messages.iter().any(|(field, message)| {
+            matches!(field.as_deref(), Some("user" | "user_id"))
+                && message.contains("already been taken")
+        });
+        if user_taken || mentions(&["user already registered"]) {
+            return Some(Self::AlreadyRegistered);
+        }
+        match status_code {
+            StatusCode::UNAUTHORIZED => Some(Self::InvalidToken),
+            StatusCode::FORBIDDEN if mentions(&["insufficient_scope", "scope"]) => {
+                Some(Self::InsufficientScope)
+            }
+            StatusCode::FORBIDDEN => Some(Self::Forbidden),
+            StatusCode::NOT_FOUND => Some(Self::NotFound),
+            StatusCode::UNPROCESSABLE_ENTITY => Some(Self::Validation),
+            StatusCode::TOO_MANY_REQUESTS => Some(Self::RateLimited),
+            status_code if status_code.is_server_error() => Some(Self::ServerError),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FtApiErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let kind = match self {
+            FtApiErrorKind::InvalidToken => "invalid token",
+            FtApiErrorKind::InsufficientScope => "insufficient scope",
+            FtApiErrorKind::Forbidden => "forbidden",
+            FtApiErrorKind::NotFound => "not found",
+            FtApiErrorKind::ScaleTeamAlreadyFilled => "scale team already filled",
+            FtApiErrorKind::AlreadyRegistered => "already registered",
+            FtApiErrorKind::Validation => "validation failed",
+            FtApiErrorKind::RateLimited => "rate limited",
+            FtApiErrorKind::ServerError => "server error",
+        };
+        f.write_str(kind)
+    }
+}
+
+/// The messages of an error body, lowercased, with the field each one validates if any.
+fn error_messages(body: &str) -> Vec<(Option<String>, String)> {
+    fn collect(
+        field: Option<&str>,
+        value: &serde_json::Value,
+        messages: &mut Vec<(Option<String>, String)>,
+    ) {
+        match value {
+            serde_json::Value::String(message) => {
+                messages.push((field.map(str::to_string), message.to_lowercase()));
+            }
+            serde_json::Value::Array(values) => {
+                values
+                    .iter()
+                    .for_each(|value| collect(field, value, messages));
+            }
+            serde_json::Value::Object(fields) => {
+                for (field, value) in fields {
+                    collect(Some(field), value, messages);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
+        return vec![(None, body.to_lowercase())];
+    };
+    let mut messages = Vec::new();
+    collect(None, &value, &mut messages);
+    messages
+}
+
 #[derive(Debug, Builder)]
 pub struct FtHttpProtocolError {
     pub cause: Option<Box<dyn std::error::Error + Sync + Send>>,
@@ -208,6 +406,23 @@ impl std::fmt::Display for FtRateLimitError {
 
 impl std::error::Error for FtRateLimitError {}
 
+/// A mutation the client did not send because it is in dry-run mode.
+#[derive(Debug, PartialEq, Clone, Builder)]
+pub struct FtDryRun {
+    pub method: String,
+    /// The URL of the request, relative to the API root when the call gave a relative one.
+    pub url: String,
+    pub body: serde_json::Value,
+}
+
+impl std::fmt::Display for FtDryRun {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Ft dry run, not sent: {} {}", self.method, self.url)
+    }
+}
+
+impl std::error::Error for FtDryRun {}
+
 impl From<url::ParseError> for FtClientError {
     fn from(url_parse_error: ParseError) -> Self {
         FtClientError::HttpProtocolError(
@@ -253,3 +468,90 @@ pub fn map_serde_error(err: serde_json::Error, tried_to_parse: Option<&str>) ->
             .opt_json_body(tried_to_parse.map(std::string::ToString::to_string)),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_credentials_from_urls() {
+        let url: url::Url = "https://api.intra.42.fr/v2/me?access_token=a1b2c3&page=2"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            redacted_url(&url).as_str(),
+            "https://api.intra.42.fr/v2/me?access_token=%3Credacted%3E&page=2"
+        );
+
+        let url: url::Url = "https://api.intra.42.fr/v2/users?filter[login]=mockstu"
+            .parse()
+            .unwrap();
+        assert_eq!(redacted_url(&url), url);
+    }
+
+    #[test]
+    fn classifies_api_errors() {
+        let kind = |status: u16, body: &str| {
+            FtClientError::HttpError(
+                FtHttpError::new(StatusCode::from_u16(status).unwrap())
+                    .with_http_response_body(body.to_string()),
+            )
+            .kind()
+        };
+
+        assert_eq!(
+            kind(
+                422,
+                r#"{"errors": {"base": ["This scale team is already filled"]}}"#
+            ),
+            Some(FtApiErrorKind::ScaleTeamAlreadyFilled)
+        );
+        assert_eq!(
+            kind(422, r#"{"errors": ["User already registered"]}"#),
+            Some(FtApiErrorKind::AlreadyRegistered)
+        );
+        assert_eq!(
+            kind(422, r#"{"user": ["has already been taken"]}"#),
+            Some(FtApiErrorKind::AlreadyRegistered)
+        );
+        assert_eq!(
+            kind(422, r#"{"errors": {"begin_at": ["can't be blank"]}}"#),
+            Some(FtApiErrorKind::Validation)
+        );
+        assert_eq!(
+            kind(422, r#"{"name": ["has already been taken"]}"#),
+            Some(FtApiErrorKind::Validation)
+        );
+        assert_eq!(
+            kind(
+                403,
+                r#"{"error": "insufficient_scope", "error_description": "The request requires higher privileges than provided by the access token."}"#
+            ),
+            Some(FtApiErrorKind::InsufficientScope)
+        );
+        assert_eq!(
+            kind(
+                403,
+                r#"{"error": "Forbidden", "message": "You are not authorized"}"#
+            ),
+            Some(FtApiErrorKind::Forbidden)
+        );
+        assert_eq!(
+            kind(
+                401,
+                r#"{"error": "Not authorized", "message": "The access token expired"}"#
+            ),
+            Some(FtApiErrorKind::InvalidToken)
+        );
+        assert_eq!(
+            kind(404, "<html>Not Found</html>"),
+            Some(FtApiErrorKind::NotFound)
+        );
+        assert_eq!(kind(502, ""), Some(FtApiErrorKind::ServerError));
+        assert_eq!(kind(409, "{}"), None);
+        assert_eq!(
+            FtClientError::RateLimitError(FtRateLimitError::new()).kind(),
+            Some(FtApiErrorKind::RateLimited)
+        );
+    }
+}
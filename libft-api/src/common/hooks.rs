@@ -0,0 +1,186 @@
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::common::FtClientError;
+
+type RateLimitedHook = dyn Fn(Duration, &str) -> ControlFlow<()> + Send + Sync;
+type RetryHook = dyn Fn(u32, &FtClientError) -> ControlFlow<()> + Send + Sync;
+
+/// Callbacks notified when requests wait for the rate limits or are sent again, set with
+/// `FtClient::with_hooks`.
+///
+/// * `on_rate_limited(wait, endpoint)` is called after a request waited `wait` for the rate
+///   limiter, before it is sent. `endpoint` is the label of the `metrics` module, e.g.
+///   `users/:id`. Returning `ControlFlow::Break` fails the request with a `SystemError` instead
+///   of sending it.
+/// * `on_retry(attempt, error)` is called before a request failing on a rate limit or a transient
+///   error is sent again, `attempt` being 1 for the first retry of the request. Returning
+///   `ControlFlow::Break` gives up: the error is returned as if it could not be retried.
+///
+/// Retries are reported by the loops run with a client: `fetch_users`, `scroller`,
+/// `FtCampaign::run`, and `FtJobQueue::run` given the hooks with `FtJobQueue::with_hooks`.
+/// `paginate` and `fetch_many` only see closures, and report nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use std::time::Duration;
+///
+/// use libft_api::prelude::*;
+///
+/// let hooks = FtClientHooks::new()
+///     .on_rate_limited(|wait, endpoint| {
+///         tracing::info!(?wait, endpoint, "waited for the rate limits");
+///         ControlFlow::Continue(())
+///     })
+///     .on_retry(|attempt, err| {
+///         tracing::warn!(attempt, %err, "retrying");
+///         if attempt < 10 {
+///             ControlFlow::Continue(())
+///         } else {
+///             ControlFlow::Break(())
+///         }
+///     });
+/// let client = FtClient::new(FtClientReqwestConnector::new()).with_hooks(hooks);
+/// ```
+#[derive(Clone, Default)]
+pub struct FtClientHooks {
+    rate_limited: Option<Arc<RateLimitedHook>>,
+    retry: Option<Arc<RetryHook>>,
+}
+
+impl FtClientHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn on_rate_limited<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(Duration, &str) -> ControlFlow<()> + Send + Sync + 'static,
+    {
+        self.rate_limited = Some(Arc::new(hook));
+        self
+    }
+
+    #[must_use]
+    pub fn on_retry<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u32, &FtClientError) -> ControlFlow<()> + Send + Sync + 'static,
+    {
+        self.retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// Calls `on_rate_limited`, if set.
+    pub fn rate_limited(&self, wait: Duration, endpoint: &str) -> ControlFlow<()> {
+        self.rate_limited
+            .as_ref()
+            .map_or(ControlFlow::Continue(()), |hook| hook(wait, endpoint))
+    }
+
+    /// Calls `on_retry`, if set.
+    pub fn retry(&self, attempt: u32, error: &FtClientError) -> ControlFlow<()> {
+        self.retry
+            .as_ref()
+            .map_or(ControlFlow::Continue(()), |hook| hook(attempt, error))
+    }
+}
+
+impl std::fmt::Debug for FtClientHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FtClientHooks")
+            .field("on_rate_limited", &self.rate_limited.is_some())
+            .field("on_retry", &self.retry.is_some())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "users", feature = "reqwest"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::test_support::*;
+
+    fn user_request() -> FtApiUsersIdRequest {
+        FtApiUsersIdRequest::new(FtUserIdentifier::UserId(FtUserId::new(FT_MOCK_USERS[0].0)))
+    }
+
+    #[tokio::test]
+    async fn reports_rate_limit_waits() {
+        let mock = FtMockServer::start().await;
+        mock.mock_rate_limited("users/190001", 1, 1).await;
+        let waits = Arc::new(Mutex::new(Vec::new()));
+        let client = mock
+            .client()
+            .with_hooks(FtClientHooks::new().on_rate_limited({
+                let waits = Arc::clone(&waits);
+                move |wait, endpoint| {
+                    waits.lock().unwrap().push((wait, endpoint.to_string()));
+                    ControlFlow::Continue(())
+                }
+            }));
+        let session = client.open_session(FtMockServer::token());
+
+        let res = session.users_id(user_request()).await;
+        assert!(matches!(res, Err(FtClientError::RateLimitError(_))));
+        // Sent once the `Retry-After` is over.
+        session.users_id(user_request()).await.unwrap();
+
+        let waits = waits.lock().unwrap();
+        assert_eq!(waits.len(), 1);
+        assert!(waits[0].0 >= Duration::from_millis(900), "{waits:?}");
+        assert_eq!(waits[0].1, "users/:id");
+    }
+
+    #[tokio::test]
+    async fn rate_limited_hook_aborts_the_request() {
+        let mock = FtMockServer::start().await;
+        mock.mock_rate_limited("users/190001", 1, 1).await;
+        let client = mock
+            .client()
+            .with_hooks(FtClientHooks::new().on_rate_limited(|_, _| ControlFlow::Break(())));
+        let session = client.open_session(FtMockServer::token());
+
+        let _ = session.users_id(user_request()).await;
+        let res = session.users_id(user_request()).await;
+        assert!(matches!(res, Err(FtClientError::SystemError(_))));
+        assert_eq!(mock.server().received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reports_and_stops_retries() {
+        let mock = FtMockServer::start().await;
+        mock.mock_rate_limited("users/190001", 0, 3).await;
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+        let client = mock.client().with_hooks(FtClientHooks::new().on_retry({
+            let attempts = Arc::clone(&attempts);
+            move |attempt, err| {
+                assert!(matches!(err, FtClientError::RateLimitError(_)));
+                attempts.lock().unwrap().push(attempt);
+                if attempt < 2 {
+                    ControlFlow::Continue(())
+                } else {
+                    ControlFlow::Break(())
+                }
+            }
+        }));
+        let session = client.open_session(FtMockServer::token());
+
+        let users = session
+            .fetch_users([FtUserId::new(FT_MOCK_USERS[0].0)], 1)
+            .await;
+        assert!(matches!(users[0], Err(FtClientError::RateLimitError(_))));
+        assert_eq!(*attempts.lock().unwrap(), vec![1, 2]);
+
+        // The third `429` is retried once, then the user is returned.
+        let users = session
+            .fetch_users([FtUserId::new(FT_MOCK_USERS[0].0)], 1)
+            .await;
+        assert!(users[0].is_ok());
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// Drops the records already seen on another page of a paginated pull, and reports the signs
+/// that records were skipped.
+///
+/// Pages are numbered from 1 and may be recorded in any order, e.g. by several workers pulling
+/// interleaved pages; share the audit behind a `Mutex` then. Records are identified by `key`,
+/// usually their id; records without one are always kept.
+///
+/// When the collection changes during the pull, its records shift across pages: a record seen
+/// twice means the pages moved under the pull, and when they move the other way, or pages are
+/// fetched out of order, as many records are skipped silently. `report` lists those signs.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Mutex;
+///
+/// use futures::future::join_all;
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let audit = Mutex::new(FtPageAudit::new(100));
+///     let workers = (1..=4).map(|first_page| {
+///         let (session, audit) = (&session, &audit);
+///         async move {
+///             let mut users = Vec::new();
+///             for page in (first_page..).step_by(4) {
+///                 let req = FtApiUsersRequest::new().with_page(page).with_per_page(100);
+///                 let records = session.users(req).await?.users;
+///                 let len = records.len();
+///                 users.extend(audit.lock().unwrap().dedup_page(page, records, |user| user.id));
+///                 if len < 100 {
+///                     break;
+///                 }
+///             }
+///             ClientResult::Ok(users)
+///         }
+///     });
+///     let users = join_all(workers).await.into_iter().collect::<ClientResult<Vec<_>>>()?;
+///     let users = users.into_iter().flatten().collect::<Vec<_>>();
+///
+///     let report = audit.into_inner().unwrap().report();
+///     if report.has_suspected_gaps() {
+///         eprintln!("{report}, pull again");
+///     }
+///     println!("{} users", users.len());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FtPageAudit<K> {
+    per_page: usize,
+    expected_total: Option<usize>,
+    seen: HashSet<K>,
+    pages: BTreeMap<usize, FtPageTally>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct FtPageTally {
+    records: usize,
+    duplicates: usize,
+}
+
+/// What `FtPageAudit` saw of a pull.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct FtPageAuditReport {
+    pub pages: usize,
+    /// The records kept, duplicates excluded.
+    pub records: usize,
+    pub duplicates: usize,
+    /// Pages holding records already seen on another page.
+    pub pages_with_duplicates: Vec<usize>,
+    /// Pages below the last one recorded that were never recorded, e.g. after a failed worker.
+    pub missing_pages: Vec<usize>,
+    /// Pages below the last one recorded with fewer than `per_page` records.
+    pub short_pages: Vec<usize>,
+    /// The size of the collection announced by the API, when given with `with_expected_total`.
+    pub expected_total: Option<usize>,
+}
+
+impl<K> FtPageAudit<K>
+where
+    K: Eq + Hash,
+{
+    /// An audit of pages of `per_page` records.
+    pub fn new(per_page: u8) -> Self {
+        Self {
+            per_page: usize::from(per_page),
+            expected_total: None,
+            seen: HashSet::new(),
+            pages: BTreeMap::new(),
+        }
+    }
+
+    /// The size of the collection, e.g. from the `x-total` header, to compare the records kept
+    /// against.
+    pub fn with_expected_total(mut self, expected_total: usize) -> Self {
+        self.expected_total = Some(expected_total);
+        self
+    }
+
+    /// Records page `page` and returns its records not seen on another page.
+    ///
+    /// Recording the same page twice, e.g. on a retry, counts its records as duplicates.
+    pub fn dedup_page<T>(
+        &mut self,
+        page: usize,
+        records: Vec<T>,
+        key: impl Fn(&T) -> Option<K>,
+    ) -> Vec<T> {
+        let tally = self.pages.entry(page).or_default();
+        tally.records += records.len();
+        records
+            .into_iter()
+            .filter(|record| {
+                let Some(key) = key(record) else {
+                    return true;
+                };
+                let new = self.seen.insert(key);
+                if !new {
+                    tally.duplicates += 1;
+                }
+                new
+            })
+            .collect()
+    }
+
+    pub fn report(&self) -> FtPageAuditReport {
+        let last_page = self.pages.keys().next_back().copied().unwrap_or(0);
+        let duplicates = self.pages.values().map(|tally| tally.duplicates).sum();
+        FtPageAuditReport {
+            pages: self.pages.len(),
+            records: self
+                .pages
+                .values()
+                .map(|tally| tally.records)
+                .sum::<usize>()
+                - duplicates,
+            duplicates,
+            pages_with_duplicates: self
+                .pages
+                .iter()
+                .filter(|(_, tally)| tally.duplicates > 0)
+                .map(|(page, _)| *page)
+                .collect(),
+            missing_pages: (1..last_page)
+                .filter(|page| !self.pages.contains_key(page))
+                .collect(),
+            short_pages: self
+                .pages
+                .range(..last_page)
+                .filter(|(_, tally)| tally.records < self.per_page)
+                .map(|(page, _)| *page)
+                .collect(),
+            expected_total: self.expected_total,
+        }
+    }
+}
+
+impl FtPageAuditReport {
+    /// How many records short of `expected_total` the pull is, 0 when unknown.
+    pub fn missing_records(&self) -> usize {
+        self.expected_total.map_or(0, |expected_total| {
+            expected_total.saturating_sub(self.records)
+        })
+    }
+
+    /// Whether records may have been skipped: the pages moved during the pull, some are missing
+    /// or short, or fewer records than expected were kept.
+    pub fn has_suspected_gaps(&self) -> bool {
+        !self.pages_with_duplicates.is_empty()
+            || !self.missing_pages.is_empty()
+            || !self.short_pages.is_empty()
+            || self.missing_records() > 0
+    }
+}
+
+impl Display for FtPageAuditReport {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} record(s) over {} page(s), {} duplicate(s)",
+            self.records, self.pages, self.duplicates
+        )?;
+        if let Some(expected_total) = self.expected_total {
+            write!(f, ", {expected_total} expected")?;
+        }
+        let pages = |pages: &[usize]| {
+            pages
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if !self.pages_with_duplicates.is_empty() {
+            write!(
+                f,
+                "; duplicates on page(s) {}",
+                pages(&self.pages_with_duplicates)
+            )?;
+        }
+        if !self.missing_pages.is_empty() {
+            write!(f, "; missing page(s) {}", pages(&self.missing_pages))?;
+        }
+        if !self.short_pages.is_empty() {
+            write!(f, "; short page(s) {}", pages(&self.short_pages))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_duplicates_and_reports_gaps() {
+        let mut audit = FtPageAudit::new(3).with_expected_total(10);
+        let key = |id: &Option<u32>| *id;
+
+        // Page 3 is pulled first, then a record is inserted at the head of the collection.
+        assert_eq!(
+            audit.dedup_page(3, vec![Some(7), Some(8), Some(9)], key),
+            vec![Some(7), Some(8), Some(9)]
+        );
+        assert_eq!(
+            audit.dedup_page(1, vec![Some(0), Some(1), None], key),
+            vec![Some(0), Some(1), None]
+        );
+        assert_eq!(audit.dedup_page(4, vec![Some(9)], key), vec![]);
+
+        let report = audit.report();
+        assert_eq!(
+            report,
+            FtPageAuditReport {
+                pages: 3,
+                records: 6,
+                duplicates: 1,
+                pages_with_duplicates: vec![4],
+                missing_pages: vec![2],
+                short_pages: vec![],
+                expected_total: Some(10),
+            }
+        );
+        assert_eq!(report.missing_records(), 4);
+        assert!(report.has_suspected_gaps());
+        assert_eq!(
+            report.to_string(),
+            "6 record(s) over 3 page(s), 1 duplicate(s), 10 expected; duplicates on page(s) 4; \
+             missing page(s) 2"
+        );
+    }
+
+    #[test]
+    fn complete_pull() {
+        let mut audit = FtPageAudit::new(2);
+        audit.dedup_page(2, vec![3], |id| Some(*id));
+        audit.dedup_page(1, vec![1, 2], |id| Some(*id));
+
+        let report = audit.report();
+        assert_eq!(report.records, 3);
+        assert!(!report.has_suspected_gaps(), "{report}");
+    }
+}
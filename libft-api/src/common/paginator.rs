@@ -1,10 +1,17 @@
-use std::{ops::ControlFlow, sync::Arc, time::Duration};
+use std::time::Duration;
+#[cfg(feature = "reqwest")]
+use std::{ops::ControlFlow, sync::Arc};
 
 use crate::prelude::*;
 
+#[cfg(feature = "reqwest")]
 use futures::future::BoxFuture;
+use futures::future::Either;
+use futures::{Stream, StreamExt};
+use std::future::Future;
 use tokio::time::sleep;
 
+#[cfg(feature = "reqwest")]
 pub fn req_validator<F, RS>(f: F) -> F
 where
     F: for<'a> Fn(
@@ -14,11 +21,13 @@ where
 {
     f
 }
+#[cfg(feature = "reqwest")]
 pub type ReqFn<RS> = for<'a> fn(
     Arc<FtClientSession<'a, FtClientReqwestConnector>>,
     usize,
 ) -> BoxFuture<'a, ClientResult<RS>>;
 
+#[cfg(feature = "reqwest")]
 pub async fn scroller<'a, T, RS, RQ>(
     client: &'a FtClient<FtClientReqwestConnector>,
     thread_num: usize,
@@ -40,11 +49,13 @@ where
     let request = Arc::new(request_builder);
 
     let mut page = initial_page;
-    while *client.meta.total_page.lock().unwrap() as usize >= page {
+    let mut retries = 0;
+    while !client.is_cancelled() && *client.meta.total_page.lock().unwrap() as usize >= page {
         let page = &mut page;
         let request = Arc::clone(&request);
         if let ControlFlow::Break(()) = {
             let result = &mut result;
+            let retries = &mut retries;
             let session_clone = Arc::clone(&session);
             async move {
                 let res = request(session_clone, *page).await;
@@ -56,13 +67,20 @@ where
 
                         result.extend(res.take_vec());
                         *page += thread_num;
+                        *retries = 0;
                     }
-                    Err(FtClientError::RateLimitError(_)) => {
+                    Err(e @ FtClientError::RateLimitError(_)) => {
+                        *retries += 1;
+                        if client.hooks().retry(*retries, &e).is_break() {
+                            tracing::error!("rate limit retries given up: {e}");
+                            return ControlFlow::Break(());
+                        }
                         tracing::warn!("rate limit, try again.");
+                        crate::metrics::retry("scroller", "rate_limit");
                         sleep(Duration::new(1, 42)).await
                     }
                     Err(e) => {
-                        eprintln!("other error: {e}");
+                        tracing::error!("other error: {e}");
                         return ControlFlow::Break(());
                     }
                 }
@@ -76,3 +94,311 @@ where
     }
     result
 }
+
+/// Streams the records of a paginated endpoint, fetching a page only once the previous one is
+/// consumed.
+///
+/// `fetch` is called with pages `1, 2, ...` and must request `per_page` records per page; the
+/// stream ends after the first short page. A rate-limited page is fetched again once the delay is
+/// over, and any other error is yielded as the last item.
+///
+/// `paginate_until` also ends the stream once a `CancellationToken` is cancelled.
+///
+/// ```rust
+/// use futures::TryStreamExt;
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let users = paginate(100, |page| {
+///         session.campus_id_users(
+///             FtApiCampusIdUsersRequest::new(FtCampusId::new(ft_campus_id::GYEONGSAN))
+///                 .with_page(page)
+///                 .with_per_page(100),
+///         )
+///     });
+///     let logins = users.map_ok(|user| user.login).try_collect::<Vec<_>>().await?;
+///     println!("{} users", logins.len());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn paginate<T, RS, F, Fut>(per_page: u8, fetch: F) -> impl Stream<Item = ClientResult<T>>
+where
+    F: FnMut(u16) -> Fut,
+    Fut: Future<Output = ClientResult<RS>>,
+    RS: HasVec<T>,
+{
+    paginate_until(per_page, CancellationToken::new(), fetch)
+}
+
+/// `paginate`, ending the stream before the next page once `cancellation` is cancelled, e.g. on
+/// Ctrl-C, so the records already streamed can be written out.
+///
+/// A rate-limited page is not waited for once cancelled either.
+pub fn paginate_until<T, RS, F, Fut>(
+    per_page: u8,
+    cancellation: CancellationToken,
+    fetch: F,
+) -> impl Stream<Item = ClientResult<T>>
+where
+    F: FnMut(u16) -> Fut,
+    Fut: Future<Output = ClientResult<RS>>,
+    RS: HasVec<T>,
+{
+    paginate_with_hooks(
+        per_page,
+        FtClientHooks::default(),
+        cancellation,
+        "paginate",
+        fetch,
+    )
+}
+
+/// `paginate_until`, reporting the retries to `hooks` and to the metrics as `source`.
+///
+/// A rate-limited page the hooks give up on is yielded as an error, ending the stream.
+pub(crate) fn paginate_with_hooks<T, RS, F, Fut>(
+    per_page: u8,
+    hooks: FtClientHooks,
+    cancellation: CancellationToken,
+    source: &'static str,
+    fetch: F,
+) -> impl Stream<Item = ClientResult<T>>
+where
+    F: FnMut(u16) -> Fut,
+    Fut: Future<Output = ClientResult<RS>>,
+    RS: HasVec<T>,
+{
+    let pages = futures::stream::unfold((fetch, Some(1u16)), move |(mut fetch, page)| {
+        let hooks = hooks.clone();
+        let cancellation = cancellation.clone();
+        async move {
+            let page = page?;
+            let mut retries = 0;
+            loop {
+                if cancellation.is_cancelled() {
+                    return None;
+                }
+                match fetch(page).await {
+                    Ok(res) => {
+                        let records = res.take_vec();
+                        let next = (records.len() >= usize::from(per_page))
+                            .then(|| page.checked_add(1))
+                            .flatten();
+                        return Some((Ok(records), (fetch, next)));
+                    }
+                    Err(FtClientError::RateLimitError(err)) => {
+                        let retry_after = err.retry_after;
+                        let err = FtClientError::RateLimitError(err);
+                        retries += 1;
+                        if hooks.retry(retries, &err).is_break() {
+                            return Some((Err(err), (fetch, None)));
+                        }
+                        tracing::warn!("rate limit, try again.");
+                        crate::metrics::retry(source, "rate_limit");
+                        tokio::select! {
+                            _ = sleep(retry_after.unwrap_or(Duration::from_secs(1))) => {}
+                            _ = cancellation.cancelled() => return None,
+                        }
+                    }
+                    Err(err) => return Some((Err(err), (fetch, None))),
+                }
+            }
+        }
+    });
+    futures::StreamExt::flat_map(pages, |page| match page {
+        Ok(records) => Either::Left(futures::stream::iter(records.into_iter().map(Ok))),
+        Err(err) => Either::Right(futures::stream::once(std::future::ready(Err(err)))),
+    })
+}
+
+/// Fetches one record per id, running up to `concurrency` requests at a time, and returns the
+/// results in the order of `ids`.
+///
+/// A rate-limited request is sent again once the delay is over; any other error is returned in
+/// place of the record, so one missing id does not fail the whole batch.
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let teams = fetch_many([FtTeamId::new(1), FtTeamId::new(2)], 4, |team_id| {
+///         session.teams_id(FtApiTeamsIdRequest::new(team_id))
+///     })
+///     .await;
+///     println!("{} teams", teams.iter().filter(|team| team.is_ok()).count());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn fetch_many<I, T, F, Fut>(
+    ids: impl IntoIterator<Item = I>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<ClientResult<T>>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = ClientResult<T>>,
+{
+    fetch_many_with_hooks(
+        ids,
+        concurrency,
+        &FtClientHooks::default(),
+        &CancellationToken::new(),
+        fetch,
+    )
+    .await
+}
+
+/// `fetch_many`, reporting the retries to `hooks`.
+///
+/// Once `cancellation` is cancelled, no request is sent for the remaining ids and a rate-limited
+/// one returns its error, so the results stop short of `ids`.
+pub(crate) async fn fetch_many_with_hooks<I, T, F, Fut>(
+    ids: impl IntoIterator<Item = I>,
+    concurrency: usize,
+    hooks: &FtClientHooks,
+    cancellation: &CancellationToken,
+    fetch: F,
+) -> Vec<ClientResult<T>>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = ClientResult<T>>,
+{
+    let fetch = &fetch;
+    futures::stream::iter(ids)
+        .take_while(|_| std::future::ready(!cancellation.is_cancelled()))
+        .map(|id| async move {
+            let mut retries = 0;
+            loop {
+                match fetch(id.clone()).await {
+                    Err(FtClientError::RateLimitError(err)) => {
+                        let retry_after = err.retry_after;
+                        let err = FtClientError::RateLimitError(err);
+                        retries += 1;
+                        if hooks.retry(retries, &err).is_break() {
+                            return Err(err);
+                        }
+                        tracing::warn!("rate limit, try again.");
+                        crate::metrics::retry("fetch_many", "rate_limit");
+                        tokio::select! {
+                            _ = sleep(retry_after.unwrap_or(Duration::from_secs(1))) => {}
+                            _ = cancellation.cancelled() => return Err(err),
+                        }
+                    }
+                    res => return res,
+                }
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fetch_many_keeps_order_and_retries() {
+        let rate_limited = AtomicUsize::new(0);
+        let res = fetch_many([3u64, 1, 2, 0], 2, |id| {
+            let rate_limited = &rate_limited;
+            async move {
+                sleep(Duration::from_millis(id * 10)).await;
+                match id {
+                    0 => Err(FtClientError::HttpError(FtHttpError::new(
+                        http::StatusCode::NOT_FOUND,
+                    ))),
+                    1 if rate_limited.fetch_add(1, Ordering::SeqCst) == 0 => {
+                        Err(FtClientError::RateLimitError(
+                            FtRateLimitError::new().with_retry_after(Duration::from_millis(1)),
+                        ))
+                    }
+                    id => Ok(id * 10),
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(rate_limited.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            res.iter()
+                .map(|res| res.as_ref().ok().copied())
+                .collect::<Vec<_>>(),
+            vec![Some(30), Some(10), Some(20), None]
+        );
+    }
+
+    struct Page(Vec<u16>);
+
+    impl HasVec<u16> for Page {
+        fn get_vec(&self) -> &Vec<u16> {
+            &self.0
+        }
+
+        fn take_vec(self) -> Vec<u16> {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn paginate_until_stops_between_pages() {
+        let cancellation = CancellationToken::new();
+        let fetched = AtomicUsize::new(0);
+        let records = paginate_until(2, cancellation.clone(), |page| {
+            fetched.fetch_add(1, Ordering::SeqCst);
+            let cancellation = cancellation.clone();
+            async move {
+                if page == 2 {
+                    cancellation.cancel();
+                }
+                Ok(Page(vec![page, page]))
+            }
+        })
+        .map(Result::unwrap)
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(records, vec![1, 1, 2, 2]);
+        assert_eq!(fetched.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fetch_many_stops_once_cancelled() {
+        let cancellation = CancellationToken::new();
+        let res = fetch_many_with_hooks(
+            [1u64, 2, 3, 4],
+            1,
+            &FtClientHooks::default(),
+            &cancellation,
+            |id| {
+                let cancellation = &cancellation;
+                async move {
+                    if id == 2 {
+                        cancellation.cancel();
+                    }
+                    Ok::<_, FtClientError>(id)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(
+            res.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
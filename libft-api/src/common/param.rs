@@ -13,7 +13,9 @@ pub enum FtRangeField {
     FinalMark,
     Host,
     Id,
+    Level,
     LockedAt,
+    MarkedAt,
     Name,
     Primary,
     ProjectId,
@@ -43,6 +45,7 @@ pub enum FtFilterField {
     CampusId,
     Closed,
     ClosedAt,
+    CoalitionId,
     CreatedAt,
     Cursus,
     CursusId,
@@ -50,6 +53,8 @@ pub enum FtFilterField {
     DeadlineAt,
     End,
     EndAt,
+    FeedbackableId,
+    FeedbackableType,
     FinalMark,
     Future,
     Host,
@@ -57,7 +62,11 @@ pub enum FtFilterField {
     Inactive,
     Locked,
     LockedAt,
+    Marked,
+    MarkedAt,
     Name,
+    PoolMonth,
+    PoolYear,
     Primary,
     PrimaryCampus,
     PrimaryCampusId,
@@ -71,6 +80,8 @@ pub enum FtFilterField {
     TerminatingAt,
     UpdatedAt,
     UserId,
+    WaitlistableId,
+    WaitlistableType,
     WithMark,
 }
 
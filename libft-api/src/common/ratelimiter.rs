@@ -1,12 +1,15 @@
-use reqwest::header::HeaderMap;
+use http::header::HeaderMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::{sleep_until, Instant};
 
+use crate::common::FtClientHooks;
+
 #[derive(Debug, Clone)]
 pub struct HeaderMetaData {
     pub ratelimiter: RateLimiter,
     pub total_page: Arc<Mutex<u64>>,
+    pub hooks: FtClientHooks,
 }
 
 impl HeaderMetaData {
@@ -14,6 +17,7 @@ impl HeaderMetaData {
         Self {
             ratelimiter,
             total_page: Arc::new(Mutex::new(u64::MAX)),
+            hooks: FtClientHooks::default(),
         }
     }
 
@@ -42,6 +46,46 @@ struct Inner {
     sec_reset: Instant,
     hour_reset: Instant,
     retry_after_until: Option<Instant>,
+    server: Option<(Instant, FtServerRateLimit)>,
+}
+
+/// The rate-limit headers of the most recent response carrying any.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FtServerRateLimit {
+    pub secondly_limit: Option<u64>,
+    pub secondly_remaining: Option<u64>,
+    pub hourly_limit: Option<u64>,
+    pub hourly_remaining: Option<u64>,
+    /// The `Retry-After` of the response, when it was rate limited.
+    pub retry_after: Option<Duration>,
+}
+
+/// The rate-limit state of a client, as returned by `FtClient::rate_limit_status`.
+///
+/// The remaining counts are the ones the limiter enforces, kept in sync with the response
+/// headers; `server` holds the last headers themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FtRateLimitStatus {
+    pub secondly_limit: u64,
+    pub hourly_limit: u64,
+    pub secondly_remaining: u64,
+    pub hourly_remaining: u64,
+    /// Time until the per-second window resets.
+    pub secondly_reset_in: Duration,
+    /// Time until the hourly window resets.
+    pub hourly_reset_in: Duration,
+    /// Time left before requests are sent again, after a `Retry-After`.
+    pub retry_after: Option<Duration>,
+    /// The last server headers, with the time elapsed since they were received.
+    pub server: Option<(Duration, FtServerRateLimit)>,
+}
+
+impl FtRateLimitStatus {
+    /// Whether `requests` more requests fit in the hourly quota, with no `Retry-After` pending,
+    /// e.g. before starting a new batch.
+    pub fn can_send(&self, requests: u64) -> bool {
+        self.retry_after.is_none() && self.hourly_remaining >= requests
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -60,12 +104,47 @@ impl RateLimiter {
             sec_reset: now + Duration::from_secs(1),
             hour_reset: now + Duration::from_secs(3600),
             retry_after_until: None,
+            server: None,
         };
         Self {
             inner: Arc::new(Mutex::new(inner)),
         }
     }
 
+    /// 현재 상태 조회: 토큰은 소비하지 않음
+    pub fn status(&self) -> FtRateLimitStatus {
+        let st = self.inner.lock().unwrap();
+        let now = Instant::now();
+
+        // 리셋 시각이 지난 윈도는 acquire가 보게 될 대로 가득 찬 것으로 봄
+        let (secondly_remaining, secondly_reset_in) = if now >= st.sec_reset {
+            (st.sec_limit, Duration::ZERO)
+        } else {
+            (st.sec_remaining, st.sec_reset - now)
+        };
+        let (hourly_remaining, hourly_reset_in) = if now >= st.hour_reset {
+            (st.hour_limit, Duration::ZERO)
+        } else {
+            (st.hour_remaining, st.hour_reset - now)
+        };
+
+        FtRateLimitStatus {
+            secondly_limit: st.sec_limit,
+            hourly_limit: st.hour_limit,
+            secondly_remaining,
+            hourly_remaining,
+            secondly_reset_in,
+            hourly_reset_in,
+            retry_after: st
+                .retry_after_until
+                .filter(|deadline| now < *deadline)
+                .map(|deadline| deadline - now),
+            server: st
+                .server
+                .map(|(received_at, server)| (now - received_at, server)),
+        }
+    }
+
     /// 헤더 기반 갱신: 한 번만 락 잡고 끝냄
     pub fn update_from_headers(&self, headers: &HeaderMap) {
         let parse_u64 = |name: &str| -> Option<u64> {
@@ -76,7 +155,18 @@ impl RateLimiter {
                 .ok()
         };
 
+        let server = FtServerRateLimit {
+            secondly_limit: parse_u64("x-secondly-ratelimit-limit"),
+            secondly_remaining: parse_u64("x-secondly-ratelimit-remaining"),
+            hourly_limit: parse_u64("x-hourly-ratelimit-limit"),
+            hourly_remaining: parse_u64("x-hourly-ratelimit-remaining"),
+            retry_after: parse_u64("retry-after").map(Duration::from_secs),
+        };
+
         let mut st = self.inner.lock().unwrap();
+        if server != FtServerRateLimit::default() {
+            st.server = Some((Instant::now(), server));
+        }
 
         if let Some(rem) = parse_u64("x-secondly-ratelimit-remaining") {
             // 서버가 알려준 값으로 덮어써서 동기화
@@ -90,8 +180,10 @@ impl RateLimiter {
         }
     }
 
-    /// 요청 전 호출: 락은 매우 짧게만 잡고, 대기는 락 밖에서 수행
-    pub async fn acquire(&self) {
+    /// 요청 전 호출: 락은 매우 짧게만 잡고, 대기는 락 밖에서 수행. 기다린 시간을 반환
+    pub async fn acquire(&self) -> Duration {
+        let started = Instant::now();
+        let mut slept = false;
         loop {
             // 락을 짧게 잡아서 '무엇을 할지'만 결정하고 곧바로 풀기
             let decision = {
@@ -135,8 +227,19 @@ impl RateLimiter {
             };
 
             match decision {
-                Control::Permit => return, // 바로 진행
-                Control::Sleep(deadline) => sleep_until(deadline).await,
+                Control::Permit => {
+                    let waited = if slept {
+                        started.elapsed()
+                    } else {
+                        Duration::ZERO
+                    };
+                    crate::metrics::ratelimit_wait(waited);
+                    return waited; // 바로 진행
+                }
+                Control::Sleep(deadline) => {
+                    sleep_until(deadline).await;
+                    slept = true;
+                }
                 Control::Recheck => {} // 즉시 루프 재검사
             }
         }
@@ -152,7 +255,7 @@ enum Control {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use reqwest::header::{HeaderMap, HeaderValue};
+    use http::header::{HeaderMap, HeaderValue};
     use tokio::time as ttime;
     use ttime::{Duration, Instant};
 
@@ -174,6 +277,7 @@ mod tests {
                 sec_reset: now + Duration::from_secs_f64(sec_window.as_secs_f64()),
                 hour_reset: now + Duration::from_secs_f64(hour_window.as_secs_f64()),
                 retry_after_until: None,
+                server: None,
             };
             Self {
                 inner: std::sync::Arc::new(std::sync::Mutex::new(inner)),
@@ -382,6 +486,48 @@ mod tests {
         j.await.unwrap();
     }
 
+    /// status는 리미터 상태와 마지막 응답 헤더를 함께 보여줌
+    #[tokio::test(start_paused = true)]
+    async fn test_status_combines_limiter_and_headers() {
+        let limiter =
+            RateLimiter::with_windows(8, 1200, Duration::from_secs(1), Duration::from_secs(3600));
+        let status = limiter.status();
+        assert_eq!(status.secondly_remaining, 8);
+        assert_eq!(status.server, None);
+        assert!(status.can_send(1200));
+
+        limiter.acquire().await;
+        let mut headers = HeaderMap::new();
+        headers.insert("x-hourly-ratelimit-limit", HeaderValue::from_static("1200"));
+        headers.insert(
+            "x-hourly-ratelimit-remaining",
+            HeaderValue::from_static("100"),
+        );
+        headers.insert("retry-after", HeaderValue::from_static("2"));
+        limiter.update_from_headers(&headers);
+        ttime::advance(Duration::from_millis(500)).await;
+
+        let status = limiter.status();
+        assert_eq!(status.secondly_remaining, 7);
+        assert_eq!(status.secondly_reset_in, Duration::from_millis(500));
+        assert_eq!(status.hourly_remaining, 100);
+        assert_eq!(status.retry_after, Some(Duration::from_millis(1500)));
+        assert!(!status.can_send(1));
+        let (age, server) = status.server.unwrap();
+        assert_eq!(age, Duration::from_millis(500));
+        assert_eq!(server.hourly_remaining, Some(100));
+        assert_eq!(server.secondly_remaining, None);
+        assert_eq!(server.retry_after, Some(Duration::from_secs(2)));
+
+        // 윈도와 retry-after가 지나면 다시 가득 참
+        ttime::advance(Duration::from_secs(2)).await;
+        let status = limiter.status();
+        assert_eq!(status.secondly_remaining, 8);
+        assert_eq!(status.secondly_reset_in, Duration::ZERO);
+        assert_eq!(status.retry_after, None);
+        assert!(status.can_send(100));
+    }
+
     /// HeaderMetaData가 x-total을 반영하는지(부가 메타 확인)
     #[test]
     fn test_header_metadata_updates_total_page() {
@@ -0,0 +1,210 @@
+use bytes::Bytes;
+use serde::de::{Deserialize, DeserializeOwned};
+use serde_json::value::RawValue;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::{map_serde_error, ClientResult};
+
+/// A page of a list endpoint kept as the JSON of its response, each record deserialized only when
+/// it is read.
+///
+/// Returned by the `_raw` variants of the list calls, such as `users_raw`. Reading the records
+/// with `iter_as` into a struct of the few fields a pipeline needs, borrowing its strings from the
+/// page, skips building the full model of each record; `iter` still yields the models, one at a
+/// time.
+///
+/// A `&str` field only borrows strings without escape sequences; a `Cow<str>` marked
+/// `#[serde(borrow)]` takes both.
+///
+/// # Example
+///
+/// ```rust
+/// use libft_api::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Login<'a> {
+///     id: i32,
+///     login: &'a str,
+/// }
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let page = session
+///         .users_raw(FtApiUsersRequest::new().with_per_page(100))
+///         .await?;
+///     for user in page.iter_as::<Login>() {
+///         let user = user?;
+///         println!("{} {}", user.id, user.login);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct FtRawPage<T> {
+    body: Bytes,
+    records: Vec<Range<usize>>,
+    record: PhantomData<fn() -> T>,
+}
+
+impl<T> FtRawPage<T> {
+    /// Splits `body`, a JSON array, into its records, without deserializing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProtocolError` when `body` is not a JSON array.
+    pub fn new(body: impl Into<Bytes>) -> ClientResult<Self> {
+        let body = body.into();
+        let start = body.as_ptr() as usize;
+        let records = serde_json::from_slice::<Vec<&RawValue>>(&body)
+            .map_err(|err| map_serde_error(err, Some(&String::from_utf8_lossy(&body))))?
+            .into_iter()
+            .map(|record| {
+                let offset = record.get().as_ptr() as usize - start;
+                offset..offset + record.get().len()
+            })
+            .collect();
+        Ok(Self {
+            body,
+            records,
+            record: PhantomData,
+        })
+    }
+
+    /// The number of records of the page.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The JSON of the response.
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.body
+    }
+
+    /// The JSON of each record, e.g. to write them to a file unchanged.
+    pub fn raw_records(&self) -> impl Iterator<Item = &[u8]> {
+        self.records.iter().map(|range| &self.body[range.clone()])
+    }
+
+    /// Deserializes the records one at a time as `R`, which may borrow from the page.
+    pub fn iter_as<'a, R>(&'a self) -> impl Iterator<Item = ClientResult<R>> + 'a
+    where
+        R: Deserialize<'a>,
+    {
+        self.raw_records().map(|record| {
+            serde_json::from_slice(record)
+                .map_err(|err| map_serde_error(err, Some(&String::from_utf8_lossy(record))))
+        })
+    }
+}
+
+impl<T> FtRawPage<T>
+where
+    T: DeserializeOwned,
+{
+    /// Deserializes the records one at a time as the model of the endpoint.
+    pub fn iter(&self) -> impl Iterator<Item = ClientResult<T>> + '_ {
+        self.iter_as()
+    }
+}
+
+impl<T> std::fmt::Debug for FtRawPage<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FtRawPage")
+            .field("bytes", &self.body.len())
+            .field("records", &self.records.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::common::FtClientError;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Login<'a> {
+        id: u64,
+        #[serde(borrow)]
+        login: Cow<'a, str>,
+    }
+
+    #[test]
+    fn reads_records_lazily() {
+        let page = FtRawPage::<serde_json::Value>::new(
+            r#"[{"id": 1, "login": "mockstu", "extra": [1, 2]}, {"id": 2, "login": "esc\"aped"}, {"id": "3"}]"#,
+        )
+        .unwrap();
+        assert_eq!(page.len(), 3);
+        assert_eq!(
+            page.raw_records().next(),
+            Some(&br#"{"id": 1, "login": "mockstu", "extra": [1, 2]}"#[..])
+        );
+
+        let mut logins = page.iter_as::<Login>();
+        let first = logins.next().unwrap().unwrap();
+        assert!(matches!(first.login, Cow::Borrowed("mockstu")));
+        assert_eq!(logins.next().unwrap().unwrap().login, "esc\"aped");
+        assert!(matches!(
+            logins.next(),
+            Some(Err(FtClientError::ProtocolError(_)))
+        ));
+
+        assert_eq!(page.iter().count(), 3);
+    }
+
+    #[test]
+    fn rejects_bodies_other_than_arrays() {
+        let res = FtRawPage::<serde_json::Value>::new(r#"{"error": "Not Found"}"#);
+        assert!(matches!(res, Err(FtClientError::ProtocolError(_))));
+    }
+
+    #[cfg(all(feature = "users", feature = "reqwest"))]
+    #[tokio::test]
+    async fn users_raw_reads_the_mock() {
+        use rvstruct::ValueStruct;
+
+        use crate::prelude::*;
+        use crate::test_support::*;
+
+        let mock = FtMockServer::start().await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let page = session.users_raw(FtApiUsersRequest::new()).await.unwrap();
+        let users = session.users(FtApiUsersRequest::new()).await.unwrap().users;
+        assert_eq!(page.len(), users.len());
+        let ids = page
+            .iter_as::<Login>()
+            .map(|login| login.unwrap().id)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            users
+                .iter()
+                .filter_map(|user| user.id.as_ref().map(|id| *id.value() as u64))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(page.iter().next().unwrap().unwrap(), users[0]);
+
+        mock.mock_rate_limited("users", 1, 1).await;
+        let res = session.users_raw(FtApiUsersRequest::new()).await;
+        assert!(matches!(res, Err(FtClientError::RateLimitError(_))));
+    }
+}
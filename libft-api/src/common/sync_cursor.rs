@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use super::{write_atomic, FtRangeField, FtRangeOption};
+
+/// When each collection was last synced, to fetch only what changed since the previous run.
+///
+/// The cursor is a small JSON file, so jobs without a database (see the `mirror` feature for a
+/// SQLite store) can be made incremental. A run starts an `FtSyncPass`, fetches every page of its
+/// `range`, and only then `complete`s it: the watermark moves to the time the pass started, as
+/// pages are not ordered by `updated_at` and the records updated during the pass may be missed.
+/// A job interrupted midway leaves the watermark alone and fetches the same range again.
+///
+/// Ranges are inclusive, so the records updated exactly at the watermark come back on the next
+/// run; upserts by id absorb them.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::TryStreamExt;
+/// use libft_api::prelude::*;
+///
+/// async fn nightly() -> Result<(), Box<dyn std::error::Error>> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let mut cursor = FtSyncCursor::load("sync_cursor.json")?;
+///     let pass = cursor.start("users");
+///
+///     let users = paginate(100, |page| {
+///         let mut req = FtApiUsersRequest::new()
+///             .with_page(usize::from(page))
+///             .with_per_page(100);
+///         req.range = pass.range();
+///         session.users(req)
+///     })
+///     .try_collect::<Vec<_>>()
+///     .await?;
+///     println!("{} users changed", users.len());
+///
+///     cursor.complete(pass);
+///     cursor.save("sync_cursor.json")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct FtSyncCursor {
+    watermarks: BTreeMap<String, DateTime<Utc>>,
+}
+
+/// A run over the records of a collection changed since its watermark, from
+/// `FtSyncCursor::start`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtSyncPass {
+    collection: String,
+    since: Option<DateTime<Utc>>,
+    started_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub enum FtSyncCursorError {
+    Io(std::io::Error),
+    Serde(serde_json::Error),
+}
+
+impl Display for FtSyncCursorError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            FtSyncCursorError::Io(err) => write!(f, "sync cursor I/O error: {err}"),
+            FtSyncCursorError::Serde(err) => write!(f, "sync cursor serialization error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FtSyncCursorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FtSyncCursorError::Io(err) => Some(err),
+            FtSyncCursorError::Serde(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for FtSyncCursorError {
+    fn from(err: std::io::Error) -> Self {
+        FtSyncCursorError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for FtSyncCursorError {
+    fn from(err: serde_json::Error) -> Self {
+        FtSyncCursorError::Serde(err)
+    }
+}
+
+impl FtSyncCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the cursor saved at `path`, or an empty cursor if the file does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a saved cursor.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FtSyncCursorError> {
+        match std::fs::read(path) {
+            Ok(raw) => Ok(serde_json::from_slice(&raw)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Writes the cursor to `path`, replacing the previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FtSyncCursorError> {
+        write_atomic(path.as_ref(), &serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// When the last completed pass over `collection` started.
+    pub fn watermark(&self, collection: &str) -> Option<DateTime<Utc>> {
+        self.watermarks.get(collection).copied()
+    }
+
+    /// Starts a pass over the records of `collection` changed since the watermark, all of them on
+    /// the first run.
+    pub fn start(&self, collection: &str) -> FtSyncPass {
+        FtSyncPass {
+            collection: collection.to_string(),
+            since: self.watermark(collection),
+            started_at: Utc::now(),
+        }
+    }
+
+    /// Moves the watermark of the collection of `pass` to the time it started, once every page of
+    /// its range was handled. Passes started before the watermark leave it alone.
+    pub fn complete(&mut self, pass: FtSyncPass) {
+        self.watermarks
+            .entry(pass.collection)
+            .and_modify(|watermark| *watermark = (*watermark).max(pass.started_at))
+            .or_insert(pass.started_at);
+    }
+
+    /// Forgets the watermark of `collection`, so the next run fetches everything again.
+    pub fn reset(&mut self, collection: &str) {
+        self.watermarks.remove(collection);
+    }
+}
+
+impl FtSyncPass {
+    pub fn collection(&self) -> &str {
+        &self.collection
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    /// The `range[updated_at]` of the pass, from the watermark to the start of the pass; `None`
+    /// on the first run.
+    pub fn range(&self) -> Option<Vec<FtRangeOption>> {
+        self.since.map(|since| {
+            vec![FtRangeOption::new(
+                FtRangeField::UpdatedAt,
+                vec![since.to_rfc3339(), self.started_at.to_rfc3339()],
+            )]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn completed_passes_move_the_watermark() {
+        let mut cursor = FtSyncCursor::new();
+        let first = cursor.start("users");
+        assert!(first.range().is_none());
+
+        // An interrupted pass leaves the watermark alone.
+        let _interrupted = cursor.start("users");
+        assert_eq!(cursor.watermark("users"), None);
+
+        let started_at = first.started_at();
+        cursor.complete(first);
+        assert_eq!(cursor.watermark("users"), Some(started_at));
+        assert_eq!(cursor.watermark("teams"), None);
+
+        let second = cursor.start("users");
+        let range = second.range().unwrap();
+        assert_eq!(range[0].range, FtRangeField::UpdatedAt);
+        assert_eq!(
+            range[0].value,
+            vec![started_at.to_rfc3339(), second.started_at().to_rfc3339()]
+        );
+
+        // A pass started earlier does not move the watermark back.
+        let stale = FtSyncPass {
+            started_at: started_at - chrono::TimeDelta::days(1),
+            ..second
+        };
+        cursor.complete(stale);
+        assert_eq!(cursor.watermark("users"), Some(started_at));
+
+        cursor.reset("users");
+        assert_eq!(cursor.watermark("users"), None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("ft_sync_cursor_{}.json", std::process::id()));
+        assert_eq!(FtSyncCursor::load(&path).unwrap(), FtSyncCursor::new());
+
+        let mut cursor = FtSyncCursor::new();
+        cursor.complete(FtSyncPass {
+            collection: "scale_teams".to_string(),
+            since: None,
+            started_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+        });
+        cursor.save(&path).unwrap();
+
+        assert_eq!(FtSyncCursor::load(&path).unwrap(), cursor);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,434 @@
+//! A connector recording API responses to files, and replaying them without network.
+//!
+//! In record mode, `FtClientReplayConnector` sends every request through another connector and
+//! writes the JSON response to a cassette: one file per method, path and query string in its
+//! directory. In replay mode it answers from the cassettes alone, so tests need neither network
+//! nor credentials; a request without cassette fails with a `SystemError` naming the file it
+//! looked for.
+//!
+//! The contract tests of `tests/contracts.rs` replay a corpus recorded this way against every
+//! endpoint module.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use libft_api::connector::FtClientReplayConnector;
+//! use libft_api::prelude::*;
+//!
+//! # async fn run() -> ClientResult<()> {
+//! // Once, with credentials: record the responses.
+//! let token = FtApiToken::try_get(AuthInfo::build_from_env()?).await?;
+//! let client = FtClient::new(FtClientReplayConnector::record(
+//!     FtClientReqwestConnector::new(),
+//!     "tests/cassettes",
+//! ));
+//! client.open_session(token.clone()).levels(FtApiLevelsRequest::new()).await?;
+//!
+//! // Then, offline: the same call is answered from `tests/cassettes`.
+//! let client = FtClient::new(FtClientReplayConnector::replay("tests/cassettes"));
+//! client.open_session(token).levels(FtApiLevelsRequest::new()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use futures::{future::BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::auth::FtApiToken;
+use crate::common::*;
+#[cfg(feature = "reqwest")]
+use crate::connector::FtClientReqwestConnector;
+
+/// A recorded response, as stored in a cassette file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FtCassette {
+    pub method: String,
+    /// The path of the request, without leading slash, e.g. `v2/users/190001`.
+    pub path: String,
+    pub query: Option<String>,
+    pub body: Value,
+}
+
+impl FtCassette {
+    pub fn new(method: &str, url: &Url, body: Value) -> Self {
+        Self {
+            method: method.to_string(),
+            path: url.path().trim_start_matches('/').to_string(),
+            query: query(url).map(str::to_string),
+            body,
+        }
+    }
+
+    /// The file name of the cassette of `method` on `url`.
+    ///
+    /// The path and query are kept readable, with the characters some file systems reject
+    /// replaced by `_`; the cassette holds the exact request to tell collisions apart.
+    pub fn file_name(method: &str, url: &Url) -> String {
+        let mut name = format!("{method}_{}", url.path().trim_start_matches('/'));
+        if let Some(query) = query(url) {
+            name.push('_');
+            name.push_str(query);
+        }
+        let name: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '=') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{name}.json")
+    }
+
+    fn matches(&self, method: &str, url: &Url) -> bool {
+        *self == Self::new(method, url, self.body.clone())
+    }
+}
+
+/// The query string of `url`, `None` when empty.
+fn query(url: &Url) -> Option<&str> {
+    url.query().filter(|query| !query.is_empty())
+}
+
+/// The connector type of a replaying `FtClientReplayConnector`, which never sends anything.
+#[cfg(feature = "reqwest")]
+type FtReplayOnly = FtClientReqwestConnector;
+/// Without the `reqwest` feature, a connector that cannot be built.
+#[cfg(not(feature = "reqwest"))]
+type FtReplayOnly = std::convert::Infallible;
+
+/// A connector recording the responses of another connector, or replaying recorded ones.
+pub struct FtClientReplayConnector<FCHC = FtReplayOnly> {
+    dir: PathBuf,
+    recorder: Option<FCHC>,
+}
+
+impl FtClientReplayConnector {
+    /// Answers every request from the cassettes of `dir`, without sending anything.
+    #[must_use]
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: None,
+        }
+    }
+}
+
+impl<FCHC> FtClientReplayConnector<FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    /// Sends every request through `connector`, and writes each response to a cassette of `dir`,
+    /// replacing the previous one.
+    #[must_use]
+    pub fn record(connector: FCHC, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: Some(connector),
+        }
+    }
+
+    /// The directory of the cassettes.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    fn replayed<RS>(&self, method: &str, url: &Url) -> ClientResult<RS>
+    where
+        RS: for<'de> serde::de::Deserialize<'de>,
+    {
+        let path = self.dir.join(FtCassette::file_name(method, url));
+        let cassette = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<FtCassette>(&raw).ok())
+            .filter(|cassette| cassette.matches(method, url))
+            .ok_or_else(|| {
+                FtClientError::SystemError(FtSystemError::new().with_message(format!(
+                    "no recorded response for {method} {} in {}",
+                    redacted_url(url),
+                    path.display()
+                )))
+            })?;
+        Self::decode(cassette.body)
+    }
+
+    fn recorded<RS>(&self, method: &str, url: &Url, body: Value) -> ClientResult<RS>
+    where
+        RS: for<'de> serde::de::Deserialize<'de>,
+    {
+        let path = self.dir.join(FtCassette::file_name(method, url));
+        let cassette = FtCassette::new(method, url, body);
+        let written = std::fs::create_dir_all(&self.dir).and_then(|()| {
+            let raw = serde_json::to_string_pretty(&cassette).map_err(std::io::Error::from)?;
+            std::fs::write(&path, raw + "\n")
+        });
+        written.map_err(|err| {
+            FtClientError::SystemError(
+                FtSystemError::new()
+                    .with_message(format!("cannot write {}", path.display()))
+                    .with_cause(Box::new(err)),
+            )
+        })?;
+        Self::decode(cassette.body)
+    }
+
+    fn decode<RS>(body: Value) -> ClientResult<RS>
+    where
+        RS: for<'de> serde::de::Deserialize<'de>,
+    {
+        RS::deserialize(&body).map_err(|err| map_serde_error(err, Some(&body.to_string())))
+    }
+}
+
+impl<FCHC> FtClientHttpConnector for FtClientReplayConnector<FCHC>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    fn create_method_uri_path(&self, method_relative_uri: &str) -> ClientResult<Url> {
+        match &self.recorder {
+            Some(connector) => connector.create_method_uri_path(method_relative_uri),
+            None => Ok(FtClientHttpApiUri::create_method_uri_path(method_relative_uri).parse()?),
+        }
+    }
+
+    fn http_get_uri<'a, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            match &self.recorder {
+                Some(connector) => {
+                    let body = connector
+                        .http_get_uri(full_uri.clone(), token, ratelimiter)
+                        .await?;
+                    self.recorded("GET", &full_uri, body)
+                }
+                None => self.replayed("GET", &full_uri),
+            }
+        }
+        .boxed()
+    }
+
+    /// Downloads are sent through the recorded connector, never recorded nor replayed.
+    fn http_get_bytes_uri<'a>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+    ) -> BoxFuture<'a, ClientResult<Vec<u8>>> {
+        match &self.recorder {
+            Some(connector) => connector.http_get_bytes_uri(full_uri, token, ratelimiter),
+            None => std::future::ready(Err(FtClientError::SystemError(
+                FtSystemError::new().with_message(format!(
+                    "downloads are not replayed: {}",
+                    redacted_url(&full_uri)
+                )),
+            )))
+            .boxed(),
+        }
+    }
+
+    fn http_post_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            match &self.recorder {
+                Some(connector) => {
+                    let body = connector
+                        .http_post_uri(full_uri.clone(), token, ratelimiter, request_body)
+                        .await?;
+                    self.recorded("POST", &full_uri, body)
+                }
+                None => self.replayed("POST", &full_uri),
+            }
+        }
+        .boxed()
+    }
+
+    fn http_patch_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            match &self.recorder {
+                Some(connector) => {
+                    let body = connector
+                        .http_patch_uri(full_uri.clone(), token, ratelimiter, request_body)
+                        .await?;
+                    self.recorded("PATCH", &full_uri, body)
+                }
+                None => self.replayed("PATCH", &full_uri),
+            }
+        }
+        .boxed()
+    }
+
+    fn http_delete_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            match &self.recorder {
+                Some(connector) => {
+                    let body = connector
+                        .http_delete_uri(full_uri.clone(), token, ratelimiter, request_body)
+                        .await?;
+                    self.recorded("DELETE", &full_uri, body)
+                }
+                None => self.replayed("DELETE", &full_uri),
+            }
+        }
+        .boxed()
+    }
+}
+
+/// Lets `FtClientReplayConnector::replay` build without the `reqwest` feature.
+#[cfg(not(feature = "reqwest"))]
+impl FtClientHttpConnector for std::convert::Infallible {
+    fn create_method_uri_path(&self, _: &str) -> ClientResult<Url> {
+        match *self {}
+    }
+
+    fn http_get_uri<'a, RS>(
+        &'a self,
+        _: Url,
+        _: &'a FtApiToken,
+        _: &'a HeaderMetaData,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        match *self {}
+    }
+
+    fn http_get_bytes_uri<'a>(
+        &'a self,
+        _: Url,
+        _: &'a FtApiToken,
+        _: &'a HeaderMetaData,
+    ) -> BoxFuture<'a, ClientResult<Vec<u8>>> {
+        match *self {}
+    }
+
+    fn http_post_uri<'a, RQ, RS>(
+        &'a self,
+        _: Url,
+        _: &'a FtApiToken,
+        _: &'a HeaderMetaData,
+        _: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        match *self {}
+    }
+
+    fn http_patch_uri<'a, RQ, RS>(
+        &'a self,
+        _: Url,
+        _: &'a FtApiToken,
+        _: &'a HeaderMetaData,
+        _: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        match *self {}
+    }
+
+    fn http_delete_uri<'a, RQ, RS>(
+        &'a self,
+        _: Url,
+        _: &'a FtApiToken,
+        _: &'a HeaderMetaData,
+        _: &'a RQ,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        match *self {}
+    }
+}
+
+#[cfg(all(test, feature = "users", feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::test_support::*;
+
+    #[tokio::test]
+    async fn records_then_replays() {
+        let dir = std::env::temp_dir().join(format!("ft-cassettes-{}", std::process::id()));
+        let mock = FtMockServer::start().await;
+        let request = || {
+            FtApiUsersIdRequest::new(FtUserIdentifier::Login(FtLoginId::new(
+                "mockstu".to_string(),
+            )))
+        };
+
+        let client = FtClient::new(FtClientReplayConnector::record(
+            FtClientReqwestConnector::new().with_ft_api_url(&mock.api_url()),
+            &dir,
+        ));
+        let recorded = client
+            .open_session(FtMockServer::token())
+            .users_id(request())
+            .await
+            .unwrap();
+        drop(mock);
+
+        let client = FtClient::new(FtClientReplayConnector::replay(&dir));
+        let session = client.open_session(FtMockServer::token());
+        let replayed = session.users_id(request()).await.unwrap();
+        assert_eq!(replayed.user, recorded.user);
+
+        let missing = session
+            .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::UserId(
+                FtUserId::new(1),
+            )))
+            .await;
+        assert!(matches!(missing, Err(FtClientError::SystemError(_))));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
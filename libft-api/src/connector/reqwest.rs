@@ -0,0 +1,343 @@
+//! The connector sending requests with `reqwest`, behind the default `reqwest` feature.
+
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+use reqwest::{
+    header::{self, AUTHORIZATION},
+    Client, RequestBuilder, StatusCode,
+};
+use tracing::{debug, info};
+use url::Url;
+
+use crate::auth::FtApiToken;
+use crate::common::*;
+
+/// A client for the 42 API that uses `reqwest` as the underlying HTTP client.
+pub struct FtClientReqwestConnector {
+    reqwest_connector: Client,
+    ft_api_url: String,
+}
+
+impl Default for FtClientReqwestConnector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FtClientReqwestConnector {
+    /// Create a new `FtClientReqwestConnector` with a default `reqwest` client.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_connector(reqwest::Client::new())
+    }
+
+    /// Create a new `FtClientReqwestConnector` with the given `reqwest` client.
+    #[must_use]
+    pub fn with_connector(connector: Client) -> Self {
+        Self {
+            ft_api_url: FtClientHttpApiUri::FT_API_URI_STR.to_string(),
+            reqwest_connector: connector,
+        }
+    }
+
+    /// Set the 42 API URL for the client.
+    #[must_use]
+    pub fn with_ft_api_url(self, ft_api_url: &str) -> Self {
+        Self {
+            ft_api_url: ft_api_url.to_string(),
+            ..self
+        }
+    }
+
+    // TODO: chagne to hyper, remove url
+    async fn send_http_request<'a, RS>(
+        &'a self,
+        method: &'static str,
+        reqwest: RequestBuilder,
+        url: Url,
+        meta: &'a HeaderMetaData,
+    ) -> ClientResult<RS>
+    where
+        RS: for<'de> serde::de::Deserialize<'de>,
+    {
+        acquire(meta, &url).await?;
+        let url_str = redacted_url(&url).to_string();
+        info!(ft_url = url_str, "Sending HTTP request to");
+        let started = Instant::now();
+        let http_res = reqwest.send().await.map_err(|error| {
+            crate::metrics::request(method, &url, None, started.elapsed(), 0);
+            FtReqwestError::new(error)
+        })?;
+        let http_status = http_res.status();
+        let http_headers = http_res.headers();
+        meta.update_from_headers(http_headers);
+        debug!("headers: {:#?}", http_headers);
+        let http_content_type = http_headers.get(header::CONTENT_TYPE);
+        let http_retry_after = retry_after(http_headers);
+        let http_content_is_json = matches!(
+            http_content_type.map(|content_type| content_type.to_str()),
+            Some(Ok("application/json; charset=utf-8"))
+        );
+        let http_body = http_res.bytes().await.map_err(FtReqwestError::new)?;
+        crate::metrics::request(
+            method,
+            &url,
+            Some(http_status.as_u16()),
+            started.elapsed(),
+            http_body.len(),
+        );
+
+        info!(ft_url = url_str, "Received HTTP response {}", http_status);
+
+        let http_body_str = || String::from_utf8_lossy(&http_body).into_owned();
+        match http_status {
+            StatusCode::OK | StatusCode::CREATED if http_content_is_json => decode_body(&http_body),
+            StatusCode::OK | StatusCode::NO_CONTENT => {
+                serde_json::from_str("{}").map_err(|err| map_serde_error(err, Some("{}")))
+            }
+            StatusCode::TOO_MANY_REQUESTS if http_content_is_json => {
+                let ft_message: FtEnvelopeMessage = decode_body(&http_body)?;
+
+                Err(FtClientError::RateLimitError(
+                    FtRateLimitError::new()
+                        .opt_retry_after(http_retry_after)
+                        .opt_code(ft_message.error)
+                        .opt_warnings(ft_message.warnings)
+                        .with_http_response_body(http_body_str()),
+                ))
+            }
+            StatusCode::TOO_MANY_REQUESTS => Err(FtClientError::RateLimitError(
+                FtRateLimitError::new()
+                    .opt_retry_after(http_retry_after)
+                    .with_http_response_body(http_body_str()),
+            )),
+            _ => Err(FtClientError::HttpError(
+                FtHttpError::new(http_status).with_http_response_body(http_body_str()),
+            )),
+        }
+    }
+}
+
+/// Deserializes a JSON response body, with `simd-json` when the feature is enabled.
+///
+/// A body `simd-json` rejects is parsed again with `serde_json`, so the error, if any, is the same
+/// with or without the feature.
+fn decode_body<RS>(body: &[u8]) -> ClientResult<RS>
+where
+    RS: for<'de> serde::de::Deserialize<'de>,
+{
+    #[cfg(feature = "simd-json")]
+    if let Ok(decoded) = simd_json::serde::from_slice(&mut body.to_vec()) {
+        return Ok(decoded);
+    }
+    serde_json::from_slice(body)
+        .map_err(|err| map_serde_error(err, Some(&String::from_utf8_lossy(body))))
+}
+
+/// The delay of the `Retry-After` header, in seconds.
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(header::RETRY_AFTER)
+        .and_then(|ra| ra.to_str().ok().and_then(|s| s.parse().ok()))
+        .map(Duration::from_secs)
+}
+
+/// Waits for the rate limiter, and reports the wait to the `on_rate_limited` hook.
+async fn acquire(meta: &HeaderMetaData, url: &Url) -> ClientResult<()> {
+    let waited = meta.ratelimiter.acquire().await;
+    if waited.is_zero() {
+        return Ok(());
+    }
+    let endpoint = crate::metrics::endpoint(url);
+    match meta.hooks.rate_limited(waited, &endpoint) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(FtClientError::SystemError(
+            FtSystemError::new().with_message(format!(
+                "request to {endpoint} aborted by the on_rate_limited hook after waiting {waited:?}"
+            )),
+        )),
+    }
+}
+
+impl FtClientHttpConnector for FtClientReqwestConnector {
+    fn create_method_uri_path(&self, method_relative_uri: &str) -> ClientResult<Url> {
+        Ok(format!("{}/{}", self.ft_api_url, method_relative_uri).parse()?)
+    }
+
+    fn http_get_uri<'a, RS>(
+        &'a self,
+        full_uri: url::Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+    ) -> futures::prelude::future::BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            let request = self
+                .reqwest_connector
+                //TODO: remove clone after migrate to hyper
+                .get(full_uri.clone())
+                .header(AUTHORIZATION, token.get_token_value());
+
+            self.send_http_request("GET", request, full_uri, ratelimiter)
+                .await
+        }
+        .boxed()
+    }
+
+    fn http_get_bytes_uri<'a>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+    ) -> futures::prelude::future::BoxFuture<'a, ClientResult<Vec<u8>>> {
+        async move {
+            let is_api_uri = Url::parse(&self.ft_api_url)
+                .is_ok_and(|api_url| api_url.origin() == full_uri.origin());
+            let mut request = self.reqwest_connector.get(full_uri.clone());
+            if is_api_uri {
+                acquire(ratelimiter, &full_uri).await?;
+                request = request.header(AUTHORIZATION, token.get_token_value());
+            }
+
+            info!(ft_url = redacted_url(&full_uri).to_string(), "Downloading");
+            let started = Instant::now();
+            let http_res = request.send().await.map_err(|error| {
+                crate::metrics::request("GET", &full_uri, None, started.elapsed(), 0);
+                FtReqwestError::new(error)
+            })?;
+            let http_status = http_res.status();
+            if is_api_uri {
+                ratelimiter.update_from_headers(http_res.headers());
+            }
+
+            if !http_status.is_success() {
+                let http_retry_after = retry_after(http_res.headers());
+                let http_body_str = http_res.text().await.map_err(FtReqwestError::new)?;
+                crate::metrics::request(
+                    "GET",
+                    &full_uri,
+                    Some(http_status.as_u16()),
+                    started.elapsed(),
+                    http_body_str.len(),
+                );
+                if is_api_uri && http_status == StatusCode::TOO_MANY_REQUESTS {
+                    return Err(FtClientError::RateLimitError(
+                        FtRateLimitError::new()
+                            .opt_retry_after(http_retry_after)
+                            .with_http_response_body(http_body_str),
+                    ));
+                }
+                return Err(FtClientError::HttpError(
+                    FtHttpError::new(http_status).with_http_response_body(http_body_str),
+                ));
+            }
+
+            let bytes = http_res.bytes().await.map_err(FtReqwestError::new)?;
+            crate::metrics::request(
+                "GET",
+                &full_uri,
+                Some(http_status.as_u16()),
+                started.elapsed(),
+                bytes.len(),
+            );
+            Ok(bytes.to_vec())
+        }
+        .boxed()
+    }
+
+    fn http_post_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: url::Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> futures::prelude::future::BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            let request = self
+                .reqwest_connector
+                //TODO: remove clone after migrate to hyper
+                .post(full_uri.clone())
+                .header(AUTHORIZATION, token.get_token_value())
+                .json(&request_body);
+
+            self.send_http_request("POST", request, full_uri, ratelimiter)
+                .await
+        }
+        .boxed()
+    }
+
+    fn http_patch_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> futures::prelude::future::BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            let request = self
+                .reqwest_connector
+                //TODO: remove clone after migrate to hyper
+                .patch(full_uri.clone())
+                .header(AUTHORIZATION, token.get_token_value())
+                .json(&request_body);
+
+            self.send_http_request("PATCH", request, full_uri, ratelimiter)
+                .await
+        }
+        .boxed()
+    }
+
+    fn http_delete_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        token: &'a FtApiToken,
+        ratelimiter: &'a HeaderMetaData,
+        request_body: &'a RQ,
+    ) -> futures::future::BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        async move {
+            let request = self
+                .reqwest_connector
+                //TODO: remove clone after migrate to hyper
+                .delete(full_uri.clone())
+                .header(AUTHORIZATION, token.get_token_value())
+                .json(&request_body);
+
+            self.send_http_request("DELETE", request, full_uri, ratelimiter)
+                .await
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_bodies_and_reports_errors() {
+        let ids: Vec<u64> = decode_body(br#"[1, 2, 3]"#).unwrap();
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        let err = decode_body::<Vec<u64>>(br#"[1, "two"]"#).unwrap_err();
+        let FtClientError::ProtocolError(err) = err else {
+            panic!("{err:?}");
+        };
+        assert_eq!(err.json_body.as_deref(), Some(r#"[1, "two"]"#));
+    }
+}
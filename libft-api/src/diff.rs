@@ -0,0 +1,360 @@
+//! Change tracking between two snapshots of the same collections.
+//!
+//! Snapshots are plain slices of models, as read back from JSON exports or from the `mirror`
+//! (`FtMirror::all`). Records are matched by id, and each difference that matters for reports
+//! becomes a typed `FtChange`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! fn weekly(last_week: &str, this_week: &str) -> serde_json::Result<()> {
+//!     let old: Vec<FtUser> = serde_json::from_str(last_week)?;
+//!     let new: Vec<FtUser> = serde_json::from_str(this_week)?;
+//!     for change in diff_users(&old, &new) {
+//!         println!("{}", serde_json::to_string(&change)?);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use rvstruct::ValueStruct;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::prelude::*;
+
+/// A difference between two snapshots.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FtChange {
+    UserAdded {
+        user_id: FtUserId,
+    },
+    UserRemoved {
+        user_id: FtUserId,
+    },
+    CorrectionPointChanged {
+        user_id: FtUserId,
+        from: Option<FtCorrectionPoint>,
+        to: Option<FtCorrectionPoint>,
+    },
+    WalletChanged {
+        user_id: FtUserId,
+        from: Option<FtWallet>,
+        to: Option<FtWallet>,
+    },
+    LevelIncreased {
+        user_id: Option<FtUserId>,
+        cursus_id: FtCursusId,
+        from: f64,
+        to: f64,
+    },
+    LevelDecreased {
+        user_id: Option<FtUserId>,
+        cursus_id: FtCursusId,
+        from: f64,
+        to: f64,
+    },
+    ProjectStarted {
+        user_id: Option<FtUserId>,
+        project: FtSlug,
+    },
+    ProjectStatusChanged {
+        user_id: Option<FtUserId>,
+        project: FtSlug,
+        from: FtStatus,
+        to: FtStatus,
+    },
+    ProjectValidated {
+        user_id: Option<FtUserId>,
+        project: FtSlug,
+        final_mark: Option<FtFinalMark>,
+    },
+    ProjectFailed {
+        user_id: Option<FtUserId>,
+        project: FtSlug,
+        final_mark: Option<FtFinalMark>,
+    },
+    TeamClosed {
+        team_id: FtTeamId,
+        final_mark: Option<FtFinalMark>,
+    },
+    TeamMarked {
+        team_id: FtTeamId,
+        from: Option<FtFinalMark>,
+        to: Option<FtFinalMark>,
+    },
+}
+
+/// Diffs two snapshots of users, including their `cursus_users` and `projects_users` when both
+/// snapshots carry them (e.g. `users_id` responses).
+pub fn diff_users(old: &[FtUser], new: &[FtUser]) -> Vec<FtChange> {
+    let mut changes = Vec::new();
+    for (old, new) in pair_by(old, new, |user| user.id) {
+        match (old, new) {
+            (None, Some(new)) => {
+                changes.extend(new.id.map(|user_id| FtChange::UserAdded { user_id }))
+            }
+            (Some(old), None) => {
+                changes.extend(old.id.map(|user_id| FtChange::UserRemoved { user_id }));
+            }
+            (Some(old), Some(new)) => {
+                let Some(user_id) = new.id else { continue };
+                if old.correction_point != new.correction_point {
+                    changes.push(FtChange::CorrectionPointChanged {
+                        user_id,
+                        from: old.correction_point.clone(),
+                        to: new.correction_point.clone(),
+                    });
+                }
+                if old.wallet != new.wallet {
+                    changes.push(FtChange::WalletChanged {
+                        user_id,
+                        from: old.wallet.clone(),
+                        to: new.wallet.clone(),
+                    });
+                }
+                if let (Some(old), Some(new)) = (&old.cursus_users, &new.cursus_users) {
+                    changes.extend(diff_cursus_users(old, new));
+                }
+                if let (Some(old), Some(new)) = (&old.projects_users, &new.projects_users) {
+                    changes.extend(diff_projects_users_of(Some(user_id), old, new));
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    changes
+}
+
+/// Diffs two snapshots of cursus users, reporting level changes.
+pub fn diff_cursus_users(old: &[FtCursusUser], new: &[FtCursusUser]) -> Vec<FtChange> {
+    pair_by(old, new, |cursus_user| Some(cursus_user.id.clone()))
+        .into_iter()
+        .filter_map(|(old, new)| {
+            let (old, new) = (old?, new?);
+            let (from, to) = (*old.level.value(), *new.level.value());
+            let (user_id, cursus_id) = (new.user.id, new.cursus_id.clone());
+            if to > from {
+                Some(FtChange::LevelIncreased {
+                    user_id,
+                    cursus_id,
+                    from,
+                    to,
+                })
+            } else if to < from {
+                Some(FtChange::LevelDecreased {
+                    user_id,
+                    cursus_id,
+                    from,
+                    to,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Diffs two snapshots of projects users, reporting started, marked and status changes.
+pub fn diff_projects_users(old: &[FtProjectsUser], new: &[FtProjectsUser]) -> Vec<FtChange> {
+    diff_projects_users_of(None, old, new)
+}
+
+fn diff_projects_users_of(
+    user_id: Option<FtUserId>,
+    old: &[FtProjectsUser],
+    new: &[FtProjectsUser],
+) -> Vec<FtChange> {
+    let mut changes = Vec::new();
+    for (old, new) in pair_by(old, new, |projects_user| Some(projects_user.id.clone())) {
+        let Some(new) = new else { continue };
+        let user_id = user_id.or_else(|| new.user.as_ref().and_then(|user| user.id));
+        let project = new.project.slug.clone();
+
+        let Some(old) = old else {
+            changes.push(FtChange::ProjectStarted { user_id, project });
+            continue;
+        };
+        if new.marked && (!old.marked || old.validated != new.validated) {
+            let final_mark = new.final_mark.clone();
+            changes.push(match new.validated {
+                Some(true) => FtChange::ProjectValidated {
+                    user_id,
+                    project,
+                    final_mark,
+                },
+                _ => FtChange::ProjectFailed {
+                    user_id,
+                    project,
+                    final_mark,
+                },
+            });
+        } else if old.status != new.status {
+            changes.push(FtChange::ProjectStatusChanged {
+                user_id,
+                project,
+                from: old.status.clone(),
+                to: new.status.clone(),
+            });
+        }
+    }
+    changes
+}
+
+/// Diffs two snapshots of teams, reporting closed teams and final mark changes.
+pub fn diff_teams(old: &[FtTeam], new: &[FtTeam]) -> Vec<FtChange> {
+    let is_closed = |team: &FtTeam| team.closed == Some(true) || team.closed_at.is_some();
+
+    let mut changes = Vec::new();
+    for (old, new) in pair_by(old, new, |team| Some(team.id.clone())) {
+        let (Some(old), Some(new)) = (old, new) else {
+            continue;
+        };
+        if is_closed(new) && !is_closed(old) {
+            changes.push(FtChange::TeamClosed {
+                team_id: new.id.clone(),
+                final_mark: new.final_mark.clone(),
+            });
+        } else if old.final_mark != new.final_mark {
+            changes.push(FtChange::TeamMarked {
+                team_id: new.id.clone(),
+                from: old.final_mark.clone(),
+                to: new.final_mark.clone(),
+            });
+        }
+    }
+    changes
+}
+
+/// Pairs the records of `old` and `new` by id: records of `new` in order, then the removed ones.
+///
+/// Records without an id are skipped.
+fn pair_by<'a, T, K, F>(old: &'a [T], new: &'a [T], id: F) -> Vec<(Option<&'a T>, Option<&'a T>)>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> Option<K>,
+{
+    let mut old_by_id = old
+        .iter()
+        .filter_map(|record| Some((id(record)?, record)))
+        .collect::<HashMap<_, _>>();
+
+    let mut pairs = new
+        .iter()
+        .filter_map(|record| {
+            let key = id(record)?;
+            Some((old_by_id.remove(&key), Some(record)))
+        })
+        .collect::<Vec<_>>();
+    pairs.extend(
+        old.iter()
+            .filter(|record| id(record).is_some_and(|key| old_by_id.contains_key(&key)))
+            .map(|record| (Some(record), None)),
+    );
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projects_user(
+        id: i32,
+        status: &str,
+        marked: bool,
+        validated: Option<bool>,
+    ) -> FtProjectsUser {
+        serde_json::from_value(serde_json::json!({
+            "created_at": "2025-01-02T00:00:00.000Z",
+            "cursus_ids": [21],
+            "final_mark": if marked { Some(100) } else { None },
+            "id": id,
+            "marked": marked,
+            "occurrence": 0,
+            "project": { "id": 1314, "name": "Libft", "slug": "42cursus-libft" },
+            "status": status,
+            "updated_at": "2025-01-20T00:00:00.000Z",
+            "user": { "id": 180_844 },
+            "validated?": validated
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn users() {
+        let user = |id: i32, correction_point: i32| {
+            serde_json::from_value::<FtUser>(serde_json::json!({
+                "id": id,
+                "correction_point": correction_point,
+            }))
+            .unwrap()
+        };
+
+        let changes = diff_users(&[user(1, 3), user(2, 5)], &[user(1, 4), user(3, 5)]);
+
+        assert_eq!(
+            changes,
+            vec![
+                FtChange::CorrectionPointChanged {
+                    user_id: FtUserId::new(1),
+                    from: Some(FtCorrectionPoint::new(3)),
+                    to: Some(FtCorrectionPoint::new(4)),
+                },
+                FtChange::UserAdded {
+                    user_id: FtUserId::new(3)
+                },
+                FtChange::UserRemoved {
+                    user_id: FtUserId::new(2)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn projects_users() {
+        let old = [projects_user(1, "in_progress", false, None)];
+        let new = [
+            projects_user(1, "finished", true, Some(true)),
+            projects_user(2, "creating_group", false, None),
+        ];
+
+        let changes = diff_projects_users(&old, &new);
+
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(
+            &changes[0],
+            FtChange::ProjectValidated { user_id: Some(user_id), final_mark: Some(_), .. }
+                if *user_id == FtUserId::new(180_844)
+        ));
+        assert!(matches!(&changes[1], FtChange::ProjectStarted { .. }));
+        assert_eq!(
+            serde_json::to_value(&changes[1]).unwrap()["kind"],
+            "project_started"
+        );
+    }
+
+    #[test]
+    fn teams() {
+        let team = |closed: bool, final_mark: Option<i32>| {
+            serde_json::from_value::<FtTeam>(serde_json::json!({
+                "id": 1,
+                "closed": closed,
+                "final_mark": final_mark,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            diff_teams(&[team(false, None)], &[team(true, Some(0))]),
+            vec![FtChange::TeamClosed {
+                team_id: FtTeamId::new(1),
+                final_mark: Some(FtFinalMark::new(0)),
+            }]
+        );
+        assert!(diff_teams(&[team(true, Some(0))], &[team(true, Some(0))]).is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+//! Exporters turning API models into files for analysis.
+//!
+//! * **csv**: Serde-based CSV export with typed column selection
+//! * **ndjson**: One JSON document per line, written as records stream in
+//! * **arrow**: Arrow record batches and Parquet files, behind the `parquet` feature
+//!
+//! The CSV and Arrow exporters share the column enums of this module (`FtUserColumn`,
+//! `FtProjectsUserColumn`, `FtScaleTeamColumn`, `FtCorrectionPointHistoryColumn`,
+//! `FtCorrectionPointAuditColumn`, `FtLocationColumn`, `FtTeamColumn`,
+//! `FtScaleTeamsMultipleCreateColumn`, `FtCoalitionAwardAuditColumn`).
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::export::{csv::to_csv_string, FtExportError, FtUserColumn};
+//! use libft_api::prelude::*;
+//!
+//! fn example(users: &[FtUser]) -> Result<String, FtExportError> {
+//!     to_csv_string(users, &[FtUserColumn::Id, FtUserColumn::Login])
+//! }
+//! ```
+
+#[cfg(feature = "parquet")]
+pub mod arrow;
+pub mod csv;
+pub mod ndjson;
+
+mod columns;
+pub use columns::*;
+
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::FtClientError;
+
+#[derive(Debug)]
+pub enum FtExportError {
+    Io(std::io::Error),
+    Csv(::csv::Error),
+    Serde(serde_json::Error),
+    /// A page of the exported records could not be fetched.
+    Client(FtClientError),
+    #[cfg(feature = "parquet")]
+    Arrow(::arrow::error::ArrowError),
+    #[cfg(feature = "parquet")]
+    Parquet(::parquet::errors::ParquetError),
+}
+
+impl Display for FtExportError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Ft export io error: {err}"),
+            Self::Csv(err) => write!(f, "Ft export csv error: {err}"),
+            Self::Serde(err) => write!(f, "Ft export serialization error: {err}"),
+            Self::Client(err) => write!(f, "Ft export client error: {err}"),
+            #[cfg(feature = "parquet")]
+            Self::Arrow(err) => write!(f, "Ft export arrow error: {err}"),
+            #[cfg(feature = "parquet")]
+            Self::Parquet(err) => write!(f, "Ft export parquet error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FtExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Csv(err) => Some(err),
+            Self::Serde(err) => Some(err),
+            Self::Client(err) => Some(err),
+            #[cfg(feature = "parquet")]
+            Self::Arrow(err) => Some(err),
+            #[cfg(feature = "parquet")]
+            Self::Parquet(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for FtExportError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<::csv::Error> for FtExportError {
+    fn from(err: ::csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
+
+impl From<serde_json::Error> for FtExportError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serde(err)
+    }
+}
+
+impl From<FtClientError> for FtExportError {
+    fn from(err: FtClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<::arrow::error::ArrowError> for FtExportError {
+    fn from(err: ::arrow::error::ArrowError) -> Self {
+        Self::Arrow(err)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<::parquet::errors::ParquetError> for FtExportError {
+    fn from(err: ::parquet::errors::ParquetError) -> Self {
+        Self::Parquet(err)
+    }
+}
@@ -0,0 +1,188 @@
+use serde::Serialize;
+
+use crate::prelude::*;
+
+/// A column that can be selected when exporting a record.
+pub trait FtExportColumn: Copy + 'static {
+    /// Every column of the record, in the default export order.
+    const ALL: &'static [Self];
+
+    /// The header cell of the column.
+    fn header(self) -> &'static str;
+
+    /// The JSON pointer of the value in the serialized record.
+    fn pointer(self) -> &'static str;
+
+    /// The type of the column, the same for every export of the record.
+    fn column_type(self) -> FtExportColumnType;
+}
+
+/// The type of an exported column, e.g. in an Arrow schema. Dates are written as strings.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FtExportColumnType {
+    Int64,
+    Float64,
+    Boolean,
+    Utf8,
+}
+
+/// A model that can be exported.
+pub trait FtExportRecord: Serialize {
+    type Column: FtExportColumn;
+}
+
+macro_rules! export_columns {
+    ($record:ty, $column:ident { $($variant:ident => ($header:literal, $pointer:literal, $column_type:ident)),+ $(,)? }) => {
+        #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Copy)]
+        pub enum $column {
+            $($variant),+
+        }
+
+        impl FtExportColumn for $column {
+            const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            fn header(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $header),+
+                }
+            }
+
+            fn pointer(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $pointer),+
+                }
+            }
+
+            fn column_type(self) -> FtExportColumnType {
+                match self {
+                    $(Self::$variant => FtExportColumnType::$column_type),+
+                }
+            }
+        }
+
+        impl FtExportRecord for $record {
+            type Column = $column;
+        }
+    };
+}
+
+export_columns!(FtUser, FtUserColumn {
+    Id => ("id", "/id", Int64),
+    Login => ("login", "/login", Utf8),
+    Email => ("email", "/email", Utf8),
+    FirstName => ("first_name", "/first_name", Utf8),
+    LastName => ("last_name", "/last_name", Utf8),
+    DisplayName => ("displayname", "/displayname", Utf8),
+    Kind => ("kind", "/kind", Utf8),
+    PoolMonth => ("pool_month", "/pool_month", Utf8),
+    PoolYear => ("pool_year", "/pool_year", Utf8),
+    Location => ("location", "/location", Utf8),
+    CorrectionPoint => ("correction_point", "/correction_point", Int64),
+    Wallet => ("wallet", "/wallet", Int64),
+    Staff => ("staff", "/staff?", Boolean),
+    Active => ("active", "/active?", Boolean),
+    Alumni => ("alumni", "/alumni?", Boolean),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    UpdatedAt => ("updated_at", "/updated_at", Utf8),
+});
+
+export_columns!(FtProjectsUser, FtProjectsUserColumn {
+    Id => ("id", "/id", Int64),
+    UserId => ("user_id", "/user/id", Int64),
+    UserLogin => ("user_login", "/user/login", Utf8),
+    ProjectId => ("project_id", "/project/id", Int64),
+    ProjectSlug => ("project_slug", "/project/slug", Utf8),
+    ProjectName => ("project_name", "/project/name", Utf8),
+    Status => ("status", "/status", Utf8),
+    FinalMark => ("final_mark", "/final_mark", Int64),
+    Validated => ("validated", "/validated?", Boolean),
+    Occurrence => ("occurrence", "/occurrence", Int64),
+    CurrentTeamId => ("current_team_id", "/current_team_id", Int64),
+    Marked => ("marked", "/marked", Boolean),
+    MarkedAt => ("marked_at", "/marked_at", Utf8),
+    RetriableAt => ("retriable_at", "/retriable_at", Utf8),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    UpdatedAt => ("updated_at", "/updated_at", Utf8),
+});
+
+export_columns!(FtScaleTeam, FtScaleTeamColumn {
+    Id => ("id", "/id", Int64),
+    ScaleId => ("scale_id", "/scale_id", Int64),
+    TeamId => ("team_id", "/team/id", Int64),
+    TeamName => ("team_name", "/team/name", Utf8),
+    CorrectorId => ("corrector_id", "/corrector/id", Int64),
+    CorrectorLogin => ("corrector_login", "/corrector/login", Utf8),
+    FinalMark => ("final_mark", "/final_mark", Int64),
+    Flag => ("flag", "/flag/name", Utf8),
+    BeginAt => ("begin_at", "/begin_at", Utf8),
+    FilledAt => ("filled_at", "/filled_at", Utf8),
+    Comment => ("comment", "/comment", Utf8),
+    Feedback => ("feedback", "/feedback", Utf8),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    UpdatedAt => ("updated_at", "/updated_at", Utf8),
+});
+
+export_columns!(FtCorrectionPointHistory, FtCorrectionPointHistoryColumn {
+    Id => ("id", "/id", Int64),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    Reason => ("reason", "/reason", Utf8),
+    ScaleTeamId => ("scale_team_id", "/scale_team_id", Int64),
+    Sum => ("sum", "/sum", Int64),
+    Total => ("total", "/total", Int64),
+    UpdatedAt => ("updated_at", "/updated_at", Utf8),
+});
+
+export_columns!(FtCorrectionPointAuditRow, FtCorrectionPointAuditColumn {
+    UserId => ("user_id", "/user_id", Int64),
+    Id => ("id", "/id", Int64),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    Reason => ("reason", "/reason", Utf8),
+    ScaleTeamId => ("scale_team_id", "/scale_team_id", Int64),
+    Sum => ("sum", "/sum", Int64),
+    Total => ("total", "/total", Int64),
+});
+
+export_columns!(FtLocation, FtLocationColumn {
+    Id => ("id", "/id", Int64),
+    UserId => ("user_id", "/user/id", Int64),
+    UserLogin => ("user_login", "/user/login", Utf8),
+    CampusId => ("campus_id", "/campus_id", Int64),
+    Host => ("host", "/host", Utf8),
+    Primary => ("primary", "/primary", Boolean),
+    BeginAt => ("begin_at", "/begin_at", Utf8),
+    EndAt => ("end_at", "/end_at", Utf8),
+});
+
+export_columns!(FtTeam, FtTeamColumn {
+    Id => ("id", "/id", Int64),
+    Name => ("name", "/name", Utf8),
+    ProjectId => ("project_id", "/project_id", Int64),
+    ProjectSessionId => ("project_session_id", "/project_session_id", Int64),
+    Status => ("status", "/status", Utf8),
+    FinalMark => ("final_mark", "/final_mark", Int64),
+    Validated => ("validated", "/validated", Boolean),
+    Closed => ("closed", "/closed", Boolean),
+    Locked => ("locked", "/locked", Boolean),
+    CreatedAt => ("created_at", "/created_at", Utf8),
+    UpdatedAt => ("updated_at", "/updated_at", Utf8),
+});
+
+#[cfg(feature = "scale_teams")]
+export_columns!(FtApiScaleTeamsMultipleCreateBody, FtScaleTeamsMultipleCreateColumn {
+    BeginAt => ("begin_at", "/begin_at", Utf8),
+    UserId => ("user_id", "/user_id", Int64),
+    TeamId => ("team_id", "/team_id", Int64),
+});
+
+export_columns!(FtCoalitionAwardAudit, FtCoalitionAwardAuditColumn {
+    Login => ("login", "/login", Utf8),
+    UserId => ("user_id", "/user_id", Int64),
+    CoalitionId => ("coalition_id", "/coalition_id", Int64),
+    CoalitionsUserId => ("coalitions_user_id", "/coalitions_user_id", Int64),
+    Points => ("points", "/points", Int64),
+    Reason => ("reason", "/reason", Utf8),
+    Status => ("status", "/status", Utf8),
+    ScoreId => ("score_id", "/score_id", Int64),
+    Error => ("error", "/error", Utf8),
+    At => ("at", "/at", Utf8),
+});
@@ -0,0 +1,171 @@
+//! CSV export of API models.
+//!
+//! Records are serialized with serde and each column picks one value out of the serialized
+//! record, so nested fields (`project/slug`, `corrector/login`) are reachable without unwrapping
+//! every `Option` by hand. Missing values are written as empty cells, and quoting is left to the
+//! `csv` writer.
+
+use serde_json::Value;
+use std::io::Write;
+
+use super::{FtExportColumn, FtExportError, FtExportRecord};
+
+/// A CSV writer for records of a single kind.
+pub struct FtCsvWriter<W: Write, C: FtExportColumn> {
+    writer: ::csv::Writer<W>,
+    columns: Vec<C>,
+    header_written: bool,
+}
+
+impl<W: Write, C: FtExportColumn> FtCsvWriter<W, C> {
+    /// Creates a writer exporting every column.
+    pub fn new(writer: W) -> Self {
+        Self::with_columns(writer, C::ALL.to_vec())
+    }
+
+    /// Creates a writer exporting only `columns`, in that order.
+    pub fn with_columns(writer: W, columns: Vec<C>) -> Self {
+        Self {
+            writer: ::csv::Writer::from_writer(writer),
+            columns,
+            header_written: false,
+        }
+    }
+
+    /// Writes one record, preceded by the header row on the first call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be serialized or the underlying writer fails.
+    pub fn write<R>(&mut self, record: &R) -> Result<(), FtExportError>
+    where
+        R: FtExportRecord<Column = C>,
+    {
+        self.write_header()?;
+
+        let value = serde_json::to_value(record)?;
+        let row = self
+            .columns
+            .iter()
+            .map(|column| cell(value.pointer(column.pointer())));
+        self.writer.write_record(row)?;
+
+        Ok(())
+    }
+
+    /// Writes every record of `records`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be serialized or the underlying writer fails.
+    pub fn write_all<'a, R, I>(&mut self, records: I) -> Result<(), FtExportError>
+    where
+        R: FtExportRecord<Column = C> + 'a,
+        I: IntoIterator<Item = &'a R>,
+    {
+        self.write_header()?;
+        for record in records {
+            self.write(record)?;
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the writer and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header or buffered rows cannot be written.
+    pub fn into_inner(mut self) -> Result<W, FtExportError> {
+        self.write_header()?;
+        self.writer
+            .into_inner()
+            .map_err(|err| FtExportError::Io(err.into_error()))
+    }
+
+    fn write_header(&mut self) -> Result<(), FtExportError> {
+        if !self.header_written {
+            self.writer
+                .write_record(self.columns.iter().map(|column| column.header()))?;
+            self.header_written = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Exports `records` to a CSV string with the given `columns`.
+///
+/// # Errors
+///
+/// Returns an error if a record cannot be serialized.
+pub fn to_csv_string<R: FtExportRecord>(
+    records: &[R],
+    columns: &[R::Column],
+) -> Result<String, FtExportError> {
+    let mut writer = FtCsvWriter::with_columns(Vec::new(), columns.to_vec());
+    writer.write_all(records)?;
+
+    Ok(String::from_utf8_lossy(&writer.into_inner()?).into_owned())
+}
+
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::FtUserColumn;
+    use crate::prelude::*;
+
+    #[test]
+    fn user_columns() {
+        let users = [FtUser::new()
+            .with_id(FtUserId::new(212_750))
+            .with_login(FtLoginId::new("hdoo".to_string()))
+            .with_staff(false)];
+
+        assert_eq!(
+            "id,login,staff,location\n212750,hdoo,false,\n",
+            to_csv_string(
+                &users,
+                &[
+                    FtUserColumn::Id,
+                    FtUserColumn::Login,
+                    FtUserColumn::Staff,
+                    FtUserColumn::Location,
+                ],
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn correction_point_history_quoting() {
+        let raw = r#"[{
+  "id": 72431977,
+  "created_at": "2024-11-06T12:26:03.805Z",
+  "reason": "Earning after defense, \"libft\"",
+  "scale_team_id": null,
+  "sum": 1,
+  "total": 24,
+  "updated_at": "2024-11-06T12:26:03.805Z"
+}]"#;
+        let historics = serde_json::from_str::<Vec<FtCorrectionPointHistory>>(raw).unwrap();
+
+        let mut writer = FtCsvWriter::new(Vec::new());
+        writer.write_all(&historics).unwrap();
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        assert_eq!(
+            "id,created_at,reason,scale_team_id,sum,total,updated_at\n\
+             72431977,2024-11-06T12:26:03.805Z,\"Earning after defense, \"\"libft\"\"\",,1,24,2024-11-06T12:26:03.805Z\n",
+            csv
+        );
+    }
+}
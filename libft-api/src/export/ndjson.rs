@@ -0,0 +1,189 @@
+//! NDJSON export of API models.
+//!
+//! Each record is serialized with serde as one JSON document per line, the layout `jq`, DuckDB
+//! and most log tooling read directly. Lines are flushed as they are written, so an export fed
+//! by `paginate` runs in constant memory and can be followed while it grows.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::export::ndjson::FtNdjsonWriter;
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let locations = paginate(100, |page| {
+//!         session.locations(FtApiLocationsRequest::new().with_page(page).with_per_page(100))
+//!     });
+//!     let mut writer = FtNdjsonWriter::create("locations.ndjson")?;
+//!     let count = writer.write_stream(locations).await?;
+//!     eprintln!("{count} locations exported");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Stdout, Write};
+use std::path::Path;
+
+use super::FtExportError;
+use crate::prelude::ClientResult;
+
+/// A writer of one JSON record per line.
+pub struct FtNdjsonWriter<W: Write> {
+    writer: W,
+    count: usize,
+}
+
+impl FtNdjsonWriter<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path`, buffered so each line is written at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, FtExportError> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl FtNdjsonWriter<Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(std::io::stdout())
+    }
+}
+
+impl<W: Write> FtNdjsonWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, count: 0 }
+    }
+
+    /// The number of records written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Writes one record as a line and flushes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be serialized or the underlying writer fails.
+    pub fn write<R: Serialize>(&mut self, record: &R) -> Result<(), FtExportError> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Writes every record of `records`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be serialized or the underlying writer fails.
+    pub fn write_all<'a, R, I>(&mut self, records: I) -> Result<(), FtExportError>
+    where
+        R: Serialize + 'a,
+        I: IntoIterator<Item = &'a R>,
+    {
+        for record in records {
+            self.write(record)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the records of `stream` as they arrive, returning how many were written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the stream, or an error if a record cannot be written; the
+    /// records before it are already written.
+    pub async fn write_stream<R, S>(&mut self, stream: S) -> Result<usize, FtExportError>
+    where
+        R: Serialize,
+        S: Stream<Item = ClientResult<R>>,
+    {
+        let mut stream = std::pin::pin!(stream);
+        let mut count = 0;
+        while let Some(record) = stream.next().await {
+            self.write(&record?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Flushes the writer and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffered lines cannot be written.
+    pub fn into_inner(mut self) -> Result<W, FtExportError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug, serde::Deserialize, rsb_derive::Builder, libft_api_derive::HasVector)]
+    struct Page {
+        users: Vec<FtUser>,
+    }
+
+    fn user(id: i32) -> FtUser {
+        FtUser::new().with_id(FtUserId::new(id))
+    }
+
+    #[tokio::test]
+    async fn streams_pages_as_lines() {
+        let pages = paginate(2, |page| async move {
+            match page {
+                1 => Ok(Page::new(vec![user(1), user(2)])),
+                2 => Ok(Page::new(vec![user(3)])),
+                _ => unreachable!("the short page ends the stream"),
+            }
+        });
+
+        let mut writer = FtNdjsonWriter::new(Vec::new());
+        assert_eq!(writer.write_stream(pages).await.unwrap(), 3);
+
+        let ndjson = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let ids = ndjson
+            .lines()
+            .map(|line| serde_json::from_str::<FtUser>(line).unwrap().id)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                Some(FtUserId::new(1)),
+                Some(FtUserId::new(2)),
+                Some(FtUserId::new(3))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_at_the_first_error() {
+        let pages = paginate(1, |page| async move {
+            match page {
+                1 => Ok(Page::new(vec![user(1)])),
+                _ => Err(FtClientError::HttpError(FtHttpError::new(
+                    http::StatusCode::NOT_FOUND,
+                ))),
+            }
+        });
+
+        let mut writer = FtNdjsonWriter::new(Vec::new());
+        let err = writer.write_stream(pages).await.unwrap_err();
+        assert!(matches!(err, FtExportError::Client(_)));
+        assert_eq!(writer.count(), 1);
+    }
+}
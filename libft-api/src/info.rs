@@ -5,61 +5,7 @@ pub const TEST_USER_YONDOO_ID: i32 = 180_844;
 pub const FT_GROUP_ID_TEST_ACCOUNT: i32 = 119;
 pub const FT_GROUP_ID_STAFF: i32 = 1;
 
-pub mod ft_campus_id {
-    pub const RABAT: i32 = 75;
-    pub const ISKANDARPUTERI: i32 = 73;
-    pub const MILANO: i32 = 72;
-    pub const BEIRUT: i32 = 71;
-    pub const NABLUS: i32 = 70;
-    pub const GYEONGSAN: i32 = 69;
-    pub const LUANDA: i32 = 68;
-    pub const WARSAW: i32 = 67;
-    pub const ANTANANARIVO: i32 = 65;
-    pub const SINGAPORE: i32 = 64;
-    pub const LE_HAVRE: i32 = 62;
-    pub const BELO_HORIZONTE: i32 = 61;
-    pub const PERPIGNAN: i32 = 60;
-    pub const LUXEMBOURG: i32 = 59;
-    pub const PORTO: i32 = 58;
-    pub const LONDON: i32 = 57;
-    pub const PRAGUE: i32 = 56;
-    pub const TÉTOUAN: i32 = 55;
-    pub const VIENNA: i32 = 53;
-    pub const FLORENCE: i32 = 52;
-    pub const BERLIN: i32 = 51;
-    pub const KOCAELI: i32 = 50;
-    pub const ISTANBUL: i32 = 49;
-    pub const MULHOUSE: i32 = 48;
-    pub const LAUSANNE: i32 = 47;
-    pub const BARCELONA: i32 = 46;
-    pub const WOLFSBURG: i32 = 44;
-    pub const ABU_DHABI: i32 = 43;
-    pub const NICE: i32 = 41;
-    pub const URDULIZ: i32 = 40;
-    pub const HEILBRONN: i32 = 39;
-    pub const LISBOA: i32 = 38;
-    pub const MALAGA: i32 = 37;
-    pub const ADELAIDE: i32 = 36;
-    pub const AMMAN: i32 = 35;
-    pub const KUALA_LUMPUR: i32 = 34;
-    pub const BANGKOK: i32 = 33;
-    pub const YEREVAN: i32 = 32;
-    pub const ANGOULEME: i32 = 31;
-    pub const ROME: i32 = 30;
-    pub const SEOUL: i32 = 29;
-    pub const RIO_DE_JANEIRO: i32 = 28;
-    pub const TOKYO: i32 = 26;
-    pub const QUEBEC: i32 = 25;
-    pub const MADRID: i32 = 22;
-    pub const BENGUERIR: i32 = 21;
-    pub const SÃO_PAULO: i32 = 20;
-    pub const KHOURIBGA: i32 = 16;
-    pub const AMSTERDAM: i32 = 14;
-    pub const HELSINKI: i32 = 13;
-    pub const _19: i32 = 12;
-    pub const LYON: i32 = 9;
-    pub const PARIS: i32 = 1;
-}
+pub mod ft_campus_id;
 pub mod ft_cursus {
     pub use inner::*;
     pub const COMMON_CORE_SUBJECTS: [u16; 33] = [
@@ -0,0 +1,633 @@
+//! A queue of API tasks run with priorities, dependencies and a concurrency limit.
+//!
+//! Every task goes through the same session, so they share the client's `RateLimiter`. On top of
+//! it, the queue stops starting tasks while a `429` asks to retry later, retries the rate-limited
+//! tasks once the delay is over, and retries the requests that could not be sent up to
+//! `with_max_attempts` times. Server errors and network errors once sent are only retried for the
+//! jobs marked `with_idempotent`, as the API may have applied the request already.
+//!
+//! Tasks run concurrently in the calling task rather than being spawned, so they can borrow the
+//! session. With `with_state`, the status of each job is saved after it finishes, and the jobs
+//! done in a previous run are not run again. With `with_cancellation`, the queue stops starting
+//! jobs once the token is cancelled, and returns when the running ones finish.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example(user_ids: Vec<FtUserId>) -> Result<(), Box<dyn std::error::Error>> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let mut queue = FtJobQueue::new(7).with_state("users.jobs.json")?;
+//!     for user_id in user_ids {
+//!         let session = &session;
+//!         let job = FtJob::new(user_id.to_string(), move || async move {
+//!             let req = FtApiUsersIdRequest::new(FtUserIdentifier::UserId(user_id));
+//!             Ok(session.users_id(req).await?.user)
+//!         });
+//!         // A read, safe to send again after any error.
+//!         queue.push(job.with_idempotent());
+//!     }
+//!
+//!     let mut progress = queue.subscribe();
+//!     let run = queue.run();
+//!     tokio::pin!(run);
+//!     let report = loop {
+//!         tokio::select! {
+//!             report = &mut run => break report,
+//!             Ok(()) = progress.changed() => {
+//!                 let progress = *progress.borrow();
+//!                 eprintln!("{}/{} done", progress.done, progress.total);
+//!             }
+//!         }
+//!     };
+//!     for outcome in report.failed() {
+//!         eprintln!("{}: {:?}", outcome.id, outcome.error);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::{sleep_until, Instant};
+
+use crate::bulk::outbox::is_transient;
+use crate::common::write_atomic;
+use crate::prelude::*;
+
+/// Where a job stands.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtJobStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+    /// Not run, because a dependency failed, is unknown or depends on the job itself.
+    Skipped,
+}
+
+/// A named task, run once its dependencies are done.
+pub struct FtJob<'a, T> {
+    id: String,
+    priority: i32,
+    depends_on: Vec<String>,
+    idempotent: bool,
+    task: Box<dyn Fn() -> BoxFuture<'a, ClientResult<T>> + 'a>,
+}
+
+impl<'a, T> FtJob<'a, T> {
+    /// A job running `task`, called again for each retry.
+    pub fn new<F, Fut>(id: impl Into<String>, task: F) -> Self
+    where
+        F: Fn() -> Fut + 'a,
+        Fut: Future<Output = ClientResult<T>> + Send + 'a,
+    {
+        Self {
+            id: id.into(),
+            priority: 0,
+            depends_on: Vec::new(),
+            idempotent: false,
+            task: Box::new(move || task().boxed()),
+        }
+    }
+
+    /// Ready jobs with a higher priority start first; ties start in insertion order.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Runs the job only once the job `id` is done.
+    pub fn with_dependency(mut self, id: impl Into<String>) -> Self {
+        self.depends_on.push(id.into());
+        self
+    }
+
+    /// Retries the job on server errors and network errors once the request was sent, e.g. for
+    /// reads or mutations the API applies only once.
+    pub fn with_idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<T> std::fmt::Debug for FtJob<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("FtJob")
+            .field("id", &self.id)
+            .field("priority", &self.priority)
+            .field("depends_on", &self.depends_on)
+            .field("idempotent", &self.idempotent)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The number of jobs in each status, published while the queue runs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FtJobProgress {
+    pub total: usize,
+    pub pending: usize,
+    pub running: usize,
+    pub done: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+impl FtJobProgress {
+    pub fn is_finished(&self) -> bool {
+        self.pending == 0 && self.running == 0
+    }
+}
+
+/// How a job ended.
+#[derive(Debug)]
+pub struct FtJobOutcome<T> {
+    pub id: String,
+    pub status: FtJobStatus,
+    pub attempts: u32,
+    /// The output of the task, `None` unless it ran in this run.
+    pub output: Option<T>,
+    pub error: Option<FtClientError>,
+}
+
+/// The outcomes of `FtJobQueue::run`, in insertion order.
+#[derive(Debug)]
+pub struct FtJobReport<T> {
+    pub outcomes: Vec<FtJobOutcome<T>>,
+}
+
+impl<T> FtJobReport<T> {
+    pub fn outcome(&self, id: &str) -> Option<&FtJobOutcome<T>> {
+        self.outcomes.iter().find(|outcome| outcome.id == id)
+    }
+
+    pub fn output(&self, id: &str) -> Option<&T> {
+        self.outcome(id)?.output.as_ref()
+    }
+
+    /// Whether every job is done.
+    pub fn is_success(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|outcome| outcome.status == FtJobStatus::Done)
+    }
+
+    /// The jobs that failed or were skipped.
+    pub fn failed(&self) -> impl Iterator<Item = &FtJobOutcome<T>> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.status != FtJobStatus::Done)
+    }
+}
+
+/// A queue of jobs, run by `run`.
+pub struct FtJobQueue<'a, T> {
+    jobs: Vec<FtJob<'a, T>>,
+    concurrency: usize,
+    max_attempts: u32,
+    state_path: Option<PathBuf>,
+    state: BTreeMap<String, FtJobStatus>,
+    progress: watch::Sender<FtJobProgress>,
+    hooks: FtClientHooks,
+    cancellation: CancellationToken,
+}
+
+impl<'a, T> FtJobQueue<'a, T>
+where
+    T: Send + 'a,
+{
+    /// A queue running at most `concurrency` jobs at once.
+    pub fn new(concurrency: usize) -> Self {
+        Self {
+            jobs: Vec::new(),
+            concurrency: concurrency.max(1),
+            max_attempts: 3,
+            state_path: None,
+            state: BTreeMap::new(),
+            progress: watch::Sender::new(FtJobProgress::default()),
+            hooks: FtClientHooks::default(),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// How many times a job failing with an error it may retry is tried, 3 by default.
+    ///
+    /// Rate-limited attempts are always retried and not counted.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Saves the status of the jobs to `path` as they finish, and skips the jobs saved as done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn with_state(mut self, path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        self.state = match std::fs::read(&path) {
+            Ok(raw) => serde_json::from_slice(&raw)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        self.state_path = Some(path);
+        Ok(self)
+    }
+
+    /// Reports the retries of the jobs to the `on_retry` hook of `hooks`, usually
+    /// `client.hooks().clone()`. A job whose retry the hook breaks fails with the error.
+    pub fn with_hooks(mut self, hooks: FtClientHooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Stops starting jobs once `cancellation` is cancelled, usually `client.cancellation.clone()`.
+    ///
+    /// The running jobs are awaited; the others stay `Pending`, to be run by the next run with
+    /// the same `with_state`.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    pub fn push(&mut self, job: FtJob<'a, T>) {
+        self.jobs.push(job);
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// A receiver notified each time a job changes status.
+    pub fn subscribe(&self) -> watch::Receiver<FtJobProgress> {
+        self.progress.subscribe()
+    }
+
+    /// Runs the jobs until each one is done, failed or skipped, or until cancelled.
+    pub async fn run(mut self) -> FtJobReport<T> {
+        let index = self
+            .jobs
+            .iter()
+            .enumerate()
+            .map(|(i, job)| (job.id.clone(), i))
+            .collect::<HashMap<_, _>>();
+        let mut outcomes = self
+            .jobs
+            .iter()
+            .map(|job| FtJobOutcome {
+                id: job.id.clone(),
+                status: match self.state.get(&job.id) {
+                    Some(FtJobStatus::Done) => FtJobStatus::Done,
+                    _ => FtJobStatus::Pending,
+                },
+                attempts: 0,
+                output: None,
+                error: None,
+            })
+            .collect::<Vec<_>>();
+        let mut retries = vec![0; self.jobs.len()];
+
+        let mut in_flight = FuturesUnordered::<BoxFuture<'a, (usize, ClientResult<T>)>>::new();
+        let mut paused_until: Option<Instant> = None;
+        loop {
+            skip_blocked(&self.jobs, &index, &mut outcomes);
+
+            while in_flight.len() < self.concurrency
+                && !self.cancellation.is_cancelled()
+                && paused_until.is_none_or(|until| until <= Instant::now())
+            {
+                let Some(i) = next_ready(&self.jobs, &index, &outcomes) else {
+                    break;
+                };
+                outcomes[i].status = FtJobStatus::Running;
+                outcomes[i].attempts += 1;
+                in_flight.push((self.jobs[i].task)().map(move |res| (i, res)).boxed());
+            }
+            self.publish(&outcomes);
+
+            let Some((i, res)) = in_flight.next().await else {
+                if self.cancellation.is_cancelled()
+                    || !outcomes.iter().any(|o| o.status == FtJobStatus::Pending)
+                {
+                    break;
+                }
+                match paused_until.filter(|until| *until > Instant::now()) {
+                    Some(until) => {
+                        tokio::select! {
+                            _ = sleep_until(until) => {}
+                            _ = self.cancellation.cancelled() => {}
+                        }
+                    }
+                    // Nothing runs and nothing can start: the rest waits on a cycle.
+                    None => skip_pending(&mut outcomes),
+                }
+                continue;
+            };
+
+            let outcome = &mut outcomes[i];
+            match res {
+                Ok(output) => {
+                    outcome.status = FtJobStatus::Done;
+                    outcome.output = Some(output);
+                    outcome.error = None;
+                }
+                // The hook gives up on the retries below.
+                Err(err)
+                    if (matches!(err, FtClientError::RateLimitError(_))
+                        || (is_retryable(&err, self.jobs[i].idempotent)
+                            && outcome.attempts < self.max_attempts))
+                        && self.hooks.retry(retries[i] + 1, &err).is_break() =>
+                {
+                    outcome.status = FtJobStatus::Failed;
+                    outcome.error = Some(err);
+                }
+                Err(FtClientError::RateLimitError(err)) => {
+                    let retry_after = err.retry_after.unwrap_or(Duration::from_secs(1));
+                    paused_until = Some(Instant::now() + retry_after);
+                    outcome.status = FtJobStatus::Pending;
+                    outcome.attempts -= 1;
+                    retries[i] += 1;
+                    tracing::warn!(job = outcome.id, ?retry_after, "rate limited, retrying");
+                    crate::metrics::retry("jobs", "rate_limit");
+                }
+                Err(err)
+                    if is_retryable(&err, self.jobs[i].idempotent)
+                        && outcome.attempts < self.max_attempts =>
+                {
+                    tracing::warn!(job = outcome.id, %err, "retrying");
+                    crate::metrics::retry("jobs", "transient");
+                    outcome.status = FtJobStatus::Pending;
+                    outcome.error = Some(err);
+                    retries[i] += 1;
+                }
+                Err(err) => {
+                    outcome.status = FtJobStatus::Failed;
+                    outcome.error = Some(err);
+                }
+            }
+            if matches!(outcome.status, FtJobStatus::Done | FtJobStatus::Failed) {
+                self.save(outcome);
+            }
+        }
+
+        self.publish(&outcomes);
+        FtJobReport { outcomes }
+    }
+
+    fn publish(&self, outcomes: &[FtJobOutcome<T>]) {
+        let mut progress = FtJobProgress {
+            total: outcomes.len(),
+            ..FtJobProgress::default()
+        };
+        for outcome in outcomes {
+            match outcome.status {
+                FtJobStatus::Pending => progress.pending += 1,
+                FtJobStatus::Running => progress.running += 1,
+                FtJobStatus::Done => progress.done += 1,
+                FtJobStatus::Failed => progress.failed += 1,
+                FtJobStatus::Skipped => progress.skipped += 1,
+            }
+        }
+        self.progress.send_if_modified(|current| {
+            let modified = *current != progress;
+            *current = progress;
+            modified
+        });
+    }
+
+    fn save(&mut self, outcome: &FtJobOutcome<T>) {
+        let Some(path) = &self.state_path else {
+            return;
+        };
+        self.state.insert(outcome.id.clone(), outcome.status);
+        if let Err(err) = write_state(path, &self.state) {
+            tracing::warn!(path = %path.display(), %err, "cannot save the job state");
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for FtJobQueue<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("FtJobQueue")
+            .field("jobs", &self.jobs)
+            .field("concurrency", &self.concurrency)
+            .field("max_attempts", &self.max_attempts)
+            .field("state_path", &self.state_path)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Skips the pending jobs with a dependency that failed, was skipped or does not exist.
+fn skip_blocked<T>(
+    jobs: &[FtJob<'_, T>],
+    index: &HashMap<String, usize>,
+    outcomes: &mut [FtJobOutcome<T>],
+) {
+    loop {
+        let blocked = (0..jobs.len()).find(|&i| {
+            outcomes[i].status == FtJobStatus::Pending
+                && jobs[i].depends_on.iter().any(|dependency| {
+                    index.get(dependency).is_none_or(|&d| {
+                        matches!(
+                            outcomes[d].status,
+                            FtJobStatus::Failed | FtJobStatus::Skipped
+                        )
+                    })
+                })
+        });
+        match blocked {
+            Some(i) => outcomes[i].status = FtJobStatus::Skipped,
+            None => return,
+        }
+    }
+}
+
+fn skip_pending<T>(outcomes: &mut [FtJobOutcome<T>]) {
+    for outcome in outcomes {
+        if outcome.status == FtJobStatus::Pending {
+            outcome.status = FtJobStatus::Skipped;
+        }
+    }
+}
+
+/// The pending job whose dependencies are done, with the highest priority.
+fn next_ready<T>(
+    jobs: &[FtJob<'_, T>],
+    index: &HashMap<String, usize>,
+    outcomes: &[FtJobOutcome<T>],
+) -> Option<usize> {
+    (0..jobs.len())
+        .filter(|&i| {
+            outcomes[i].status == FtJobStatus::Pending
+                && jobs[i]
+                    .depends_on
+                    .iter()
+                    .all(|dependency| outcomes[index[dependency]].status == FtJobStatus::Done)
+        })
+        .min_by_key(|&i| (std::cmp::Reverse(jobs[i].priority), i))
+}
+
+/// Whether a failed job may be run again: the request was not sent, or the job is idempotent and
+/// the error transient.
+fn is_retryable(err: &FtClientError, idempotent: bool) -> bool {
+    match err {
+        #[cfg(feature = "reqwest")]
+        FtClientError::ReqwestError(err) if err.error.is_connect() || err.error.is_builder() => {
+            true
+        }
+        err => idempotent && is_transient(err),
+    }
+}
+
+fn write_state(path: &Path, state: &BTreeMap<String, FtJobStatus>) -> std::io::Result<()> {
+    write_atomic(path, &serde_json::to_vec_pretty(state)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    fn http(status: StatusCode) -> FtClientError {
+        FtClientError::HttpError(FtHttpError::new(status))
+    }
+
+    #[tokio::test]
+    async fn priorities_and_dependencies() {
+        let order = Mutex::new(Vec::new());
+        let job = |id: &'static str| {
+            let order = &order;
+            FtJob::new(id, move || async move {
+                order.lock().unwrap().push(id);
+                Ok(id.len())
+            })
+        };
+
+        let mut queue = FtJobQueue::new(1);
+        queue.push(job("low"));
+        queue.push(job("high").with_priority(10));
+        queue.push(job("after_low").with_dependency("low").with_priority(20));
+        let report = queue.run().await;
+
+        assert!(report.is_success());
+        assert_eq!(*order.lock().unwrap(), vec!["high", "low", "after_low"]);
+        assert_eq!(report.output("after_low"), Some(&9));
+    }
+
+    #[tokio::test]
+    async fn retries_and_skips() {
+        let calls = AtomicU32::new(0);
+        let calls = &calls;
+
+        let mut queue = FtJobQueue::new(2).with_max_attempts(2);
+        queue.push(
+            FtJob::new("flaky", move || async move {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(FtClientError::RateLimitError(
+                        FtRateLimitError::new().with_retry_after(Duration::ZERO),
+                    )),
+                    1 => Err(http(StatusCode::BAD_GATEWAY)),
+                    _ => Ok(()),
+                }
+            })
+            .with_idempotent(),
+        );
+        queue.push(FtJob::new("post", || async {
+            Err(http(StatusCode::BAD_GATEWAY))
+        }));
+        queue.push(FtJob::new("rejected", || async {
+            Err(http(StatusCode::UNPROCESSABLE_ENTITY))
+        }));
+        queue.push(FtJob::new("after_rejected", || async { Ok(()) }).with_dependency("rejected"));
+        queue.push(FtJob::new("cycle_a", || async { Ok(()) }).with_dependency("cycle_b"));
+        queue.push(FtJob::new("cycle_b", || async { Ok(()) }).with_dependency("cycle_a"));
+        let progress = queue.subscribe();
+        let report = queue.run().await;
+
+        let status = |id| report.outcome(id).unwrap().status;
+        assert_eq!(status("flaky"), FtJobStatus::Done);
+        assert_eq!(report.outcome("flaky").unwrap().attempts, 2);
+        assert_eq!(status("post"), FtJobStatus::Failed);
+        assert_eq!(report.outcome("post").unwrap().attempts, 1);
+        assert_eq!(status("rejected"), FtJobStatus::Failed);
+        assert_eq!(status("after_rejected"), FtJobStatus::Skipped);
+        assert_eq!(status("cycle_a"), FtJobStatus::Skipped);
+        assert_eq!(
+            *progress.borrow(),
+            FtJobProgress {
+                total: 6,
+                done: 1,
+                failed: 2,
+                skipped: 3,
+                ..FtJobProgress::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn resumes_from_state() {
+        let path = std::env::temp_dir().join(format!("ft_jobs_{}.json", std::process::id()));
+
+        let mut queue = FtJobQueue::new(1).with_state(&path).unwrap();
+        queue.push(FtJob::new("done", || async { Ok(()) }));
+        queue.push(FtJob::new("failed", || async {
+            Err(http(StatusCode::NOT_FOUND))
+        }));
+        queue.run().await;
+
+        let mut queue = FtJobQueue::new(1).with_state(&path).unwrap();
+        queue.push(FtJob::new("done", || async { Ok(()) }));
+        queue.push(FtJob::new("failed", || async { Ok(()) }));
+        let report = queue.run().await;
+
+        assert_eq!(report.outcome("done").unwrap().attempts, 0);
+        assert_eq!(report.outcome("failed").unwrap().attempts, 1);
+        assert!(report.is_success());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn stops_starting_jobs_once_cancelled() {
+        let cancellation = CancellationToken::new();
+        let cancel = &cancellation;
+
+        let mut queue = FtJobQueue::new(1).with_cancellation(cancellation.clone());
+        queue.push(FtJob::new("first", move || async move {
+            cancel.cancel();
+            Ok(())
+        }));
+        queue.push(FtJob::new("second", || async { Ok(()) }));
+        let report = queue.run().await;
+
+        assert_eq!(report.outcome("first").unwrap().status, FtJobStatus::Done);
+        assert_eq!(
+            report.outcome("second").unwrap().status,
+            FtJobStatus::Pending
+        );
+        assert!(!report.is_success());
+    }
+}
@@ -36,29 +36,98 @@
 //! * **Session Management**: Reusable sessions for making multiple API calls
 //! * **Async Support**: Fully asynchronous API calls using async/await
 //! * **Caching**: Automatic token caching and refresh
-//! * **Error Handling**: Comprehensive error types for different failure scenarios
+//! * **Error Handling**: Comprehensive error types for different failure scenarios, and
+//!   `FtClientError::kind` to branch on the known Intra failures without matching their messages
+//! * **Cancellation**: Pagination and bulk loops stop between requests once a `CancellationToken`
+//!   is cancelled, e.g. on Ctrl-C
 //!
 //! ## Modules
+//! * `analytics` — derived figures such as levels from XP, blackhole/milestone deadlines and
+//!   daily logtime.
 //! * `api` — high-level endpoint clients grouped by 42 domain (campus, user, projects, exams).
 //! * `models` — serde-powered representations of request and response payloads.
 //! * `auth` — helpers for building OAuth tokens and refreshing sessions.
+//! * `bulk` — reviewable bulk changes, such as closing stale teams.
+//! * `campaign` — TOML/YAML described export campaigns, behind the `campaign` feature.
 //! * `common` — shared utilities, error types, parameters, rate limiters, and pagination.
-//! * `connector` — HTTP connector implementations (currently reqwest-based).
+//! * `connector` — HTTP connector implementations: reqwest-based, and a record/replay wrapper.
+//! * `diff` — typed change events between two snapshots of users, projects users and teams.
+//! * `export` — CSV (and, with the `parquet` feature, Arrow/Parquet) export of models.
 //! * `info` — constants and information about 42 campuses and cursus.
+//! * `jobs` — a rate-limit-aware queue of API tasks with priorities and dependencies.
+//! * `metrics` — request, rate limit and retry metrics, recorded with the `metrics` feature.
+//! * `mirror` — incremental SQLite mirror of collections, behind the `mirror` feature.
+//! * `registry` — runtime name lookups (e.g. campus ids) backing the generated constants.
+//! * `reports` — typed report rows, such as the correction point audit.
+//! * `scheduler` — round-robin assignment of evaluators to defenses.
+//! * `store` — an in-memory store of fetched entities with typed joins.
+//! * `test_support` — a mock Intra API for integration tests, behind the `test-support` feature.
+//! * `validate` — consistency checks across teams, scale teams and projects users.
 //! * `prelude` — convenient glob imports for common functionality.
 //!
 //! Explore the `bin/` directory for runnable examples of each workflow, and enable tracing with
 //! `RUST_LOG=info` to inspect HTTP activity during development.
+//!
+//! ## Cargo features
+//! Each endpoint group of `api` sits behind a feature named after its path: `users`,
+//! `scale_teams`, `projects`, `campus`, `events`, and so on. The default `full` feature enables
+//! them all; to build only the endpoints a program calls, e.g. on a small target:
+//!
+//! ```toml
+//! libft-api = { version = "0.1", default-features = false, features = ["users", "campus"] }
+//! ```
+//!
+//! The models are shared by every group and always built. Helpers fetching from an endpoint, such
+//! as `FtPiscine::fetch` or `close_stale_teams`, need the feature of that endpoint; the
+//! `scheduler` module needs `scale_teams`. The `campaign` and `mirror` features enable the groups
+//! they fetch from.
+//!
+//! The `reqwest` feature, also on by default, provides `FtClientReqwestConnector` and the token
+//! requests of `FtApiToken`. Disable it to send requests through your own implementation of
+//! `FtClientHttpConnector`, without building `reqwest`.
+//!
+//! The `simd-json` feature parses the responses of `FtClientReqwestConnector` with `simd-json`,
+//! for programs where decoding large pages, such as 100 `FtUserExt` at a time, shows in profiles.
+//!
+//! ## Tests
+//! `cargo test` runs offline, against the mock of `test_support`. The tests calling the real
+//! Intra API need credentials in the environment, and some of them modify data on the Intra; they
+//! are ignored unless the `online-tests` feature is enabled:
+//!
+//! ```sh
+//! cargo test --features online-tests
+//! ```
 #![feature(macro_metavar_expr_concat)]
 #![allow(unexpected_cfgs)]
 
+pub mod analytics;
 pub mod api;
 pub mod models;
 
 pub mod auth;
+pub mod bulk;
+#[cfg(feature = "campaign")]
+pub mod campaign;
 mod common;
 
 pub mod info;
+pub mod jobs;
+pub mod metrics;
 pub mod prelude;
+pub mod registry;
+pub mod reports;
+#[cfg(feature = "scale_teams")]
+pub mod scheduler;
+pub mod store;
 
 pub mod connector;
+
+pub mod diff;
+pub mod export;
+pub mod validate;
+
+#[cfg(feature = "mirror")]
+pub mod mirror;
+
+#[cfg(any(all(test, feature = "reqwest"), feature = "test-support"))]
+pub mod test_support;
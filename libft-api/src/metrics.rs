@@ -0,0 +1,217 @@
+//! Request metrics, emitted through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! With the `metrics` feature, the connector, the rate limiter and the retry loops of the crate
+//! (pagination, `fetch_many`, the job queue, campaigns) record the metrics below. Install any
+//! `metrics` recorder to collect them, e.g. `metrics-exporter-prometheus` for a sync service
+//! scraped by Prometheus. Without the feature nothing is recorded and the `metrics` crate is not
+//! built.
+//!
+//! | Name | Kind | Labels |
+//! |------|------|--------|
+//! | `ft_api_requests_total` | counter | `method`, `endpoint`, `status` |
+//! | `ft_api_request_duration_seconds` | histogram | `method`, `endpoint` |
+//! | `ft_api_received_bytes_total` | counter | `endpoint` |
+//! | `ft_api_ratelimit_wait_seconds` | histogram | |
+//! | `ft_api_retries_total` | counter | `source`, `reason` |
+//!
+//! `endpoint` is the path of the request with its ids replaced by `:id`, e.g.
+//! `users/:id/projects_users`, so the number of series stays bounded. `status` is the HTTP
+//! status code, or `error` when no response was received.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! // With `metrics-exporter-prometheus` in the dependencies of the service.
+//! metrics_exporter_prometheus::PrometheusBuilder::new()
+//!     .with_http_listener(([0, 0, 0, 0], 9000))
+//!     .install()
+//!     .unwrap();
+//! libft_api::metrics::describe();
+//! ```
+
+use std::time::Duration;
+
+use url::Url;
+
+pub const FT_METRIC_REQUESTS: &str = "ft_api_requests_total";
+pub const FT_METRIC_REQUEST_DURATION: &str = "ft_api_request_duration_seconds";
+pub const FT_METRIC_RECEIVED_BYTES: &str = "ft_api_received_bytes_total";
+pub const FT_METRIC_RATELIMIT_WAIT: &str = "ft_api_ratelimit_wait_seconds";
+pub const FT_METRIC_RETRIES: &str = "ft_api_retries_total";
+
+/// Registers the unit and description of each metric with the installed recorder.
+#[cfg(feature = "metrics")]
+pub fn describe() {
+    use ::metrics::{describe_counter, describe_histogram, Unit};
+
+    describe_counter!(
+        FT_METRIC_REQUESTS,
+        Unit::Count,
+        "Requests sent to the 42 API, by endpoint and status."
+    );
+    describe_histogram!(
+        FT_METRIC_REQUEST_DURATION,
+        Unit::Seconds,
+        "Time from sending a request to receiving its whole body."
+    );
+    describe_counter!(
+        FT_METRIC_RECEIVED_BYTES,
+        Unit::Bytes,
+        "Response bytes received from the 42 API."
+    );
+    describe_histogram!(
+        FT_METRIC_RATELIMIT_WAIT,
+        Unit::Seconds,
+        "Time requests waited for the rate limiter."
+    );
+    describe_counter!(
+        FT_METRIC_RETRIES,
+        Unit::Count,
+        "Requests sent again after a rate limit or a transient error."
+    );
+}
+
+/// The endpoint label of `url`: its path below the API root, with ids replaced by `:id`.
+///
+/// Numeric segments are ids, and so are the logins and slugs following `users` and `projects`.
+pub fn endpoint(url: &Url) -> String {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default();
+    let segments = match segments.first() {
+        Some(&"v2") => &segments[1..],
+        _ => &segments[..],
+    };
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let named_id = i == 1 && matches!(segments[0], "users" | "projects");
+            if named_id || segment.bytes().all(|b| b.is_ascii_digit()) {
+                ":id"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Records a request, `status` being `None` when no response was received.
+#[cfg(feature = "reqwest")]
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn request(
+    method: &'static str,
+    url: &Url,
+    status: Option<u16>,
+    elapsed: Duration,
+    bytes: usize,
+) {
+    #[cfg(feature = "metrics")]
+    {
+        let endpoint = endpoint(url);
+        let status = status.map_or_else(|| "error".to_string(), |status| status.to_string());
+        ::metrics::counter!(
+            FT_METRIC_REQUESTS,
+            "method" => method,
+            "endpoint" => endpoint.clone(),
+            "status" => status
+        )
+        .increment(1);
+        ::metrics::histogram!(
+            FT_METRIC_REQUEST_DURATION,
+            "method" => method,
+            "endpoint" => endpoint.clone()
+        )
+        .record(elapsed);
+        ::metrics::counter!(FT_METRIC_RECEIVED_BYTES, "endpoint" => endpoint)
+            .increment(bytes as u64);
+    }
+}
+
+/// Records the time a request waited for the rate limiter.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn ratelimit_wait(waited: Duration) {
+    #[cfg(feature = "metrics")]
+    ::metrics::histogram!(FT_METRIC_RATELIMIT_WAIT).record(waited);
+}
+
+/// Records a request sent again by `source`, e.g. `paginate`, because of `reason`
+/// (`rate_limit` or `transient`).
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn retry(source: &'static str, reason: &'static str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(FT_METRIC_RETRIES, "source" => source, "reason" => reason).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = |url: &str| endpoint(&url.parse().unwrap());
+
+        assert_eq!(
+            endpoint("https://api.intra.42.fr/v2/users/190001/projects_users?page=2"),
+            "users/:id/projects_users"
+        );
+        assert_eq!(
+            endpoint("https://api.intra.42.fr/v2/users/mockstu"),
+            "users/:id"
+        );
+        assert_eq!(
+            endpoint("https://api.intra.42.fr/v2/projects/libft/project_sessions"),
+            "projects/:id/project_sessions"
+        );
+        assert_eq!(
+            endpoint("https://api.intra.42.fr/v2/scale_teams/multiple_create"),
+            "scale_teams/multiple_create"
+        );
+        assert_eq!(
+            endpoint("http://127.0.0.1:8080/v2/campus/69/users"),
+            "campus/:id/users"
+        );
+    }
+
+    #[cfg(all(feature = "metrics", feature = "reqwest"))]
+    #[test]
+    fn records_requests() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        ::metrics::with_local_recorder(&recorder, || {
+            let url = "https://api.intra.42.fr/v2/users/1".parse().unwrap();
+            request("GET", &url, Some(200), Duration::from_millis(5), 120);
+            request("GET", &url, Some(429), Duration::from_millis(5), 20);
+            retry("paginate", "rate_limit");
+        });
+
+        let snapshot = snapshotter.snapshot().into_vec();
+        let value = |name: &str, label: Option<(&str, &str)>| {
+            snapshot
+                .iter()
+                .find(|(key, _, _, _)| {
+                    key.key().name() == name
+                        && label.is_none_or(|(k, v)| {
+                            key.key().labels().any(|l| l.key() == k && l.value() == v)
+                        })
+                })
+                .map(|(_, _, _, value)| value)
+        };
+        assert_eq!(
+            value(FT_METRIC_REQUESTS, Some(("status", "429"))),
+            Some(&DebugValue::Counter(1))
+        );
+        assert_eq!(
+            value(FT_METRIC_RECEIVED_BYTES, Some(("endpoint", "users/:id"))),
+            Some(&DebugValue::Counter(140))
+        );
+        assert_eq!(
+            value(FT_METRIC_RETRIES, None),
+            Some(&DebugValue::Counter(1))
+        );
+    }
+}
@@ -0,0 +1,624 @@
+//! Local SQLite mirror of API collections.
+//!
+//! Available with the `mirror` feature. Each collection is stored in its own table keyed by id,
+//! with the raw JSON of the record and its `updated_at`. A watermark per collection and filter
+//! records when the last sync started, so the next sync with the same filter only asks the API for
+//! records updated since then (`range[updated_at]`).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use libft_api::mirror::FtMirror;
+//! use libft_api::prelude::*;
+//!
+//! async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let mirror = FtMirror::open("ft.sqlite")?;
+//!     let synced = mirror
+//!         .sync_users(
+//!             &session,
+//!             vec![FtFilterOption::new(FtFilterField::PrimaryCampusId, vec!["69".to_string()])],
+//!         )
+//!         .await?;
+//!     println!("{synced} users updated since the last sync");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use rusqlite::{params, Connection, OptionalExtension};
+use rvstruct::ValueStruct;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::path::Path;
+use std::pin::pin;
+
+use crate::prelude::*;
+
+/// The number of records asked per page while syncing.
+pub const FT_MIRROR_PER_PAGE: u8 = 100;
+
+const WATERMARKS_TABLE: &str = "ft_mirror_watermarks";
+
+/// A model that can be stored in the mirror.
+pub trait FtMirrorRecord: Serialize + DeserializeOwned {
+    /// The table holding the collection.
+    const TABLE: &'static str;
+
+    fn mirror_id(&self) -> Option<i64>;
+
+    fn mirror_updated_at(&self) -> Option<DateTime<Utc>>;
+}
+
+impl FtMirrorRecord for FtUser {
+    const TABLE: &'static str = "users";
+
+    fn mirror_id(&self) -> Option<i64> {
+        self.id.as_ref().map(|id| i64::from(*id.value()))
+    }
+
+    fn mirror_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_ref().map(|at| *at.value())
+    }
+}
+
+impl FtMirrorRecord for FtTeam {
+    const TABLE: &'static str = "teams";
+
+    fn mirror_id(&self) -> Option<i64> {
+        Some(i64::from(self.id.0))
+    }
+
+    fn mirror_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_ref().map(|at| *at.value())
+    }
+}
+
+impl FtMirrorRecord for FtScaleTeam {
+    const TABLE: &'static str = "scale_teams";
+
+    fn mirror_id(&self) -> Option<i64> {
+        Some(i64::from(*self.id.value()))
+    }
+
+    fn mirror_updated_at(&self) -> Option<DateTime<Utc>> {
+        Some(*self.updated_at.value())
+    }
+}
+
+impl FtMirrorRecord for FtProjectsUser {
+    const TABLE: &'static str = "projects_users";
+
+    fn mirror_id(&self) -> Option<i64> {
+        Some(i64::from(self.id.0))
+    }
+
+    fn mirror_updated_at(&self) -> Option<DateTime<Utc>> {
+        Some(*self.updated_at.value())
+    }
+}
+
+#[derive(Debug)]
+pub enum FtMirrorError {
+    Sqlite(rusqlite::Error),
+    Serde(serde_json::Error),
+    Client(FtClientError),
+}
+
+impl Display for FtMirrorError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Sqlite(err) => write!(f, "Ft mirror sqlite error: {err}"),
+            Self::Serde(err) => write!(f, "Ft mirror serialization error: {err}"),
+            Self::Client(err) => write!(f, "Ft mirror client error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FtMirrorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Sqlite(err) => Some(err),
+            Self::Serde(err) => Some(err),
+            Self::Client(err) => Some(err),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for FtMirrorError {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Sqlite(err)
+    }
+}
+
+impl From<serde_json::Error> for FtMirrorError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serde(err)
+    }
+}
+
+impl From<FtClientError> for FtMirrorError {
+    fn from(err: FtClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+/// A SQLite database mirroring users, teams, scale teams and projects users.
+pub struct FtMirror {
+    conn: Connection,
+}
+
+impl FtMirror {
+    /// Opens (or creates) the mirror stored at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or its tables cannot be created.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FtMirrorError> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a mirror living in memory, mostly useful for tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tables cannot be created.
+    pub fn open_in_memory() -> Result<Self, FtMirrorError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, FtMirrorError> {
+        for table in [
+            FtUser::TABLE,
+            FtTeam::TABLE,
+            FtScaleTeam::TABLE,
+            FtProjectsUser::TABLE,
+        ] {
+            conn.execute_batch(&format!(
+                "CREATE TABLE IF NOT EXISTS {table} (
+                    id INTEGER PRIMARY KEY,
+                    updated_at TEXT,
+                    data TEXT NOT NULL
+                );"
+            ))?;
+        }
+        conn.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {WATERMARKS_TABLE} (
+                collection TEXT PRIMARY KEY,
+                synced_at TEXT NOT NULL
+            );"
+        ))?;
+
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, to run analytics queries against the mirror.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Inserts or replaces `records`, returning how many were stored.
+    ///
+    /// Records without an id are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be serialized or written.
+    pub fn upsert<R: FtMirrorRecord>(&self, records: &[R]) -> Result<usize, FtMirrorError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut stored = 0;
+        {
+            let mut stmt = tx.prepare_cached(&format!(
+                "INSERT INTO {} (id, updated_at, data) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(id) DO UPDATE SET updated_at = excluded.updated_at, data = excluded.data",
+                R::TABLE
+            ))?;
+            for record in records {
+                let Some(id) = record.mirror_id() else {
+                    continue;
+                };
+                stmt.execute(params![
+                    id,
+                    record.mirror_updated_at().map(|at| at.to_rfc3339()),
+                    serde_json::to_string(record)?,
+                ])?;
+                stored += 1;
+            }
+        }
+        tx.commit()?;
+
+        Ok(stored)
+    }
+
+    /// Reads back the record stored under `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored JSON cannot be parsed.
+    pub fn get<R: FtMirrorRecord>(&self, id: i64) -> Result<Option<R>, FtMirrorError> {
+        let data: Option<String> = self
+            .conn
+            .query_row(
+                &format!("SELECT data FROM {} WHERE id = ?1", R::TABLE),
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+    }
+
+    /// Reads back every record of the collection of `R`, e.g. to diff two snapshots.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored JSON cannot be parsed.
+    pub fn all<R: FtMirrorRecord>(&self) -> Result<Vec<R>, FtMirrorError> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("SELECT data FROM {} ORDER BY id", R::TABLE))?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+
+        let mut records = Vec::new();
+        for data in rows {
+            records.push(serde_json::from_str(&data?)?);
+        }
+        Ok(records)
+    }
+
+    /// The number of records of the collection of `R`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn count<R: FtMirrorRecord>(&self) -> Result<usize, FtMirrorError> {
+        Ok(self
+            .conn
+            .query_row(&format!("SELECT COUNT(*) FROM {}", R::TABLE), [], |row| {
+                row.get(0)
+            })?)
+    }
+
+    /// When the last successful sync of the collection of `R` within `scope` started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn watermark<R: FtMirrorRecord>(
+        &self,
+        scope: &str,
+    ) -> Result<Option<DateTime<Utc>>, FtMirrorError> {
+        let synced_at: Option<String> = self
+            .conn
+            .query_row(
+                &format!("SELECT synced_at FROM {WATERMARKS_TABLE} WHERE collection = ?1"),
+                params![watermark_key::<R>(scope)],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(synced_at
+            .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+            .map(|at| at.to_utc()))
+    }
+
+    fn set_watermark<R: FtMirrorRecord>(
+        &self,
+        scope: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), FtMirrorError> {
+        self.conn.execute(
+            &format!(
+                "INSERT INTO {WATERMARKS_TABLE} (collection, synced_at) VALUES (?1, ?2)
+                 ON CONFLICT(collection) DO UPDATE SET synced_at = excluded.synced_at"
+            ),
+            params![watermark_key::<R>(scope), at.to_rfc3339()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Fetches every page of records updated since the watermark of `scope` and stores them.
+    ///
+    /// `scope` tells apart the syncs of a collection fetching different records, e.g. one per
+    /// campus: each has its own watermark, so a sync of one campus does not skip the records of
+    /// another. The `sync_*` methods use their filter as scope.
+    ///
+    /// `fetch` is called with the page number and the `updated_at` range to apply, until it
+    /// returns fewer than `FT_MIRROR_PER_PAGE` records; a rate-limited page is fetched again once
+    /// the delay is over. The watermark only moves once every page has been stored, so an
+    /// interrupted sync is retried from the previous watermark.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or stored.
+    pub async fn sync<R, F, Fut>(&self, scope: &str, mut fetch: F) -> Result<usize, FtMirrorError>
+    where
+        R: FtMirrorRecord,
+        F: FnMut(u16, Option<Vec<FtRangeOption>>) -> Fut,
+        Fut: Future<Output = ClientResult<Vec<R>>>,
+    {
+        let since = self.watermark::<R>(scope)?;
+        let started_at = Utc::now();
+        let range = since.map(|since| {
+            vec![FtRangeOption::new(
+                FtRangeField::UpdatedAt,
+                vec![since.to_rfc3339(), started_at.to_rfc3339()],
+            )]
+        });
+
+        let mut records = pin!(
+            paginate(FT_MIRROR_PER_PAGE, |page| { fetch(page, range.clone()) })
+                .chunks(usize::from(FT_MIRROR_PER_PAGE))
+        );
+        let mut synced = 0;
+        while let Some(page) = records.next().await {
+            synced += self.upsert(&page.into_iter().collect::<ClientResult<Vec<_>>>()?)?;
+        }
+        self.set_watermark::<R>(scope, started_at)?;
+
+        Ok(synced)
+    }
+
+    /// Syncs the users matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or stored.
+    pub async fn sync_users<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        filter: Vec<FtFilterOption>,
+    ) -> Result<usize, FtMirrorError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        self.sync(&filter_scope(&filter), |page, range| {
+            let mut req = FtApiUsersRequest::new()
+                .with_filter(filter.clone())
+                .with_page(usize::from(page))
+                .with_per_page(FT_MIRROR_PER_PAGE);
+            req.range = range;
+            async move { session.users(req).await.map(|res| res.users) }
+        })
+        .await
+    }
+
+    /// Syncs the teams matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or stored.
+    pub async fn sync_teams<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        filter: Vec<FtFilterOption>,
+    ) -> Result<usize, FtMirrorError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        self.sync(&filter_scope(&filter), |page, range| {
+            let mut req = FtApiTeamsRequest::new()
+                .with_filter(filter.clone())
+                .with_page(page)
+                .with_per_page(FT_MIRROR_PER_PAGE);
+            req.range = range;
+            async move { session.teams(req).await.map(|res| res.teams) }
+        })
+        .await
+    }
+
+    /// Syncs the scale teams matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or stored.
+    pub async fn sync_scale_teams<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        filter: Vec<FtFilterOption>,
+    ) -> Result<usize, FtMirrorError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        self.sync(&filter_scope(&filter), |page, range| {
+            let mut req = FtApiScaleTeamsRequest::new()
+                .with_filter(filter.clone())
+                .with_page(page)
+                .with_per_page(FT_MIRROR_PER_PAGE);
+            req.range = range;
+            async move { session.scale_teams(req).await.map(|res| res.scale_teams) }
+        })
+        .await
+    }
+
+    /// Syncs the projects users matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or stored.
+    pub async fn sync_projects_users<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        filter: Vec<FtFilterOption>,
+    ) -> Result<usize, FtMirrorError>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        self.sync(&filter_scope(&filter), |page, range| {
+            let mut req = FtApiProjectsUsersRequest::new()
+                .with_filter(filter.clone())
+                .with_page(page)
+                .with_per_page(FT_MIRROR_PER_PAGE);
+            req.range = range;
+            async move {
+                session
+                    .projects_users(req)
+                    .await
+                    .map(|res| res.projects_users)
+            }
+        })
+        .await
+    }
+}
+
+/// The key of the watermark of the collection of `R` within `scope`.
+fn watermark_key<R: FtMirrorRecord>(scope: &str) -> String {
+    if scope.is_empty() {
+        R::TABLE.to_string()
+    } else {
+        format!("{}?{scope}", R::TABLE)
+    }
+}
+
+/// The scope of the syncs restricted by `filter`, its query parameters in a stable order, e.g.
+/// `filter[primary_campus_id]=69`.
+fn filter_scope(filter: &[FtFilterOption]) -> String {
+    let filter = filter
+        .iter()
+        .map(|option| {
+            let mut value = option.value.clone();
+            value.sort();
+            FtFilterOption::new(option.field.clone(), value)
+        })
+        .collect();
+    let mut params = convert_filter_option_to_tuple(filter)
+        .unwrap()
+        .into_iter()
+        .map(|(key, value)| format!("{key}={}", value.unwrap_or_default()))
+        .collect::<Vec<_>>();
+    params.sort();
+    params.join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: i32, login: &str) -> FtUser {
+        FtUser::new()
+            .with_id(FtUserId::new(id))
+            .with_login(FtLoginId::new(login.to_string()))
+    }
+
+    #[test]
+    fn upsert_and_get() {
+        let mirror = FtMirror::open_in_memory().unwrap();
+
+        mirror.upsert(&[user(1, "hdoo"), FtUser::new()]).unwrap();
+        mirror
+            .upsert(&[user(1, "yondoo"), user(2, "taejikim")])
+            .unwrap();
+
+        assert_eq!(mirror.count::<FtUser>().unwrap(), 2);
+        assert_eq!(
+            mirror.get::<FtUser>(1).unwrap().unwrap().login,
+            Some(FtLoginId::new("yondoo".to_string()))
+        );
+        assert!(mirror.get::<FtUser>(3).unwrap().is_none());
+        assert_eq!(mirror.all::<FtUser>().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sync_moves_watermark() {
+        let mirror = FtMirror::open_in_memory().unwrap();
+        assert!(mirror.watermark::<FtUser>("").unwrap().is_none());
+
+        let mut ranges = Vec::new();
+        let synced = mirror
+            .sync("", |page, range| {
+                ranges.push(range);
+                let users = if page == 1 {
+                    (0..i32::from(FT_MIRROR_PER_PAGE))
+                        .map(|id| user(id, "user"))
+                        .collect()
+                } else {
+                    vec![user(1_000, "last")]
+                };
+                async move { Ok(users) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(synced, usize::from(FT_MIRROR_PER_PAGE) + 1);
+        assert_eq!(ranges, vec![None, None]);
+        let watermark = mirror.watermark::<FtUser>("").unwrap();
+        assert!(watermark.is_some());
+
+        let mut ranges = Vec::new();
+        mirror
+            .sync("", |_, range| {
+                ranges.push(range);
+                async move { Ok(Vec::<FtUser>::new()) }
+            })
+            .await
+            .unwrap();
+
+        let range = ranges[0].as_ref().unwrap();
+        assert_eq!(range[0].range, FtRangeField::UpdatedAt);
+        assert_eq!(range[0].value[0], watermark.unwrap().to_rfc3339());
+    }
+
+    #[tokio::test]
+    async fn sync_retries_rate_limited_pages() {
+        let mirror = FtMirror::open_in_memory().unwrap();
+
+        let mut calls = 0;
+        let synced = mirror
+            .sync("", |_, _| {
+                calls += 1;
+                let res = if calls == 1 {
+                    Err(FtClientError::RateLimitError(
+                        FtRateLimitError::new().with_retry_after(std::time::Duration::ZERO),
+                    ))
+                } else {
+                    Ok(vec![user(1, "hdoo")])
+                };
+                async move { res }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!((synced, calls), (1, 2));
+        assert!(mirror.watermark::<FtUser>("").unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn watermarks_are_kept_per_filter() {
+        let mirror = FtMirror::open_in_memory().unwrap();
+        let campus = |ids: &[&str]| {
+            vec![FtFilterOption::new(
+                FtFilterField::PrimaryCampusId,
+                ids.iter().map(ToString::to_string).collect(),
+            )]
+        };
+        assert_eq!(
+            filter_scope(&campus(&["70", "69"])),
+            "filter[primary_campus_id]=69,70"
+        );
+
+        let gyeongsan = filter_scope(&campus(&["69"]));
+        mirror
+            .sync(&gyeongsan, |_, _| async move { Ok(Vec::<FtUser>::new()) })
+            .await
+            .unwrap();
+        assert!(mirror.watermark::<FtUser>(&gyeongsan).unwrap().is_some());
+
+        let mut ranges = Vec::new();
+        mirror
+            .sync(&filter_scope(&campus(&["29"])), |_, range| {
+                ranges.push(range);
+                async move { Ok(Vec::<FtUser>::new()) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(ranges, vec![None]);
+        assert!(mirror.watermark::<FtUser>("").unwrap().is_none());
+    }
+}
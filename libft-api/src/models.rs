@@ -29,28 +29,54 @@
 //! ```
 
 pub mod achievement;
+pub mod app;
+pub mod attachment;
+pub mod bloc;
+pub mod broadcast;
 pub mod campus;
 pub mod campus_user;
+pub mod close;
+pub mod coalition;
+pub mod community_service;
 pub mod correction_point_history;
 pub mod cursus_user;
 pub mod datetime;
+pub mod evaluation;
+pub mod event;
 pub mod exam;
+pub mod experience;
 pub mod feedback;
 pub mod flag;
+pub mod gitlab_user;
 pub mod group;
 pub mod image;
 pub mod journals;
 pub mod language;
+pub mod level;
 pub mod locations;
+pub mod note;
+pub mod notion;
+pub mod offer;
+pub mod pool;
+pub mod product;
 pub mod project;
 pub mod project_data;
 pub mod project_session;
+pub mod project_sessions_rule;
 pub mod projects_users;
+pub mod quest;
 pub mod role;
 pub mod scale;
 pub mod scale_teams;
+pub mod skill;
+pub mod slot;
+pub mod tag;
 pub mod team;
 pub mod title;
+pub mod transaction;
+pub mod translation;
 pub mod user;
+pub mod user_candidature;
+pub mod waitlist;
 
 pub mod prelude;
@@ -44,3 +44,37 @@ pub struct FtAchievement {
     pub nbr_of_success: Option<FtAchievementNbrOfSuccess>,
     pub users_url: Option<FtAchievementUsersUrl>,
 }
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtAchievementsUserId(pub u64);
+
+/// The link between a user and an achievement they unlocked.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtAchievementsUser {
+    pub id: FtAchievementsUserId,
+    pub achievement_id: FtAchievementId,
+    pub user_id: FtUserId,
+    pub login: Option<FtLoginId>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 1234567,
+  "achievement_id": 45,
+  "user_id": 123456,
+  "login": "hdoo",
+  "created_at": "2024-02-11T09:12:03.517Z",
+  "updated_at": "2024-02-11T09:12:03.517Z"
+}"#;
+
+        let achievements_user = serde_json::from_str::<FtAchievementsUser>(raw).unwrap();
+        assert_eq!(achievements_user.achievement_id, FtAchievementId::new(45));
+    }
+}
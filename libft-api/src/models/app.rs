@@ -0,0 +1,60 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// Requests per second allowed to an application; the intra only reports the hourly limit.
+pub const FT_APP_DEFAULT_SECONDLY_RATE_LIMIT: u64 = 2;
+
+/// An OAuth application registered on the intra.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtApp {
+    pub id: FtAppId,
+    pub name: String,
+    pub description: Option<String>,
+    pub website: Option<String>,
+    pub public: Option<bool>,
+    pub scopes: Vec<String>,
+    /// The number of requests per hour allowed to the application.
+    pub rate_limit: Option<u64>,
+    pub roles: Option<Vec<FtRole>>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+impl FtApp {
+    /// Returns the `(secondly, hourly)` limits of the application, as taken by
+    /// `FtClient::with_ratelimits`, or `None` when the intra did not report a limit.
+    pub fn ratelimits(&self) -> Option<(u64, u64)> {
+        self.rate_limit
+            .map(|hourly| (FT_APP_DEFAULT_SECONDLY_RATE_LIMIT, hourly))
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtAppId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  {
+    "id": 36721,
+    "name": "libft-api",
+    "description": "Campus tooling",
+    "website": null,
+    "public": false,
+    "scopes": ["public", "projects", "profile"],
+    "rate_limit": 1200,
+    "roles": [{ "id": 1, "name": "Official App" }],
+    "created_at": "2024-04-11T06:21:43.551Z",
+    "updated_at": "2024-04-11T06:21:43.551Z"
+  }
+]"#;
+
+        let apps = serde_json::from_str::<Vec<FtApp>>(raw).unwrap();
+        assert_eq!(apps[0].ratelimits(), Some((2, 1200)));
+    }
+}
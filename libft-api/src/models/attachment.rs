@@ -0,0 +1,25 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A file or video attached to a project or a subnotion, e.g. a subject PDF.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtAttachment {
+    pub id: FtAttachmentId,
+    pub base_id: Option<i32>,
+    pub name: String,
+    pub slug: Option<String>,
+    pub url: Option<FtUrl>,
+    pub kind: Option<FtAttachmentKind>,
+    pub language: Option<FtLanguage>,
+    pub thumb_url: Option<FtUrl>,
+    pub pdf_thumb_url: Option<FtUrl>,
+    pub created_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtAttachmentId(pub i32);
+
+/// The kind of an attachment, e.g. `pdf`, `video`, `link`.
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtAttachmentKind(pub String);
@@ -0,0 +1,50 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A group of coalitions competing against each other on a campus and cursus.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtBloc {
+    pub id: FtBlocId,
+    pub campus_id: FtCampusId,
+    pub cursus_id: FtCursusId,
+    pub squad_size: Option<i32>,
+    pub coalitions: Option<Vec<FtCoalition>>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtBlocId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r##"{
+  "id": 72,
+  "campus_id": 69,
+  "cursus_id": 21,
+  "squad_size": null,
+  "created_at": "2023-03-02T01:25:33.231Z",
+  "updated_at": "2023-03-02T01:25:33.231Z",
+  "coalitions": [
+    {
+      "id": 310,
+      "name": "Gun",
+      "slug": "gyeongsan-gun",
+      "image_url": "https://cdn.intra.42.fr/coalition/image/310/gun.svg",
+      "cover_url": "https://cdn.intra.42.fr/coalition/cover/310/gun.jpg",
+      "color": "#4180db",
+      "score": 12840,
+      "user_id": 123456
+    }
+  ]
+}"##;
+
+        let bloc = serde_json::from_str::<FtBloc>(raw).unwrap();
+        assert_eq!(bloc.coalitions.unwrap().len(), 1);
+    }
+}
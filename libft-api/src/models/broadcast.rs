@@ -0,0 +1,18 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A message broadcast to the users of a campus.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtBroadcast {
+    pub id: FtBroadcastId,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub campus_id: Option<FtCampusId>,
+    pub user_id: Option<FtUserId>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtBroadcastId(pub i32);
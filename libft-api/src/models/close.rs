@@ -0,0 +1,61 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// The closing of a user account, with the reason and the staff member who closed it.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtClose {
+    pub id: FtCloseId,
+    pub reason: Option<String>,
+    pub state: Option<FtCloseState>,
+    pub kind: Option<FtCloseKind>,
+    pub user: Option<FtUser>,
+    pub closer: Option<FtUser>,
+    pub community_services: Option<Vec<FtCommunityService>>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtCloseId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCloseKind {
+    Agu,
+    BlackHoled,
+    Deserter,
+    NonAdmitted,
+    SeriousMisconduct,
+    SocialSecurity,
+    Other,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCloseState {
+    Close,
+    Unclose,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 51422,
+  "reason": "Left the piscine before the end",
+  "state": "close",
+  "kind": "deserter",
+  "community_services": [],
+  "created_at": "2024-04-29T13:20:11.874Z",
+  "updated_at": "2024-04-29T13:20:11.874Z"
+}"#;
+
+        let close = serde_json::from_str::<FtClose>(raw).unwrap();
+        assert_eq!(close.kind, Some(FtCloseKind::Deserter));
+        assert_eq!(close.state, Some(FtCloseState::Close));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtCoalition {
+    pub id: FtCoalitionId,
+    pub name: String,
+    pub slug: Option<String>,
+    pub image_url: Option<FtUrl>,
+    pub cover_url: Option<FtUrl>,
+    pub color: Option<String>,
+    pub score: Option<i64>,
+    pub user_id: Option<FtUserId>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtCoalitionsUser {
+    pub id: FtCoalitionsUserId,
+    pub coalition_id: FtCoalitionId,
+    pub user_id: FtUserId,
+    pub score: Option<i64>,
+    pub rank: Option<i32>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+/// Points awarded to (or, when negative, removed from) a coalition.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtScore {
+    pub id: FtScoreId,
+    pub coalition_id: FtCoalitionId,
+    pub scoreable_id: Option<i64>,
+    pub scoreable_type: Option<String>,
+    pub coalitions_user_id: Option<FtCoalitionsUserId>,
+    pub calculation_id: Option<i64>,
+    pub value: i32,
+    pub reason: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtCoalitionId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtCoalitionsUserId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtScoreId(pub i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r##"{
+  "id": 310,
+  "name": "Gun",
+  "slug": "gyeongsan-gun",
+  "image_url": "https://cdn.intra.42.fr/coalition/image/310/gun.svg",
+  "cover_url": "https://cdn.intra.42.fr/coalition/cover/310/gun.jpg",
+  "color": "#4180db",
+  "score": 12840,
+  "user_id": 123456
+}"##;
+
+        let coalition = serde_json::from_str::<FtCoalition>(raw).unwrap();
+        assert_eq!(coalition.id, FtCoalitionId::new(310));
+        assert_eq!(coalition.score, Some(12840));
+    }
+}
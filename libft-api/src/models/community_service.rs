@@ -0,0 +1,51 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A community service (TIG) a user has to perform after a close.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtCommunityService {
+    pub id: FtCommunityServiceId,
+    pub close_id: Option<FtCloseId>,
+    /// The duration of the service, in seconds.
+    pub duration: i32,
+    pub schedule_at: Option<FtDateTimeUtc>,
+    pub occupation: Option<String>,
+    pub state: FtCommunityServiceState,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtCommunityServiceId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtCommunityServiceState {
+    Open,
+    Scheduled,
+    Validated,
+    Invalidated,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 18234,
+  "close_id": 51422,
+  "duration": 7200,
+  "schedule_at": "2024-05-02T08:00:00.000Z",
+  "occupation": "Cleaning the cluster keyboards",
+  "state": "scheduled",
+  "created_at": "2024-04-29T13:21:45.104Z",
+  "updated_at": "2024-04-29T13:21:45.104Z"
+}"#;
+
+        let community_service = serde_json::from_str::<FtCommunityService>(raw).unwrap();
+        assert_eq!(community_service.state, FtCommunityServiceState::Scheduled);
+    }
+}
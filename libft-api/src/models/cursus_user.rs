@@ -44,7 +44,7 @@ pub struct FtSkillName(String);
 #[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtSkillLevel(f64);
 
-#[cfg(test)]
+#[cfg(all(test, feature = "users"))]
 mod tests {
     use crate::api::prelude::*;
 
@@ -0,0 +1,29 @@
+use crate::models::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An evaluation requirement configured on a project session.
+///
+/// `kind` is `scale` for peer evaluations, the number of correctors being the
+/// `correction_number` of the scales sharing this `evaluation_id`.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtEvaluation {
+    pub id: FtEvaluationId,
+    pub kind: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  { "id": 2996, "kind": "scale", "created_at": "2019-07-29T08:45:17.896Z", "updated_at": "2019-07-29T08:45:17.896Z" },
+  { "id": 2997, "kind": "moulinette", "created_at": "2019-07-29T08:45:17.896Z", "updated_at": "2019-07-29T08:45:17.896Z" }
+]"#;
+
+        serde_json::from_str::<Vec<FtEvaluation>>(raw).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtEvent {
+    pub id: FtEventId,
+    pub name: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub kind: Option<FtEventKind>,
+    pub max_people: Option<i32>,
+    pub nbr_subscribers: Option<i32>,
+    pub begin_at: FtDateTimeUtc,
+    pub end_at: FtDateTimeUtc,
+    pub campus_ids: Option<Vec<FtCampusId>>,
+    pub cursus_ids: Option<Vec<FtCursusId>>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtEventsUser {
+    pub id: FtEventsUserId,
+    pub event_id: FtEventId,
+    pub user_id: FtUserId,
+    pub user: Option<FtUser>,
+    pub event: Option<FtEvent>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtEventId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtEventsUserId(pub i32);
+
+/// The kind of an event, e.g. `event`, `meet_up`, `conference`, `hackathon`, `workshop`, `association`.
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtEventKind(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 31542,
+  "name": "Welcome breakfast",
+  "description": "Meet the new cohort",
+  "location": "Cluster 1",
+  "kind": "association",
+  "max_people": 80,
+  "nbr_subscribers": 12,
+  "begin_at": "2025-03-03T00:30:00.000Z",
+  "end_at": "2025-03-03T01:30:00.000Z",
+  "campus_ids": [69],
+  "cursus_ids": [21],
+  "themes": [],
+  "waitlist": null,
+  "prohibition_of_cancellation": null,
+  "created_at": "2025-02-20T04:11:43.451Z",
+  "updated_at": "2025-02-20T04:11:43.451Z"
+}"#;
+
+        serde_json::from_str::<FtEvent>(raw).unwrap();
+    }
+}
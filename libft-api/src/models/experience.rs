@@ -0,0 +1,21 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// Experience points granted to a user in a cursus.
+///
+/// The intra spells the polymorphic source `experiancable`; the field names follow it.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtExperience {
+    pub id: FtExperienceId,
+    pub user_id: FtUserId,
+    pub cursus_id: Option<FtCursusId>,
+    pub skill_id: Option<FtSkillId>,
+    pub experiancable_id: Option<i64>,
+    pub experiancable_type: Option<String>,
+    pub amount: i64,
+    pub created_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtExperienceId(pub i64);
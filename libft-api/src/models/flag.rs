@@ -1,9 +1,10 @@
 use crate::models::prelude::*;
+use rvstruct::ValueStruct;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct FtFlag {
-    pub id: i8,
+    pub id: FtFlagId,
     pub name: FtFlagName,
     pub positive: bool,
     pub icon: String,
@@ -11,6 +12,9 @@ pub struct FtFlag {
     pub updated_at: FtDateTimeUtc,
 }
 
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtFlagId(i8);
+
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
 pub struct FtFlagName(String);
 
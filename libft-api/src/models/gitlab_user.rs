@@ -0,0 +1,44 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// The link between an intra user and their account on the vogsphere gitlab.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtGitlabUser {
+    pub id: FtGitlabUserId,
+    pub user_id: FtUserId,
+    pub gitlab_id: Option<i64>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtGitlabUserId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  {
+    "id": 187654,
+    "user_id": 212750,
+    "gitlab_id": 204311,
+    "created_at": "2024-04-11T06:21:43.551Z",
+    "updated_at": "2024-04-11T06:21:43.551Z"
+  },
+  {
+    "id": 187655,
+    "user_id": 174083,
+    "gitlab_id": null,
+    "created_at": "2024-04-11T06:21:43.551Z",
+    "updated_at": "2024-04-11T06:21:43.551Z"
+  }
+]"#;
+
+        let gitlab_users = serde_json::from_str::<Vec<FtGitlabUser>>(raw).unwrap();
+        assert_eq!(gitlab_users[1].gitlab_id, None);
+    }
+}
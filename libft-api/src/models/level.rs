@@ -0,0 +1,37 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A row of the XP table of a cursus: the experience needed to reach `lvl`.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtLevel {
+    pub id: FtLevelId,
+    pub lvl: i32,
+    pub xp: i64,
+    pub cursus_id: Option<FtCursusId>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtLevelId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 352,
+  "lvl": 10,
+  "xp": 78880,
+  "cursus_id": 21,
+  "created_at": "2019-07-29T08:45:17.896Z",
+  "updated_at": "2019-07-29T08:45:17.896Z"
+}"#;
+
+        let level = serde_json::from_str::<FtLevel>(raw).unwrap();
+        assert_eq!((level.lvl, level.xp), (10, 78880));
+    }
+}
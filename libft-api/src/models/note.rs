@@ -0,0 +1,24 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// An administrative note left on a user's profile.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtNote {
+    pub id: FtNoteId,
+    pub user_id: FtUserId,
+    pub from_user_id: Option<FtUserId>,
+    pub subject: String,
+    pub content: String,
+    pub kind: Option<FtNoteKind>,
+    pub campus_id: Option<FtCampusId>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtNoteId(pub i32);
+
+/// The kind of a note, e.g. `info`, `warning`.
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtNoteKind(pub String);
@@ -0,0 +1,73 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// An e-learning notion, made of subnotions.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtNotion {
+    pub id: FtNotionId,
+    pub name: String,
+    pub slug: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub subnotions: Option<Vec<FtSubnotion>>,
+    pub tags: Option<Vec<FtTag>>,
+}
+
+/// A chapter of a notion, with its videos and attachments.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtSubnotion {
+    pub id: FtSubnotionId,
+    pub name: String,
+    pub slug: Option<String>,
+    pub notepad: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub attachments: Option<Vec<FtAttachment>>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtNotionId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtSubnotionId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 412,
+  "name": "Pointers",
+  "slug": "pointers",
+  "notepad": null,
+  "created_at": "2016-10-03T12:10:05.233Z",
+  "attachments": [
+    {
+      "id": 1829,
+      "base_id": 1829,
+      "name": "Pointers - part 1",
+      "slug": "pointers-part-1",
+      "url": "https://cdn.intra.42.fr/video/video/1829/pointers_part_1.mp4",
+      "kind": "video",
+      "language": {
+        "id": 2,
+        "name": "English",
+        "identifier": "en",
+        "created_at": "2015-04-14T16:07:38.122Z",
+        "updated_at": "2024-11-04T08:52:20.184Z"
+      },
+      "thumb_url": "https://cdn.intra.42.fr/video/thumb/1829/pointers_part_1.jpg",
+      "pdf_thumb_url": null,
+      "created_at": "2016-10-03T12:10:05.233Z"
+    }
+  ]
+}"#;
+
+        let subnotion = serde_json::from_str::<FtSubnotion>(raw).unwrap();
+        assert_eq!(
+            subnotion.attachments.unwrap()[0].kind,
+            Some(FtAttachmentKind::new("video".to_string()))
+        );
+    }
+}
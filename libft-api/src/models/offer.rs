@@ -0,0 +1,61 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A job or internship offer published on the campus job board.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtOffer {
+    pub id: FtOfferId,
+    pub title: String,
+    pub little_description: Option<String>,
+    pub big_description: Option<String>,
+    pub salary: Option<String>,
+    pub contract_type: Option<FtOfferContractType>,
+    pub address: Option<String>,
+    pub email: Option<String>,
+    pub slot: Option<i32>,
+    pub valid_at: Option<FtDateTimeUtc>,
+    pub invalid_at: Option<FtDateTimeUtc>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtOfferContractType {
+    FullTime,
+    PartTime,
+    Internship,
+    Freelance,
+    Apprenticeship,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtOfferId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 4512,
+  "title": "Backend developer intern",
+  "little_description": "Six month internship",
+  "big_description": "Rust and PostgreSQL on a payments platform.",
+  "salary": "2000",
+  "contract_type": "internship",
+  "address": "Gyeongsan, South Korea",
+  "email": "jobs@example.com",
+  "slot": 2,
+  "valid_at": "2024-04-01T00:00:00.000Z",
+  "invalid_at": "2024-06-30T00:00:00.000Z",
+  "created_at": "2024-03-28T06:21:43.551Z",
+  "updated_at": "2024-03-28T06:21:43.551Z"
+}"#;
+
+        let offer = serde_json::from_str::<FtOffer>(raw).unwrap();
+        assert_eq!(offer.contract_type, Some(FtOfferContractType::Internship));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A pool of a campus and cursus, holding the points given back by the piscine cohorts.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtPool {
+    pub id: FtPoolId,
+    pub current_points: i64,
+    pub max_points: i64,
+    pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtPoolId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  {
+    "id": 44,
+    "current_points": 1820,
+    "max_points": 5000,
+    "cursus_id": 21,
+    "campus_id": 69
+  }
+]"#;
+
+        let pools = serde_json::from_str::<Vec<FtPool>>(raw).unwrap();
+        assert_eq!(pools[0].id, FtPoolId::new(44));
+    }
+}
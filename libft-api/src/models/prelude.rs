@@ -1,24 +1,50 @@
 pub use super::achievement::*;
+pub use super::app::*;
+pub use super::attachment::*;
+pub use super::bloc::*;
+pub use super::broadcast::*;
 pub use super::campus::*;
 pub use super::campus_user::*;
+pub use super::close::*;
+pub use super::coalition::*;
+pub use super::community_service::*;
 pub use super::correction_point_history::*;
 pub use super::cursus_user::*;
 pub use super::datetime::*;
+pub use super::evaluation::*;
+pub use super::event::*;
 pub use super::exam::*;
+pub use super::experience::*;
 pub use super::feedback::*;
 pub use super::flag::*;
+pub use super::gitlab_user::*;
 pub use super::group::*;
 pub use super::image::*;
 pub use super::journals::*;
 pub use super::language::*;
+pub use super::level::*;
 pub use super::locations::*;
+pub use super::note::*;
+pub use super::notion::*;
+pub use super::offer::*;
+pub use super::pool::*;
+pub use super::product::*;
 pub use super::project::*;
 pub use super::project_data::*;
 pub use super::project_session::*;
+pub use super::project_sessions_rule::*;
 pub use super::projects_users::*;
+pub use super::quest::*;
 pub use super::role::*;
 pub use super::scale::*;
 pub use super::scale_teams::*;
+pub use super::skill::*;
+pub use super::slot::*;
+pub use super::tag::*;
 pub use super::team::*;
 pub use super::title::*;
+pub use super::transaction::*;
+pub use super::translation::*;
 pub use super::user::*;
+pub use super::user_candidature::*;
+pub use super::waitlist::*;
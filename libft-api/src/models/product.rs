@@ -0,0 +1,64 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// An item of the campus shop, paid with wallet points.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtProduct {
+    pub id: FtProductId,
+    pub name: String,
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    pub price: i32,
+    /// The remaining stock, `None` when unlimited.
+    pub quantity: Option<i32>,
+    pub is_uniq: Option<bool>,
+    pub one_time_purchase: Option<bool>,
+    pub campus_id: Option<FtCampusId>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+/// The purchase of a product by a user.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtCommand {
+    pub id: FtCommandId,
+    pub product_id: FtProductId,
+    pub user_id: Option<FtUserId>,
+    pub user: Option<FtUser>,
+    pub comment: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtProductId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtCommandId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 412,
+  "name": "42 Hoodie",
+  "slug": "42-hoodie-gyeongsan",
+  "description": "Black hoodie with the campus logo.",
+  "price": 300,
+  "quantity": null,
+  "is_uniq": false,
+  "one_time_purchase": false,
+  "campus_id": 69,
+  "created_at": "2024-03-04T02:11:53.214Z",
+  "updated_at": "2024-03-04T02:11:53.214Z"
+}"#;
+
+        let product = serde_json::from_str::<FtProduct>(raw).unwrap();
+        assert_eq!(product.price, 300);
+        assert_eq!(product.quantity, None);
+    }
+}
@@ -49,6 +49,12 @@ pub struct FtParentProject {
 #[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct FtVideo {}
 
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum FtProjectIdentifier {
+    Slug(FtSlug),
+    ProjectId(FtProjectId),
+}
+
 // Newtype structs for various IDs and other specific fields
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtProjectId(pub i32);
@@ -61,3 +67,30 @@ pub struct FtSlug(pub String);
 
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtCursusId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser_graph() {
+        let raw = r#"{
+  "id": 1331,
+  "name": "ft_printf",
+  "slug": "42cursus-ft_printf",
+  "difficulty": 882,
+  "parent": null,
+  "children": [],
+  "exam": false,
+  "git_id": null,
+  "repository": null,
+  "created_at": "2019-11-04T12:39:58.374Z",
+  "updated_at": "2024-10-01T09:42:19.003Z"
+}"#;
+
+        let project = serde_json::from_str::<FtProject>(raw).unwrap();
+        assert_eq!(project.difficulty, Some(882));
+        assert!(project.parent.is_none());
+        assert!(project.children.unwrap().is_empty());
+    }
+}
@@ -62,6 +62,7 @@ pub struct FtMimeType(String);
 #[derive(Debug, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtProjectSessionId(pub u16);
 
+/// Project session ids captured from one campus; resolve others with `FtProjectRegistry`.
 pub mod ft_project_session_ids {
     pub mod ft_cursus {
         pub mod inner {
@@ -0,0 +1,81 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtProjectSessionsRule {
+    pub id: FtProjectSessionsRuleId,
+    pub required: Option<bool>,
+    pub position: Option<i32>,
+    pub params: Option<Vec<FtParamsProjectSessionsRule>>,
+    pub rule: Option<FtRule>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtRule {
+    pub id: FtRuleId,
+    pub kind: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub slug: Option<FtSlug>,
+    pub internal_name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtParamsProjectSessionsRule {
+    pub id: FtParamsProjectSessionsRuleId,
+    pub param_id: Option<i32>,
+    pub project_sessions_rule_id: Option<FtProjectSessionsRuleId>,
+    pub value: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtProjectSessionsRuleId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtRuleId(pub i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtParamsProjectSessionsRuleId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  {
+    "id": 4021,
+    "required": true,
+    "position": 1,
+    "params": [
+      {
+        "id": 5120,
+        "param_id": 8,
+        "project_sessions_rule_id": 4021,
+        "value": "2",
+        "created_at": "2023-05-02T07:11:43.451Z",
+        "updated_at": "2023-05-02T07:11:43.451Z"
+      }
+    ],
+    "rule": {
+      "id": 12,
+      "kind": "inscription",
+      "name": "Group size",
+      "description": "The group must have between %{min} and %{max} members",
+      "slug": "group-size",
+      "internal_name": "GroupSize"
+    }
+  }
+]"#;
+
+        let rules = serde_json::from_str::<Vec<FtProjectSessionsRule>>(raw).unwrap();
+        assert_eq!(
+            rules[0].params.as_ref().unwrap()[0].value.as_deref(),
+            Some("2")
+        );
+    }
+}
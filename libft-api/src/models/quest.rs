@@ -0,0 +1,49 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A set of projects to validate, e.g. the common core milestones.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtQuest {
+    pub id: FtQuestId,
+    pub name: String,
+    pub slug: Option<String>,
+    pub kind: Option<String>,
+    pub internal_name: Option<String>,
+    pub description: Option<String>,
+    pub cursus_id: Option<FtCursusId>,
+    pub campus_id: Option<FtCampusId>,
+    pub grade_id: Option<i32>,
+    pub position: Option<i32>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtQuestId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 37,
+  "name": "Common Core Rank 02",
+  "slug": "common-core-rank-02",
+  "kind": "main",
+  "internal_name": "Common Core Rank 02",
+  "description": "Rank 02",
+  "cursus_id": 21,
+  "campus_id": null,
+  "created_at": "2019-07-29T08:10:08.124Z",
+  "updated_at": "2019-07-29T08:10:08.124Z",
+  "grade_id": null,
+  "position": 3
+}"#;
+
+        let quest = serde_json::from_str::<FtQuest>(raw).unwrap();
+        assert_eq!(quest.cursus_id, Some(FtCursusId::new(21)));
+    }
+}
@@ -19,6 +19,26 @@ pub struct FtScale {
     pub free: Option<bool>,
     pub flags: Option<Vec<FtFlag>>,
     pub languages: Option<Vec<FtLanguage>>,
+    pub sections: Option<Vec<FtScaleSection>>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtScaleSection {
+    pub id: FtScaleSectionId,
+    pub name: Option<String>,
+    pub position: Option<i32>,
+    pub description: Option<String>,
+    pub questions: Option<Vec<FtScaleQuestion>>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtScaleQuestion {
+    pub id: FtScaleQuestionId,
+    pub name: Option<String>,
+    pub position: Option<i32>,
+    pub guidelines: Option<String>,
+    pub rating: Option<String>,
+    pub kind: Option<String>,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
@@ -27,6 +47,12 @@ pub struct FtScaleId(i32);
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtEvaluationId(i32);
 
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtScaleSectionId(i32);
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtScaleQuestionId(i32);
+
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtScaleName(pub String);
 
@@ -56,3 +82,45 @@ pub struct FtScaleFlag {
     pub name: String,
     pub positive: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 45833,
+  "evaluation_id": 2996,
+  "name": "scale 7",
+  "is_primary": true,
+  "correction_number": 3,
+  "duration": 900,
+  "free": false,
+  "sections": [
+    {
+      "id": 187634,
+      "name": "Mandatory part",
+      "position": 1,
+      "description": "",
+      "questions": [
+        {
+          "id": 712345,
+          "name": "Norminette",
+          "position": 1,
+          "guidelines": "Run norminette on the repository.",
+          "rating": "bool",
+          "kind": "standard"
+        }
+      ]
+    }
+  ]
+}"#;
+
+        let scale = serde_json::from_str::<FtScale>(raw).unwrap();
+        assert_eq!(
+            scale.sections.unwrap()[0].questions.as_ref().unwrap().len(),
+            1
+        );
+    }
+}
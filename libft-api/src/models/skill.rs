@@ -0,0 +1,32 @@
+use crate::models::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A skill as listed by the `/skills` endpoint.
+///
+/// Unlike `FtSkill`, which is a user's progress in a skill as embedded in `FtCursusUser`, this
+/// carries no level.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtSkillDefinition {
+    pub id: FtSkillId,
+    pub name: FtSkillName,
+    pub slug: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 3,
+  "name": "Rigor",
+  "slug": "rigor",
+  "created_at": "2014-11-02T16:43:29.785Z"
+}"#;
+
+        let skill = serde_json::from_str::<FtSkillDefinition>(raw).unwrap();
+        assert_eq!(skill.name, FtSkillName::new("Rigor".to_string()));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtSlot {
+    pub id: FtSlotId,
+    pub begin_at: FtDateTimeUtc,
+    pub end_at: FtDateTimeUtc,
+    pub scale_team: Option<FtSlotScaleTeam>,
+    pub user: Option<FtSlotUser>,
+}
+
+/// The scale team booked on a slot.
+///
+/// The API only exposes the scale team of your own slots, other ones are sent as `"invisible"`.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FtSlotScaleTeam {
+    ScaleTeam { id: FtScaleTeamId },
+    String(String),
+}
+
+/// The owner of a slot, sent as `"invisible"` when the slot belongs to someone else.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FtSlotUser {
+    User(Box<FtUser>),
+    String(String),
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtSlotId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"[
+  {
+    "id": 61283114,
+    "begin_at": "2025-03-10T05:00:00.000Z",
+    "end_at": "2025-03-10T05:15:00.000Z",
+    "scale_team": null,
+    "user": "invisible"
+  },
+  {
+    "id": 61283115,
+    "begin_at": "2025-03-10T05:15:00.000Z",
+    "end_at": "2025-03-10T05:30:00.000Z",
+    "scale_team": { "id": 7812345 },
+    "user": {
+      "id": 180844,
+      "login": "yondoo",
+      "url": "https://api.intra.42.fr/v2/users/yondoo"
+    }
+  }
+]"#;
+
+        let slots = serde_json::from_str::<Vec<FtSlot>>(raw).unwrap();
+        assert_eq!(
+            slots[1].scale_team,
+            Some(FtSlotScaleTeam::ScaleTeam {
+                id: FtScaleTeamId::new(7_812_345)
+            })
+        );
+    }
+}
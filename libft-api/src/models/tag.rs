@@ -0,0 +1,16 @@
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtTag {
+    pub id: FtTagId,
+    pub name: String,
+    pub kind: Option<FtTagKind>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtTagId(pub i32);
+
+/// The kind of a tag, e.g. `junk`, `normal`.
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtTagKind(pub String);
@@ -15,8 +15,10 @@ pub struct FtTeam {
     pub url: Option<FtUrl>,
     pub users: Option<Vec<FtUser>>,
     pub final_mark: Option<FtFinalMark>,
+    #[serde(alias = "closed?")]
     pub closed: Option<bool>,
     pub closed_at: Option<FtDateTimeUtc>,
+    #[serde(alias = "locked?")]
     pub locked: Option<bool>,
     pub locked_at: Option<FtDateTimeUtc>,
     pub project_gitlab_path: Option<FtProjectGitlabPath>,
@@ -36,11 +38,26 @@ pub struct FtTeamUpload {
     pub upload_id: FtTeamUploadId,
 }
 
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtTeamsUser {
+    pub id: FtTeamsUserId,
+    pub team_id: FtTeamId,
+    pub user_id: FtUserId,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub validated: Option<bool>,
+    pub leader: Option<bool>,
+    pub occurrence: Option<i32>,
+    pub user: Option<FtUser>,
+}
+
 #[derive(
     Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Clone, Serialize, Deserialize, ValueStruct,
 )]
 pub struct FtTeamId(pub i32);
 
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtTeamsUserId(pub i32);
+
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct FtTeamName(pub String);
 
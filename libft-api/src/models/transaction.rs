@@ -0,0 +1,39 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// A wallet operation, adding (or, when negative, removing) points to a user's wallet.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtTransaction {
+    pub id: FtTransactionId,
+    pub value: i32,
+    pub user_id: FtUserId,
+    pub transactable_id: Option<i64>,
+    pub transactable_type: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtTransactionId(pub i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 9283741,
+  "value": -20,
+  "user_id": 212750,
+  "transactable_id": null,
+  "transactable_type": "Tig",
+  "reason": "Piscine t-shirt",
+  "created_at": "2024-06-12T09:43:02.120Z"
+}"#;
+
+        let transaction = serde_json::from_str::<FtTransaction>(raw).unwrap();
+        assert_eq!(transaction.value, -20);
+    }
+}
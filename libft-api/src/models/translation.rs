@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// The localized strings of a translatable object (a project, a quest...) in one language.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtTranslation {
+    pub id: FtTranslationId,
+    pub translatable_id: i32,
+    pub translatable_type: String,
+    pub language_id: FtLanguageId,
+    /// The translated fields, keyed by field name (`name`, `description`...).
+    pub fields: BTreeMap<String, Option<String>>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtTranslationId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 44231,
+  "translatable_id": 1314,
+  "translatable_type": "Project",
+  "language_id": 2,
+  "fields": {
+    "name": "Libft",
+    "description": "Écrire sa propre bibliothèque de fonctions."
+  },
+  "created_at": "2019-07-30T13:52:35.011Z",
+  "updated_at": "2019-07-30T13:52:35.011Z"
+}"#;
+
+        let translation = serde_json::from_str::<FtTranslation>(raw).unwrap();
+        assert_eq!(
+            translation.fields.get("name").cloned().flatten(),
+            Some("Libft".to_string())
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// The admission file of a user, filled in before the piscine.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtUserCandidature {
+    pub id: FtUserCandidatureId,
+    pub user_id: FtUserId,
+    pub birth_date: Option<String>,
+    pub gender: Option<String>,
+    pub zip_code: Option<String>,
+    pub country: Option<String>,
+    pub birth_city: Option<String>,
+    pub birth_country: Option<String>,
+    pub postal_street: Option<String>,
+    pub postal_complement: Option<String>,
+    pub postal_city: Option<String>,
+    pub postal_zip_code: Option<String>,
+    pub postal_country: Option<String>,
+    pub contact_affiliation: Option<String>,
+    pub contact_last_name: Option<String>,
+    pub contact_first_name: Option<String>,
+    pub contact_phone1: Option<String>,
+    pub contact_phone2: Option<String>,
+    pub max_level_memory: Option<i32>,
+    pub max_level_logic: Option<i32>,
+    pub other_information: Option<String>,
+    pub language: Option<String>,
+    pub meeting_date: Option<FtDateTimeUtc>,
+    pub piscine_date: Option<String>,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub pin: Option<String>,
+    pub phone_country_code: Option<String>,
+    pub hidden_phone: Option<String>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtUserCandidatureId(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deser() {
+        let raw = r#"{
+  "id": 201873,
+  "user_id": 212750,
+  "birth_date": "2000-01-01",
+  "gender": null,
+  "zip_code": null,
+  "country": "South Korea",
+  "birth_city": null,
+  "birth_country": null,
+  "postal_street": null,
+  "postal_complement": null,
+  "postal_city": null,
+  "postal_zip_code": null,
+  "postal_country": null,
+  "contact_affiliation": null,
+  "contact_last_name": null,
+  "contact_first_name": null,
+  "contact_phone1": null,
+  "contact_phone2": null,
+  "max_level_memory": 12,
+  "max_level_logic": 9,
+  "other_information": null,
+  "language": "ko",
+  "meeting_date": null,
+  "piscine_date": "2024-07-01",
+  "created_at": "2024-04-11T06:21:43.551Z",
+  "updated_at": "2024-04-11T06:21:43.551Z",
+  "phone": "hidden",
+  "email": "hidden",
+  "pin": null,
+  "phone_country_code": null,
+  "hidden_phone": null
+}"#;
+
+        let candidature = serde_json::from_str::<FtUserCandidature>(raw).unwrap();
+        assert_eq!(candidature.max_level_logic, Some(9));
+    }
+}
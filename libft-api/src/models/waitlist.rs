@@ -0,0 +1,23 @@
+use crate::models::prelude::*;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+/// The waitlist of a full event or exam.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FtWaitlist {
+    pub id: FtWaitlistId,
+    pub waitlistable_id: i32,
+    pub waitlistable_type: String,
+    pub created_at: Option<FtDateTimeUtc>,
+    pub updated_at: Option<FtDateTimeUtc>,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct FtWaitlistId(pub i32);
+
+/// The object a waitlist belongs to.
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum FtWaitlistable {
+    Event(FtEventId),
+    Exam(FtExamId),
+}
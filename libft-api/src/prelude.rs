@@ -5,16 +5,37 @@
 //! needing to import individual modules.
 //!
 //! The prelude includes:
+//! * Computations on models from the `analytics` module
 //! * API endpoint clients and requests from the `api` module
 //! * Authentication types and functions from the `auth` module
+//! * Bulk maintenance helpers from the `bulk` module
 //! * Common types like error types, client, parameters, rate limiter, and paginator from the `common` module
-//! * The HTTP connector implementation from the `connector` module
+//! * Snapshot diffing from the `diff` module
+//! * The HTTP connector implementations from the `connector` module
 //! * Constants and information about 42 campuses and cursus from the `info` module
+//! * The job queue from the `jobs` module
 //! * All model types from the `models` module
+//! * Name registries from the `registry` module
+//! * Reports from the `reports` module
+//! * The defense scheduler from the `scheduler` module
+//! * The entity store from the `store` module
+//! * Consistency checks from the `validate` module
 
+pub use crate::analytics::*;
 pub use crate::api::prelude::*;
 pub use crate::auth::*;
+pub use crate::bulk::*;
 pub use crate::common::*;
+pub use crate::connector::FtClientReplayConnector;
+#[cfg(feature = "reqwest")]
 pub use crate::connector::FtClientReqwestConnector;
+pub use crate::diff::*;
 pub use crate::info::*;
+pub use crate::jobs::*;
 pub use crate::models::prelude::*;
+pub use crate::registry::*;
+pub use crate::reports::*;
+#[cfg(feature = "scale_teams")]
+pub use crate::scheduler::*;
+pub use crate::store::*;
+pub use crate::validate::*;
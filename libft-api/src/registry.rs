@@ -0,0 +1,30 @@
+//! Name lookups for ids that are otherwise hard coded.
+//!
+//! The constants in `info` are generated from the API and can lag behind it; the registries here
+//! resolve names at runtime, first from those constants and then from what was fetched live, and
+//! render the constants back for the generators in `bin/`.
+
+pub mod campus;
+pub use campus::*;
+#[cfg(feature = "projects")]
+pub mod project;
+#[cfg(feature = "projects")]
+pub use project::*;
+
+/// `"Le Havre"` becomes `LE_HAVRE`, `"19"` becomes `_19`.
+pub(crate) fn constant_name(name: &str) -> String {
+    let mut constant = String::new();
+    for word in name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if !constant.is_empty() {
+            constant.push('_');
+        }
+        constant.extend(word.chars().flat_map(char::to_uppercase));
+    }
+    if !constant.starts_with(|c: char| c.is_alphabetic()) {
+        constant.insert(0, '_');
+    }
+    constant
+}
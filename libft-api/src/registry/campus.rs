@@ -0,0 +1,252 @@
+#[cfg(feature = "campus")]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::sync::{LazyLock, RwLock};
+
+use super::constant_name;
+use crate::info::ft_campus_id;
+use crate::prelude::*;
+
+static FT_CAMPUS_REGISTRY: LazyLock<RwLock<FtCampusRegistry>> =
+    LazyLock::new(|| RwLock::new(FtCampusRegistry::builtin()));
+
+/// A campus known to a `FtCampusRegistry`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FtCampusEntry {
+    pub id: FtCampusId,
+    pub name: String,
+}
+
+/// Campus ids by name.
+///
+/// `FtCampusRegistry::by_name` looks up the generated `ft_campus_id` constants, then the campuses
+/// added at runtime through `FtCampusRegistry::load`, so a campus opened after the last
+/// generation can still be found.
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// assert_eq!(
+///     FtCampusRegistry::by_name("Gyeongsan"),
+///     Some(FtCampusId::new(ft_campus_id::GYEONGSAN))
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct FtCampusRegistry {
+    campuses: Vec<FtCampusEntry>,
+}
+
+impl FtCampusRegistry {
+    /// The campuses of the generated `ft_campus_id` module.
+    pub fn builtin() -> Self {
+        Self {
+            campuses: ft_campus_id::ALL
+                .iter()
+                .map(|(name, id)| FtCampusEntry {
+                    id: FtCampusId::new(*id),
+                    name: (*name).to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// The campuses returned by the API, skipping the ones without a name.
+    pub fn from_campuses(campuses: &[FtCampus]) -> Self {
+        Self {
+            campuses: campuses
+                .iter()
+                .filter_map(|campus| {
+                    Some(FtCampusEntry {
+                        id: campus.id.clone(),
+                        name: campus.name.as_ref()?.value().clone(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Fetches every campus from `/campus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    #[cfg(feature = "campus")]
+    pub async fn fetch<FCHC>(session: &FtClientSession<'_, FCHC>) -> ClientResult<Self>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let campuses = paginate(PER_PAGE, |page| {
+            session.campus_id(
+                FtApiCampusIdRequest::new()
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        Ok(Self::from_campuses(&campuses))
+    }
+
+    /// Fetches every campus and makes the unknown ones available to `FtCampusRegistry::by_name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    #[cfg(feature = "campus")]
+    pub async fn load<FCHC>(session: &FtClientSession<'_, FCHC>) -> ClientResult<()>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        let fetched = Self::fetch(session).await?;
+        FT_CAMPUS_REGISTRY
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .merge(fetched);
+        Ok(())
+    }
+
+    /// Looks `name` up in the generated constants and the campuses added by `load`.
+    ///
+    /// Names are compared ignoring case, spaces and punctuation, so `"Le Havre"` and `"LE_HAVRE"`
+    /// both match.
+    pub fn by_name(name: &str) -> Option<FtCampusId> {
+        FT_CAMPUS_REGISTRY
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .find(name)
+    }
+
+    pub fn campuses(&self) -> &[FtCampusEntry] {
+        &self.campuses
+    }
+
+    pub fn find(&self, name: &str) -> Option<FtCampusId> {
+        let name = normalize(name);
+        self.campuses
+            .iter()
+            .find(|campus| normalize(&campus.name) == name)
+            .map(|campus| campus.id.clone())
+    }
+
+    pub fn name_of(&self, id: &FtCampusId) -> Option<&str> {
+        self.campuses
+            .iter()
+            .find(|campus| campus.id == *id)
+            .map(|campus| campus.name.as_str())
+    }
+
+    /// Adds the campuses of `other` whose id is unknown, keeping the existing names.
+    pub fn merge(&mut self, other: Self) {
+        let known = self
+            .campuses
+            .iter()
+            .map(|campus| campus.id.clone())
+            .collect::<HashSet<_>>();
+        self.campuses.extend(
+            other
+                .campuses
+                .into_iter()
+                .filter(|campus| !known.contains(&campus.id)),
+        );
+    }
+
+    /// Renders the source of the `ft_campus_id` module, campuses sorted by descending id.
+    pub fn to_constants_module(&self) -> String {
+        let mut campuses = self
+            .campuses
+            .iter()
+            .map(|campus| (constant_name(&campus.name), campus))
+            .collect::<Vec<_>>();
+        campuses.sort_by(|(_, a), (_, b)| b.id.value().cmp(a.id.value()));
+
+        let mut seen = HashSet::new();
+        for (constant, campus) in &mut campuses {
+            if !seen.insert(constant.clone()) {
+                write!(constant, "_{}", campus.id).unwrap();
+            }
+        }
+
+        let mut out = String::from(
+            "//! Campus ids of the 42 network.\n\
+             //!\n\
+             //! Generated from the `/campus` endpoint by `cargo run --bin gen-constants`. Re-running it keeps\n\
+             //! the existing constants and appends newly opened campuses.\n\n",
+        );
+        for (constant, campus) in &campuses {
+            writeln!(out, "pub const {constant}: i32 = {};", campus.id).unwrap();
+        }
+        writeln!(out, "\n/// Every campus above, with its display name.").unwrap();
+        writeln!(out, "pub const ALL: [(&str, i32); {}] = [", campuses.len()).unwrap();
+        for (constant, campus) in &campuses {
+            writeln!(out, "    ({:?}, {constant}),", campus.name).unwrap();
+        }
+        out.push_str("];\n");
+        out
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_matches_generated_module() {
+        assert_eq!(
+            FtCampusRegistry::builtin().to_constants_module(),
+            include_str!("../info/ft_campus_id.rs")
+        );
+    }
+
+    #[test]
+    fn by_name() {
+        let registry = FtCampusRegistry::builtin();
+
+        assert_eq!(
+            registry.find("le_havre"),
+            Some(FtCampusId::new(ft_campus_id::LE_HAVRE))
+        );
+        assert_eq!(
+            registry.find("19"),
+            Some(FtCampusId::new(ft_campus_id::_19))
+        );
+        assert_eq!(
+            registry.name_of(&FtCampusId::new(ft_campus_id::GYEONGSAN)),
+            Some("Gyeongsan")
+        );
+        assert_eq!(registry.find("Atlantis"), None);
+    }
+
+    #[test]
+    fn merge_keeps_existing_names() {
+        let mut registry = FtCampusRegistry::builtin();
+        registry.merge(FtCampusRegistry {
+            campuses: vec![
+                FtCampusEntry {
+                    id: FtCampusId::new(ft_campus_id::PARIS),
+                    name: "Paris Campus".to_string(),
+                },
+                FtCampusEntry {
+                    id: FtCampusId::new(999),
+                    name: "New Campus".to_string(),
+                },
+            ],
+        });
+
+        assert_eq!(registry.find("Paris Campus"), None);
+        assert_eq!(registry.find("new campus"), Some(FtCampusId::new(999)));
+        assert!(registry
+            .to_constants_module()
+            .contains("pub const NEW_CAMPUS: i32 = 999;"));
+    }
+}
@@ -0,0 +1,334 @@
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::RwLock;
+
+use super::constant_name;
+use crate::prelude::*;
+
+type FtProjectSessionKey = (FtSlug, Option<FtCampusId>, Option<FtCursusId>);
+
+/// Project and project session ids by slug, resolved from the API and cached.
+///
+/// The `ft_project_session_ids` constants only hold for the campus and cursus they were taken
+/// from. The registry resolves the session of a project for any campus and cursus instead,
+/// preferring a session dedicated to the campus over the global one, and asks the API only once
+/// per slug.
+///
+/// ```rust
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let registry = FtProjectRegistry::new();
+///     let project_session_id = registry
+///         .project_session_id(
+///             &session,
+///             &FtSlug::new("42cursus-libft".to_string()),
+///             Some(FtCampusId::new(ft_campus_id::GYEONGSAN)),
+///             Some(FtCursusId::new(FT_CURSUS_ID)),
+///         )
+///         .await?;
+///     println!("{project_session_id:?}");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct FtProjectRegistry {
+    projects: RwLock<HashMap<FtSlug, FtProjectId>>,
+    sessions: RwLock<HashMap<FtProjectSessionKey, FtProjectSessionId>>,
+}
+
+impl FtProjectRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves the id of the project `slug`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be fetched, including when it does not exist.
+    pub async fn project_id<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        slug: &FtSlug,
+    ) -> ClientResult<FtProjectId>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        if let Some(project_id) = self.cached_project_id(slug) {
+            return Ok(project_id);
+        }
+
+        let project = session
+            .projects_id(FtApiProjectsIdRequest::new(FtProjectIdentifier::Slug(
+                slug.clone(),
+            )))
+            .await?
+            .project;
+        self.write_projects()
+            .insert(slug.clone(), project.id.clone());
+        Ok(project.id)
+    }
+
+    /// Resolves the session of the project `slug` for `campus_id` and `cursus_id`.
+    ///
+    /// Returns `None` if the project has no session for them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project or its sessions cannot be fetched.
+    pub async fn project_session_id<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        slug: &FtSlug,
+        campus_id: Option<FtCampusId>,
+        cursus_id: Option<FtCursusId>,
+    ) -> ClientResult<Option<FtProjectSessionId>>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let key = (slug.clone(), campus_id.clone(), cursus_id.clone());
+        if let Some(project_session_id) = self.cached_project_session_id(&key) {
+            return Ok(Some(project_session_id));
+        }
+
+        let project_id = self.project_id(session, slug).await?;
+        let project_sessions = paginate(PER_PAGE, |page| {
+            let mut req = FtApiProjectsIdProjectSessionsRequest::new(project_id.clone())
+                .with_page(page)
+                .with_per_page(PER_PAGE);
+            req.cursus_id = cursus_id.clone();
+            session.projects_id_project_sessions(req)
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let project_session_id =
+            select_project_session(&project_sessions, campus_id.as_ref(), cursus_id.as_ref())
+                .map(|project_session| project_session.id.clone());
+        if let Some(project_session_id) = &project_session_id {
+            self.write_sessions()
+                .insert(key, project_session_id.clone());
+        }
+        Ok(project_session_id)
+    }
+
+    /// Caches every project of `cursus_id` available on `campus_id`, with its session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched.
+    pub async fn load<FCHC>(
+        &self,
+        session: &FtClientSession<'_, FCHC>,
+        campus_id: FtCampusId,
+        cursus_id: FtCursusId,
+    ) -> ClientResult<()>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let projects = paginate(PER_PAGE, |page| {
+            session.projects(
+                FtApiProjectRequest::new()
+                    .with_cursus_id(cursus_id.clone())
+                    .with_filter(vec![FtFilterOption::new(
+                        FtFilterField::Campus,
+                        vec![campus_id.to_string()],
+                    )])
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        for project in projects {
+            self.insert(project, &campus_id, &cursus_id);
+        }
+        Ok(())
+    }
+
+    /// Renders the cached sessions of `campus_id` and `cursus_id` as a constants module named
+    /// `module`, e.g. `LIBFT` for `42cursus-libft`.
+    pub fn to_constants_module(
+        &self,
+        module: &str,
+        campus_id: &FtCampusId,
+        cursus_id: &FtCursusId,
+    ) -> String {
+        let mut constants = self
+            .read_sessions()
+            .iter()
+            .filter(|((_, campus, cursus), _)| {
+                campus.as_ref() == Some(campus_id) && cursus.as_ref() == Some(cursus_id)
+            })
+            .map(|((slug, _, _), project_session_id)| {
+                (
+                    constant_name(slug.value().trim_start_matches("42cursus-")),
+                    project_session_id.value().to_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        constants.sort();
+
+        let mut out = format!(
+            "// Project sessions of cursus {cursus_id} on campus {campus_id}.\n\
+             // Generated by `cargo run --bin gen-constants -- project-sessions`.\n\
+             pub mod {module} {{\n"
+        );
+        for (constant, project_session_id) in constants {
+            writeln!(out, "    pub const {constant}: u16 = {project_session_id};").unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn insert(&self, project: FtProject, campus_id: &FtCampusId, cursus_id: &FtCursusId) {
+        let project_session_id = project.project_sessions.as_deref().and_then(|sessions| {
+            select_project_session(sessions, Some(campus_id), Some(cursus_id))
+                .map(|project_session| project_session.id.clone())
+        });
+        if let Some(project_session_id) = project_session_id {
+            self.write_sessions().insert(
+                (
+                    project.slug.clone(),
+                    Some(campus_id.clone()),
+                    Some(cursus_id.clone()),
+                ),
+                project_session_id,
+            );
+        }
+        self.write_projects().insert(project.slug, project.id);
+    }
+
+    fn cached_project_id(&self, slug: &FtSlug) -> Option<FtProjectId> {
+        self.projects
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(slug)
+            .cloned()
+    }
+
+    fn cached_project_session_id(&self, key: &FtProjectSessionKey) -> Option<FtProjectSessionId> {
+        self.read_sessions().get(key).cloned()
+    }
+
+    fn write_projects(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<FtSlug, FtProjectId>> {
+        self.projects.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn read_sessions(
+        &self,
+    ) -> std::sync::RwLockReadGuard<'_, HashMap<FtProjectSessionKey, FtProjectSessionId>> {
+        self.sessions.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_sessions(
+        &self,
+    ) -> std::sync::RwLockWriteGuard<'_, HashMap<FtProjectSessionKey, FtProjectSessionId>> {
+        self.sessions.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The session of `campus_id` if any, otherwise the session shared by every campus.
+///
+/// Sessions of another cursus than `cursus_id` are ignored; sessions without a cursus match any.
+fn select_project_session<'a>(
+    project_sessions: &'a [FtProjectSession],
+    campus_id: Option<&FtCampusId>,
+    cursus_id: Option<&FtCursusId>,
+) -> Option<&'a FtProjectSession> {
+    let candidates = project_sessions.iter().filter(|project_session| {
+        match (cursus_id, project_session.cursus_id) {
+            (Some(cursus_id), Some(session_cursus_id)) => *cursus_id.value() == session_cursus_id,
+            _ => true,
+        }
+    });
+
+    let mut shared = None;
+    for project_session in candidates {
+        match (campus_id, project_session.campus_id) {
+            (Some(campus_id), Some(session_campus_id))
+                if *campus_id.value() == session_campus_id =>
+            {
+                return Some(project_session);
+            }
+            (_, None) if shared.is_none() => shared = Some(project_session),
+            _ => {}
+        }
+    }
+    shared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_session(
+        id: u16,
+        campus_id: Option<i32>,
+        cursus_id: Option<i32>,
+    ) -> FtProjectSession {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "campus_id": campus_id,
+            "cursus_id": cursus_id,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn select_prefers_campus_session() {
+        let sessions = [
+            project_session(1, None, Some(21)),
+            project_session(2, Some(1), Some(21)),
+            project_session(3, Some(69), Some(9)),
+            project_session(4, Some(69), Some(21)),
+        ];
+        let gyeongsan = FtCampusId::new(ft_campus_id::GYEONGSAN);
+        let paris = FtCampusId::new(ft_campus_id::PARIS);
+        let lyon = FtCampusId::new(ft_campus_id::LYON);
+        let cursus = FtCursusId::new(FT_CURSUS_ID);
+
+        let selected = |campus_id| {
+            select_project_session(&sessions, Some(campus_id), Some(&cursus))
+                .map(|project_session| project_session.id.clone())
+        };
+        assert_eq!(selected(&gyeongsan), Some(FtProjectSessionId::new(4)));
+        assert_eq!(selected(&paris), Some(FtProjectSessionId::new(2)));
+        assert_eq!(selected(&lyon), Some(FtProjectSessionId::new(1)));
+    }
+
+    #[test]
+    fn constants_module() {
+        let registry = FtProjectRegistry::new();
+        let campus_id = FtCampusId::new(ft_campus_id::GYEONGSAN);
+        let cursus_id = FtCursusId::new(FT_CURSUS_ID);
+        let project: FtProject = serde_json::from_value(serde_json::json!({
+            "id": 1314,
+            "name": "Libft",
+            "slug": "42cursus-libft",
+            "project_sessions": [{ "id": 11805, "campus_id": null, "cursus_id": 21 }],
+        }))
+        .unwrap();
+        registry.insert(project, &campus_id, &cursus_id);
+
+        assert_eq!(
+            registry.cached_project_id(&FtSlug::new("42cursus-libft".to_string())),
+            Some(FtProjectId::new(1314))
+        );
+        assert!(registry
+            .to_constants_module("ft_cursus", &campus_id, &cursus_id)
+            .contains("pub mod ft_cursus {\n    pub const LIBFT: u16 = 11805;\n}"));
+    }
+}
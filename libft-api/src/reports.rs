@@ -0,0 +1,7 @@
+//! Ready-made reports returning typed rows.
+//!
+//! Rows implement `FtExportRecord`, so they can be written with `export::csv::FtCsvWriter` (or
+//! Parquet) the same way as the models.
+
+pub mod correction_point;
+pub use correction_point::*;
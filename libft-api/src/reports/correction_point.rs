@@ -0,0 +1,216 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "users")]
+use futures::TryStreamExt;
+use rsb_derive::Builder;
+#[cfg(feature = "users")]
+use rvstruct::ValueStruct;
+use serde::Serialize;
+use std::io::Write;
+
+use crate::export::csv::FtCsvWriter;
+use crate::export::FtExportError;
+use crate::prelude::*;
+
+/// Which historics `correction_point_audit` keeps.
+#[derive(Debug, Clone, Default, Builder)]
+pub struct FtCorrectionPointAuditFilter {
+    /// Only historics created at or after this date.
+    pub from: Option<DateTime<Utc>>,
+    /// Only historics created before this date.
+    pub to: Option<DateTime<Utc>>,
+    /// Only historics whose reason contains this text, e.g. `defense`.
+    pub reason: Option<String>,
+}
+
+/// One correction point change of a user.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FtCorrectionPointAuditRow {
+    pub user_id: FtUserId,
+    pub id: FtCorrectionPointHistoryId,
+    pub created_at: FtDateTimeUtc,
+    pub reason: FtCorrectionPointsReason,
+    pub scale_team_id: Option<FtScaleTeamId>,
+    pub sum: FtCorrectionPointsAmount,
+    pub total: FtCorrectionpointsTotal,
+}
+
+/// Fetches the correction point historics of `user_ids` matching `filter`, user by user.
+///
+/// # Example
+///
+/// ```rust
+/// use libft_api::export::csv::FtCsvWriter;
+/// use libft_api::prelude::*;
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let rows = correction_point_audit(
+///         &session,
+///         &[FtUserId::new(180_844)],
+///         FtCorrectionPointAuditFilter::new().with_reason("defense".to_string()),
+///     )
+///     .await?;
+///     write_correction_point_audit_csv(&rows, std::io::stdout())?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a page of historics cannot be fetched.
+#[cfg(feature = "users")]
+pub async fn correction_point_audit<FCHC>(
+    session: &FtClientSession<'_, FCHC>,
+    user_ids: &[FtUserId],
+    filter: FtCorrectionPointAuditFilter,
+) -> ClientResult<Vec<FtCorrectionPointAuditRow>>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    const PER_PAGE: u8 = 100;
+
+    let range = (filter.from.is_some() || filter.to.is_some()).then(|| {
+        vec![FtRangeOption::new(
+            FtRangeField::CreatedAt,
+            vec![
+                filter.from.unwrap_or(DateTime::UNIX_EPOCH).to_rfc3339(),
+                filter.to.unwrap_or_else(Utc::now).to_rfc3339(),
+            ],
+        )]
+    });
+
+    let mut rows = Vec::new();
+    for user_id in user_ids {
+        let historics = paginate(PER_PAGE, |page| {
+            let mut req = FtApiUsersIdCorrectionPointHistoricsRequest::new(*user_id)
+                .with_page(page)
+                .with_per_page(PER_PAGE);
+            req.range = range.clone();
+            session.users_id_correction_point_historics(req)
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        rows.extend(audit_rows(*user_id, historics, &filter));
+    }
+    Ok(rows)
+}
+
+/// Writes `rows` as CSV, with a header row.
+///
+/// # Errors
+///
+/// Returns an error if a row cannot be serialized or `writer` fails.
+pub fn write_correction_point_audit_csv<W: Write>(
+    rows: &[FtCorrectionPointAuditRow],
+    writer: W,
+) -> Result<(), FtExportError> {
+    let mut writer = FtCsvWriter::new(writer);
+    writer.write_all(rows)?;
+    writer.into_inner()?.flush()?;
+    Ok(())
+}
+
+#[cfg(feature = "users")]
+fn audit_rows(
+    user_id: FtUserId,
+    historics: Vec<FtCorrectionPointHistory>,
+    filter: &FtCorrectionPointAuditFilter,
+) -> impl Iterator<Item = FtCorrectionPointAuditRow> + '_ {
+    historics
+        .into_iter()
+        .filter(|historic| {
+            let created_at = *historic.created_at.value();
+            filter.from.is_none_or(|from| from <= created_at)
+                && filter.to.is_none_or(|to| created_at < to)
+                && filter
+                    .reason
+                    .as_ref()
+                    .is_none_or(|reason| historic.reason.value().contains(reason.as_str()))
+        })
+        .map(move |historic| FtCorrectionPointAuditRow {
+            user_id,
+            id: historic.id,
+            created_at: historic.created_at,
+            reason: historic.reason,
+            scale_team_id: historic.scale_team_id,
+            sum: historic.sum,
+            total: historic.total,
+        })
+}
+
+#[cfg(all(test, feature = "users"))]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn historics() -> Vec<FtCorrectionPointHistory> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "id": 72431977,
+                "created_at": "2024-11-06T12:26:03.805Z",
+                "reason": "Earning after defense",
+                "scale_team_id": 6512345,
+                "sum": 1,
+                "total": 24,
+                "updated_at": "2024-11-06T12:26:03.805Z"
+            },
+            {
+                "id": 72431978,
+                "created_at": "2024-11-08T09:00:00.000Z",
+                "reason": "Defense plannification",
+                "scale_team_id": 6512399,
+                "sum": -1,
+                "total": 23,
+                "updated_at": "2024-11-08T09:00:00.000Z"
+            },
+            {
+                "id": 72431979,
+                "created_at": "2024-12-01T00:00:00.000Z",
+                "reason": "Earning after defense",
+                "scale_team_id": 6512400,
+                "sum": 1,
+                "total": 24,
+                "updated_at": "2024-12-01T00:00:00.000Z"
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn filters_historics() {
+        let filter = FtCorrectionPointAuditFilter::new()
+            .with_to(Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap())
+            .with_reason("after defense".to_string());
+
+        let rows = audit_rows(FtUserId::new(180_844), historics(), &filter).collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].user_id, FtUserId::new(180_844));
+        assert_eq!(rows[0].scale_team_id, Some(FtScaleTeamId::new(6512345)));
+    }
+
+    #[test]
+    fn csv() {
+        let rows = audit_rows(
+            FtUserId::new(180_844),
+            historics(),
+            &FtCorrectionPointAuditFilter::new(),
+        )
+        .take(1)
+        .collect::<Vec<_>>();
+
+        let mut csv = Vec::new();
+        write_correction_point_audit_csv(&rows, &mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "user_id,id,created_at,reason,scale_team_id,sum,total\n\
+             180844,72431977,2024-11-06T12:26:03.805Z,Earning after defense,6512345,1,24\n"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Defense scheduling helpers.
+//!
+//! This module assigns evaluators to teams in a round-robin fashion and staggers the defenses
+//! into consecutive slots, producing the bodies expected by
+//! `FtClientSession::scale_teams_multiple_create_post`.
+//!
+//! Evaluators already booked through existing scale teams are never given an overlapping slot:
+//! their defense is pushed to their next free slot, and the shift is reported as a conflict.
+//!
+//! When evaluators open slots instead, `FtSlotPlanner` places the defenses in their common
+//! availability.
+//!
+//! `FtTruantReport` lists the defenses a user did not show up to, and plans them again with a
+//! `FtSlotPlanner`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{TimeDelta, TimeZone, Utc};
+//! use libft_api::prelude::*;
+//!
+//! async fn example(teams: Vec<FtTeamId>, evaluators: Vec<FtUserId>) -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let begin_at = Utc.with_ymd_and_hms(2025, 1, 28, 5, 0, 0).unwrap();
+//!     let schedule = FtDefenseScheduler::new(begin_at, TimeDelta::hours(1))
+//!         .schedule(&teams, &evaluators)
+//!         .unwrap();
+//!     for request in schedule.into_requests() {
+//!         session.scale_teams_multiple_create_post(request).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use chrono::{DateTime, TimeDelta, Utc};
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+pub mod planner;
+pub use planner::*;
+pub mod truant;
+pub use truant::*;
+
+/// The number of scale teams sent in a single `scale_teams/multiple_create` call.
+pub const FT_DEFENSE_SCHEDULER_DEFAULT_BATCH_SIZE: usize = 30;
+
+/// Round-robin assigner of evaluators to teams.
+#[derive(Debug, Clone)]
+pub struct FtDefenseScheduler {
+    pub begin_at: DateTime<Utc>,
+    pub slot: TimeDelta,
+    pub batch_size: usize,
+    busy: HashMap<FtUserId, Vec<DateTime<Utc>>>,
+}
+
+/// The result of a scheduling run.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct FtDefenseSchedule {
+    pub batches: Vec<Vec<FtApiScaleTeamsMultipleCreateBody>>,
+    pub conflicts: Vec<FtDefenseConflict>,
+}
+
+/// A defense moved away from its round-robin slot because the evaluator was already booked.
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct FtDefenseConflict {
+    pub team_id: FtTeamId,
+    pub user_id: FtUserId,
+    pub requested_at: DateTime<Utc>,
+    pub scheduled_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FtDefenseSchedulerError {
+    NoEvaluators,
+    EmptySlot,
+    EmptyBatch,
+}
+
+impl Display for FtDefenseSchedulerError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::NoEvaluators => write!(f, "Defense scheduler error: no evaluators given"),
+            Self::EmptySlot => write!(f, "Defense scheduler error: slot length must be positive"),
+            Self::EmptyBatch => write!(f, "Defense scheduler error: batch size must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for FtDefenseSchedulerError {}
+
+impl FtDefenseScheduler {
+    pub fn new(begin_at: DateTime<Utc>, slot: TimeDelta) -> Self {
+        Self {
+            begin_at,
+            slot,
+            batch_size: FT_DEFENSE_SCHEDULER_DEFAULT_BATCH_SIZE,
+            busy: HashMap::new(),
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Marks `user_id` as busy for one slot starting at `begin_at`.
+    pub fn with_busy(mut self, user_id: FtUserId, begin_at: DateTime<Utc>) -> Self {
+        self.busy.entry(user_id).or_default().push(begin_at);
+        self
+    }
+
+    /// Marks the correctors of `scale_teams` as busy at their `begin_at`.
+    ///
+    /// Scale teams without a begin date or with a hidden corrector are ignored.
+    pub fn with_scale_teams(mut self, scale_teams: &[FtScaleTeam]) -> Self {
+        for scale_team in scale_teams {
+            let (FtCorrector::User(corrector), Some(begin_at)) =
+                (&scale_team.corrector, &scale_team.begin_at)
+            else {
+                continue;
+            };
+            if let Some(user_id) = &corrector.id {
+                self.busy
+                    .entry(*user_id)
+                    .or_default()
+                    .push(*begin_at.value());
+            }
+        }
+        self
+    }
+
+    /// Assigns `evaluators` to `teams` in turn, the n-th round starting `n` slots after
+    /// `begin_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `evaluators` is empty while there are teams to schedule, or if the slot
+    /// length or batch size is not positive.
+    pub fn schedule(
+        &self,
+        teams: &[FtTeamId],
+        evaluators: &[FtUserId],
+    ) -> Result<FtDefenseSchedule, FtDefenseSchedulerError> {
+        if self.slot <= TimeDelta::zero() {
+            return Err(FtDefenseSchedulerError::EmptySlot);
+        }
+        if self.batch_size == 0 {
+            return Err(FtDefenseSchedulerError::EmptyBatch);
+        }
+        if evaluators.is_empty() && !teams.is_empty() {
+            return Err(FtDefenseSchedulerError::NoEvaluators);
+        }
+
+        let mut next_slot = vec![0_i32; evaluators.len()];
+        let mut bodies = Vec::with_capacity(teams.len());
+        let mut conflicts = Vec::new();
+
+        for (i, team_id) in teams.iter().enumerate() {
+            let evaluator = i % evaluators.len();
+            let user_id = &evaluators[evaluator];
+            let requested_at = self.slot_at(next_slot[evaluator]);
+
+            while self.is_busy(user_id, self.slot_at(next_slot[evaluator])) {
+                next_slot[evaluator] += 1;
+            }
+            let scheduled_at = self.slot_at(next_slot[evaluator]);
+            next_slot[evaluator] += 1;
+
+            if scheduled_at != requested_at {
+                conflicts.push(FtDefenseConflict {
+                    team_id: team_id.clone(),
+                    user_id: *user_id,
+                    requested_at,
+                    scheduled_at,
+                });
+            }
+            bodies.push(FtApiScaleTeamsMultipleCreateBody {
+                begin_at: FtDateTimeUtc::new(scheduled_at),
+                user_id: *user_id,
+                team_id: team_id.clone(),
+            });
+        }
+
+        let mut batches = Vec::new();
+        let mut bodies = bodies.into_iter().peekable();
+        while bodies.peek().is_some() {
+            batches.push(bodies.by_ref().take(self.batch_size).collect());
+        }
+
+        Ok(FtDefenseSchedule { batches, conflicts })
+    }
+
+    fn slot_at(&self, index: i32) -> DateTime<Utc> {
+        self.begin_at + self.slot * index
+    }
+
+    fn is_busy(&self, user_id: &FtUserId, begin_at: DateTime<Utc>) -> bool {
+        self.busy.get(user_id).is_some_and(|booked| {
+            booked
+                .iter()
+                .any(|booked| *booked < begin_at + self.slot && begin_at < *booked + self.slot)
+        })
+    }
+}
+
+impl FtDefenseSchedule {
+    /// Turns the batches into requests for `scale_teams_multiple_create_post`.
+    pub fn into_requests(self) -> Vec<FtApiScaleTeamsMultipleCreateRequest> {
+        self.batches
+            .into_iter()
+            .map(FtApiScaleTeamsMultipleCreateRequest::new)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn begin_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 28, 5, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn round_robin() {
+        let teams = [1, 2, 3].map(FtTeamId::new);
+        let evaluators = [174_094, 172_309].map(FtUserId::new);
+
+        let schedule = FtDefenseScheduler::new(begin_at(), TimeDelta::hours(1))
+            .schedule(&teams, &evaluators)
+            .unwrap();
+
+        let bodies = &schedule.batches[0];
+        assert_eq!(bodies[1].user_id, FtUserId::new(172_309));
+        assert_eq!(bodies[2].user_id, FtUserId::new(174_094));
+        assert_eq!(*bodies[1].begin_at.value(), begin_at());
+        assert_eq!(
+            *bodies[2].begin_at.value(),
+            begin_at() + TimeDelta::hours(1)
+        );
+        assert!(schedule.conflicts.is_empty());
+    }
+
+    #[test]
+    fn conflict_moves_to_next_free_slot() {
+        let teams = [1, 2].map(FtTeamId::new);
+        let evaluators = [FtUserId::new(174_094)];
+
+        let schedule = FtDefenseScheduler::new(begin_at(), TimeDelta::hours(1))
+            .with_busy(FtUserId::new(174_094), begin_at() + TimeDelta::minutes(30))
+            .schedule(&teams, &evaluators)
+            .unwrap();
+
+        let bodies = &schedule.batches[0];
+        assert_eq!(
+            *bodies[0].begin_at.value(),
+            begin_at() + TimeDelta::hours(2)
+        );
+        assert_eq!(
+            *bodies[1].begin_at.value(),
+            begin_at() + TimeDelta::hours(3)
+        );
+        assert_eq!(schedule.conflicts.len(), 1);
+        assert_eq!(schedule.conflicts[0].team_id, FtTeamId::new(1));
+    }
+
+    #[test]
+    fn batches() {
+        let teams = [1, 2, 3, 4, 5].map(FtTeamId::new);
+        let evaluators = [FtUserId::new(174_094)];
+
+        let requests = FtDefenseScheduler::new(begin_at(), TimeDelta::hours(1))
+            .with_batch_size(2)
+            .schedule(&teams, &evaluators)
+            .unwrap()
+            .into_requests();
+
+        assert_eq!(
+            requests
+                .iter()
+                .map(|req| req.scale_teams.len())
+                .collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn no_evaluators() {
+        let res = FtDefenseScheduler::new(begin_at(), TimeDelta::hours(1))
+            .schedule(&[FtTeamId::new(1)], &[]);
+
+        assert_eq!(res, Err(FtDefenseSchedulerError::NoEvaluators));
+    }
+}
@@ -0,0 +1,413 @@
+use chrono::{DateTime, TimeDelta, Utc};
+#[cfg(feature = "slots")]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+type FtTimeRanges = HashMap<FtUserId, Vec<(DateTime<Utc>, DateTime<Utc>)>>;
+
+/// The granularity of evaluation slots on the intra.
+pub const FT_SLOT_PLANNER_DEFAULT_STEP_MINUTES: i64 = 15;
+
+/// Finds free evaluator windows for a set of defenses.
+///
+/// Availability comes from the evaluators' open slots, booked slots and existing scale teams
+/// being removed from it. Each defense takes the earliest window where an evaluator is free for
+/// the whole `duration`, the least loaded evaluator winning ties.
+///
+/// ```rust
+/// use chrono::{TimeDelta, TimeZone, Utc};
+/// use libft_api::prelude::*;
+///
+/// async fn example(teams: Vec<FtTeamId>, evaluators: Vec<FtUserId>) -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let begin_at = Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap();
+///     let plan = FtSlotPlanner::new(TimeDelta::minutes(45))
+///         .fetch(&session, &evaluators, begin_at, begin_at + TimeDelta::days(7))
+///         .await?
+///         .plan(&teams);
+///     for request in plan.into_requests() {
+///         session.scale_teams_multiple_create_post(request).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FtSlotPlanner {
+    pub duration: TimeDelta,
+    pub step: TimeDelta,
+    pub batch_size: usize,
+    available: FtTimeRanges,
+    busy: FtTimeRanges,
+    evaluators: Vec<FtUserId>,
+}
+
+/// The result of a planning run.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct FtSlotPlan {
+    pub batches: Vec<Vec<FtApiScaleTeamsMultipleCreateBody>>,
+    /// Teams no evaluator had a free window for.
+    pub unplanned: Vec<FtTeamId>,
+}
+
+impl FtSlotPlanner {
+    pub fn new(duration: TimeDelta) -> Self {
+        Self {
+            duration,
+            step: TimeDelta::minutes(FT_SLOT_PLANNER_DEFAULT_STEP_MINUTES),
+            batch_size: FT_DEFENSE_SCHEDULER_DEFAULT_BATCH_SIZE,
+            available: HashMap::new(),
+            busy: HashMap::new(),
+            evaluators: Vec::new(),
+        }
+    }
+
+    pub fn with_step(mut self, step: TimeDelta) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Marks `user_id` as available from `begin_at` to `end_at`.
+    pub fn with_availability(
+        mut self,
+        user_id: FtUserId,
+        begin_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> Self {
+        if !self.evaluators.contains(&user_id) {
+            self.evaluators.push(user_id);
+        }
+        self.available
+            .entry(user_id)
+            .or_default()
+            .push((begin_at, end_at));
+        self
+    }
+
+    /// Marks `user_id` as busy from `begin_at` to `end_at`.
+    pub fn with_busy(
+        mut self,
+        user_id: FtUserId,
+        begin_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> Self {
+        self.busy
+            .entry(user_id)
+            .or_default()
+            .push((begin_at, end_at));
+        self
+    }
+
+    /// Adds the slots of `user_id`: open ones as availability, booked ones as busy time.
+    ///
+    /// The owner of a slot is often sent as `"invisible"`, hence the explicit `user_id`.
+    pub fn with_slots(mut self, user_id: FtUserId, slots: &[FtSlot]) -> Self {
+        for slot in slots {
+            let (begin_at, end_at) = (*slot.begin_at.value(), *slot.end_at.value());
+            self = match slot.scale_team {
+                None => self.with_availability(user_id, begin_at, end_at),
+                Some(_) => self.with_busy(user_id, begin_at, end_at),
+            };
+        }
+        self
+    }
+
+    /// Marks the correctors of `scale_teams` as busy for `duration` from their `begin_at`.
+    ///
+    /// Scale teams without a begin date or with a hidden corrector are ignored.
+    pub fn with_scale_teams(mut self, scale_teams: &[FtScaleTeam]) -> Self {
+        for scale_team in scale_teams {
+            let (FtCorrector::User(corrector), Some(begin_at)) =
+                (&scale_team.corrector, &scale_team.begin_at)
+            else {
+                continue;
+            };
+            if let Some(user_id) = corrector.id {
+                let (begin_at, end_at) = (*begin_at.value(), *begin_at.value() + self.duration);
+                self = self.with_busy(user_id, begin_at, end_at);
+            }
+        }
+        self
+    }
+
+    /// Never picks `user_ids` as evaluators, whatever their availability.
+    pub fn without_evaluators(mut self, user_ids: &[FtUserId]) -> Self {
+        self.evaluators
+            .retain(|user_id| !user_ids.contains(user_id));
+        self
+    }
+
+    /// Fetches the slots and scale teams of `evaluators` between `begin_at` and `end_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched, or a `SystemError` once the cancellation
+    /// token of the client is cancelled: planning on part of the slots would double-book
+    /// evaluators.
+    #[cfg(feature = "slots")]
+    pub async fn fetch<FCHC>(
+        mut self,
+        session: &FtClientSession<'_, FCHC>,
+        evaluators: &[FtUserId],
+        begin_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> ClientResult<Self>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let range = vec![FtRangeOption::new(
+            FtRangeField::BeginAt,
+            vec![begin_at.to_rfc3339(), end_at.to_rfc3339()],
+        )];
+        for user_id in evaluators {
+            let filter = vec![FtFilterOption::new(
+                FtFilterField::UserId,
+                vec![user_id.to_string()],
+            )];
+
+            let cancellation = &session.http_session_api.client.cancellation;
+            let slots = paginate_until(PER_PAGE, cancellation.clone(), |page| {
+                session.slots(
+                    FtApiSlotsRequest::new()
+                        .with_filter(filter.clone())
+                        .with_range(range.clone())
+                        .with_page(page)
+                        .with_per_page(PER_PAGE),
+                )
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+            check_cancelled(session)?;
+            self = self.with_slots(*user_id, &slots);
+
+            let scale_teams = paginate_until(PER_PAGE, cancellation.clone(), |page| {
+                session.scale_teams(
+                    FtApiScaleTeamsRequest::new()
+                        .with_filter(filter.clone())
+                        .with_range(range.clone())
+                        .with_page(page)
+                        .with_per_page(PER_PAGE),
+                )
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+            check_cancelled(session)?;
+            self = self.with_scale_teams(&scale_teams);
+        }
+
+        Ok(self)
+    }
+
+    /// Gives each team, in order, the earliest free window of an evaluator.
+    pub fn plan(&self, teams: &[FtTeamId]) -> FtSlotPlan {
+        let mut busy = self.busy.clone();
+        let mut load = HashMap::<FtUserId, usize>::new();
+        let mut bodies = Vec::new();
+        let mut unplanned = Vec::new();
+
+        for team_id in teams {
+            let best = self
+                .evaluators
+                .iter()
+                .filter_map(|user_id| {
+                    let begin_at = self.first_free(user_id, busy.get(user_id))?;
+                    Some((begin_at, load.get(user_id).copied().unwrap_or(0), *user_id))
+                })
+                .min_by_key(|(begin_at, load, _)| (*begin_at, *load));
+
+            let Some((begin_at, _, user_id)) = best else {
+                unplanned.push(team_id.clone());
+                continue;
+            };
+            busy.entry(user_id)
+                .or_default()
+                .push((begin_at, begin_at + self.duration));
+            *load.entry(user_id).or_default() += 1;
+            bodies.push(FtApiScaleTeamsMultipleCreateBody {
+                begin_at: FtDateTimeUtc::new(begin_at),
+                user_id,
+                team_id: team_id.clone(),
+            });
+        }
+
+        let mut batches = Vec::new();
+        let mut bodies = bodies.into_iter().peekable();
+        while bodies.peek().is_some() {
+            batches.push(bodies.by_ref().take(self.batch_size.max(1)).collect());
+        }
+
+        FtSlotPlan { batches, unplanned }
+    }
+
+    fn first_free(
+        &self,
+        user_id: &FtUserId,
+        busy: Option<&Vec<(DateTime<Utc>, DateTime<Utc>)>>,
+    ) -> Option<DateTime<Utc>> {
+        if self.step <= TimeDelta::zero() {
+            return None;
+        }
+
+        let mut windows = self.available.get(user_id)?.clone();
+        windows.sort();
+        // Consecutive slots come as separate 15 minutes ranges: merge them first.
+        let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+        for (begin_at, end_at) in windows {
+            match merged.last_mut() {
+                Some((_, last_end)) if begin_at <= *last_end => *last_end = (*last_end).max(end_at),
+                _ => merged.push((begin_at, end_at)),
+            }
+        }
+
+        merged.into_iter().find_map(|(window_begin, window_end)| {
+            let mut begin_at = window_begin;
+            while begin_at + self.duration <= window_end {
+                let end_at = begin_at + self.duration;
+                let overlaps = busy.is_some_and(|busy| {
+                    busy.iter()
+                        .any(|(busy_begin, busy_end)| *busy_begin < end_at && begin_at < *busy_end)
+                });
+                if !overlaps {
+                    return Some(begin_at);
+                }
+                begin_at += self.step;
+            }
+            None
+        })
+    }
+}
+
+impl FtSlotPlan {
+    /// Turns the batches into requests for `scale_teams_multiple_create_post`.
+    pub fn into_requests(self) -> Vec<FtApiScaleTeamsMultipleCreateRequest> {
+        self.batches
+            .into_iter()
+            .map(FtApiScaleTeamsMultipleCreateRequest::new)
+            .collect()
+    }
+}
+
+#[cfg(feature = "slots")]
+fn check_cancelled<FCHC>(session: &FtClientSession<'_, FCHC>) -> ClientResult<()>
+where
+    FCHC: FtClientHttpConnector + Send + Sync,
+{
+    if session.http_session_api.client.is_cancelled() {
+        return Err(FtClientError::SystemError(
+            FtSystemError::new().with_message("slot fetching cancelled".to_string()),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn merges_consecutive_slots() {
+        let evaluator = FtUserId::new(180_844);
+        let slots: Vec<FtSlot> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "begin_at": at(5, 0), "end_at": at(5, 15), "scale_team": null, "user": "invisible" },
+            { "id": 2, "begin_at": at(5, 15), "end_at": at(5, 30), "scale_team": null, "user": "invisible" },
+            { "id": 3, "begin_at": at(5, 30), "end_at": at(5, 45), "scale_team": { "id": 7 }, "user": "invisible" },
+        ]))
+        .unwrap();
+
+        let plan = FtSlotPlanner::new(TimeDelta::minutes(30))
+            .with_slots(evaluator, &slots)
+            .plan(&[FtTeamId::new(1), FtTeamId::new(2)]);
+
+        assert_eq!(plan.batches[0].len(), 1);
+        assert_eq!(*plan.batches[0][0].begin_at.value(), at(5, 0));
+        assert_eq!(plan.unplanned, vec![FtTeamId::new(2)]);
+    }
+
+    #[test]
+    fn avoids_busy_time_and_balances_load() {
+        let (a, b) = (FtUserId::new(1), FtUserId::new(2));
+
+        let plan = FtSlotPlanner::new(TimeDelta::hours(1))
+            .with_availability(a, at(5, 0), at(9, 0))
+            .with_availability(b, at(5, 0), at(9, 0))
+            .with_busy(a, at(5, 30), at(6, 0))
+            .plan(&[1, 2, 3].map(FtTeamId::new));
+
+        let bodies = &plan.batches[0];
+        assert_eq!(
+            (bodies[0].user_id, *bodies[0].begin_at.value()),
+            (b, at(5, 0))
+        );
+        assert_eq!(
+            (bodies[1].user_id, *bodies[1].begin_at.value()),
+            (a, at(6, 0))
+        );
+        assert_eq!(
+            (bodies[2].user_id, *bodies[2].begin_at.value()),
+            (b, at(6, 0))
+        );
+        assert!(plan.unplanned.is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "reqwest", feature = "slots"))]
+mod fetch_tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::test_support::*;
+
+    #[tokio::test]
+    async fn fetch_retries_rate_limited_pages() {
+        let begin_at = Utc.with_ymd_and_hms(2025, 3, 10, 5, 0, 0).unwrap();
+        let mock = FtMockServer::start().await;
+        mock.mock_rate_limited("slots", 0, 1).await;
+        mock.mock_get(
+            "slots",
+            serde_json::json!([{
+                "id": 1,
+                "begin_at": begin_at,
+                "end_at": begin_at + TimeDelta::hours(1),
+                "scale_team": null,
+                "user": "invisible"
+            }]),
+        )
+        .await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let evaluator = FtUserId::new(FT_MOCK_USERS[0].0);
+        let plan = FtSlotPlanner::new(TimeDelta::hours(1))
+            .fetch(
+                &session,
+                &[evaluator],
+                begin_at,
+                begin_at + TimeDelta::days(1),
+            )
+            .await
+            .unwrap()
+            .plan(&[FtTeamId::new(1)]);
+
+        assert_eq!(plan.batches[0][0].user_id, evaluator);
+        assert!(plan.unplanned.is_empty());
+    }
+}
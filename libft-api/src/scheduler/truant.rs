@@ -0,0 +1,241 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "project_sessions")]
+use futures::TryStreamExt;
+use rvstruct::ValueStruct;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// A defense a user did not show up to.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtTruancy {
+    pub scale_team_id: FtScaleTeamId,
+    pub team_id: Option<FtTeamId>,
+    pub truant_id: FtUserId,
+    pub truant_login: Option<FtLoginId>,
+    pub begin_at: Option<DateTime<Utc>>,
+}
+
+/// A user with at least one truancy.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FtTruantOffender {
+    pub user_id: FtUserId,
+    pub login: Option<FtLoginId>,
+    pub count: usize,
+}
+
+/// The truancies found in a set of scale teams.
+///
+/// ```rust
+/// use chrono::{TimeDelta, Utc};
+/// use libft_api::prelude::*;
+///
+/// async fn example(evaluators: Vec<FtUserId>) -> ClientResult<()> {
+///     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+///     let client = FtClient::new(FtClientReqwestConnector::new());
+///     let session = client.open_session(token);
+///
+///     let now = Utc::now();
+///     let report = FtTruantReport::fetch(
+///         &session,
+///         FtProjectSessionId::new(11805),
+///         now - TimeDelta::days(7),
+///     )
+///     .await?;
+///     for offender in report.offenders() {
+///         println!("{:?}: {} missed defense(s)", offender.login, offender.count);
+///     }
+///
+///     let planner = FtSlotPlanner::new(TimeDelta::minutes(45))
+///         .fetch(&session, &evaluators, now, now + TimeDelta::days(3))
+///         .await?;
+///     for request in report.reschedule(&planner).into_requests() {
+///         session.scale_teams_multiple_create_post(request).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FtTruantReport {
+    pub truancies: Vec<FtTruancy>,
+}
+
+impl FtTruantReport {
+    /// Collects the scale teams with a `truant` user.
+    pub fn from_scale_teams(scale_teams: &[FtScaleTeam]) -> Self {
+        let truancies = scale_teams
+            .iter()
+            .filter_map(|scale_team| {
+                let truant = scale_team.truant.as_ref()?;
+                Some(FtTruancy {
+                    scale_team_id: scale_team.id.clone(),
+                    team_id: scale_team.team.as_ref().map(|team| team.id.clone()),
+                    truant_id: truant.id?,
+                    truant_login: truant.login.clone(),
+                    begin_at: scale_team
+                        .begin_at
+                        .as_ref()
+                        .map(|begin_at| *begin_at.value()),
+                })
+            })
+            .collect();
+        Self { truancies }
+    }
+
+    /// Fetches the scale teams of `project_session_id` that began since `since`, and collects
+    /// their truancies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page of scale teams cannot be fetched, or a `SystemError` once the
+    /// cancellation token of the client is cancelled: rescheduling on part of the truancies would
+    /// miss some.
+    #[cfg(feature = "project_sessions")]
+    pub async fn fetch<FCHC>(
+        session: &FtClientSession<'_, FCHC>,
+        project_session_id: FtProjectSessionId,
+        since: DateTime<Utc>,
+    ) -> ClientResult<Self>
+    where
+        FCHC: FtClientHttpConnector + Send + Sync,
+    {
+        const PER_PAGE: u8 = 100;
+
+        let range = vec![FtRangeOption::new(
+            FtRangeField::BeginAt,
+            vec![since.to_rfc3339(), Utc::now().to_rfc3339()],
+        )];
+
+        let cancellation = session.http_session_api.client.cancellation.clone();
+        let scale_teams = paginate_until(PER_PAGE, cancellation, |page| {
+            session.project_sessions_id_scale_teams(
+                FtApiProjectSessionsScaleTeamsRequest::new(project_session_id.clone())
+                    .with_range(range.clone())
+                    .with_page(page)
+                    .with_per_page(PER_PAGE),
+            )
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+        if session.http_session_api.client.is_cancelled() {
+            return Err(FtClientError::SystemError(
+                FtSystemError::new().with_message("scale team fetching cancelled".to_string()),
+            ));
+        }
+        Ok(Self::from_scale_teams(&scale_teams))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.truancies.is_empty()
+    }
+
+    /// The truant users, most truancies first.
+    pub fn offenders(&self) -> Vec<FtTruantOffender> {
+        let mut offenders = HashMap::<FtUserId, FtTruantOffender>::new();
+        for truancy in &self.truancies {
+            offenders
+                .entry(truancy.truant_id)
+                .or_insert_with(|| FtTruantOffender {
+                    user_id: truancy.truant_id,
+                    login: truancy.truant_login.clone(),
+                    count: 0,
+                })
+                .count += 1;
+        }
+
+        let mut offenders = offenders.into_values().collect::<Vec<_>>();
+        offenders.sort_by_key(|offender| (Reverse(offender.count), *offender.user_id.value()));
+        offenders
+    }
+
+    /// The teams whose defense was missed, each listed once.
+    pub fn team_ids(&self) -> Vec<FtTeamId> {
+        let mut team_ids = Vec::new();
+        for team_id in self
+            .truancies
+            .iter()
+            .filter_map(|truancy| truancy.team_id.as_ref())
+        {
+            if !team_ids.contains(team_id) {
+                team_ids.push(team_id.clone());
+            }
+        }
+        team_ids
+    }
+
+    /// Plans the missed defenses again at the next free window of `planner`.
+    ///
+    /// The offenders are never picked as evaluators. Send the plan with
+    /// `FtSlotPlan::into_requests` and `scale_teams_multiple_create_post`.
+    pub fn reschedule(&self, planner: &FtSlotPlanner) -> FtSlotPlan {
+        let offenders = self
+            .offenders()
+            .into_iter()
+            .map(|offender| offender.user_id)
+            .collect::<Vec<_>>();
+        planner
+            .clone()
+            .without_evaluators(&offenders)
+            .plan(&self.team_ids())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, TimeZone};
+
+    use super::*;
+
+    fn scale_team(id: i32, team_id: i32, truant: serde_json::Value) -> FtScaleTeam {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "scale_id": 1,
+            "created_at": "2025-03-01T00:00:00.000Z",
+            "updated_at": "2025-03-01T00:00:00.000Z",
+            "begin_at": "2025-03-03T05:00:00.000Z",
+            "corrector": { "id": 1, "login": "corrector" },
+            "correcteds": [{ "id": 2, "login": "corrected" }],
+            "truant": truant,
+            "team": { "id": team_id }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn offenders_and_reschedule() {
+        let scale_teams = [
+            scale_team(1, 10, serde_json::json!({ "id": 2, "login": "corrected" })),
+            scale_team(2, 10, serde_json::json!({ "id": 2, "login": "corrected" })),
+            scale_team(3, 11, serde_json::json!({ "id": 1, "login": "corrector" })),
+            scale_team(4, 12, serde_json::json!({})),
+        ];
+
+        let report = FtTruantReport::from_scale_teams(&scale_teams);
+        assert_eq!(report.truancies.len(), 3);
+        assert_eq!(
+            report
+                .offenders()
+                .iter()
+                .map(|offender| (offender.user_id, offender.count))
+                .collect::<Vec<_>>(),
+            vec![(FtUserId::new(2), 2), (FtUserId::new(1), 1)]
+        );
+        assert_eq!(
+            report.team_ids(),
+            vec![FtTeamId::new(10), FtTeamId::new(11)]
+        );
+
+        let at = |hour| Utc.with_ymd_and_hms(2025, 3, 4, hour, 0, 0).unwrap();
+        let planner = FtSlotPlanner::new(TimeDelta::hours(1))
+            .with_availability(FtUserId::new(1), at(5), at(9))
+            .with_availability(FtUserId::new(3), at(6), at(9));
+        let plan = report.reschedule(&planner);
+
+        let bodies = &plan.batches[0];
+        assert!(bodies.iter().all(|body| body.user_id == FtUserId::new(3)));
+        assert_eq!(*bodies[0].begin_at.value(), at(6));
+        assert_eq!(*bodies[1].begin_at.value(), at(7));
+    }
+}
@@ -0,0 +1,289 @@
+//! An in-memory store of fetched entities, with the joins reports keep needing.
+//!
+//! `FtEntityStore` indexes users, teams, teams users and scale teams by id, and links them the
+//! way the API does: a team to its members through `teams_users` or the users embedded in the
+//! team, a scale team to its team and corrector. Logins are collected from every user seen,
+//! including the ones embedded in other records, so joins work even when only some users were
+//! fetched in full.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn example(project_session_id: FtProjectSessionId) -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let mut store = FtEntityStore::new();
+//!     store.extend_teams(
+//!         session
+//!             .project_sessions_id_teams(
+//!                 FtApiProjectSessionsTeamsRequest::new(project_session_id.clone())
+//!                     .with_per_page(100),
+//!             )
+//!             .await?
+//!             .teams,
+//!     );
+//!     store.extend_scale_teams(
+//!         session
+//!             .project_sessions_id_scale_teams(
+//!                 FtApiProjectSessionsScaleTeamsRequest::new(project_session_id)
+//!                     .with_per_page(100),
+//!             )
+//!             .await?
+//!             .scale_teams,
+//!     );
+//!
+//!     for scale_team in store.scale_teams() {
+//!         let members = scale_team
+//!             .team
+//!             .as_ref()
+//!             .map(|team| store.team_logins(&team.id))
+//!             .unwrap_or_default();
+//!         println!("{:?} evaluates {members:?}", store.corrector_login(scale_team));
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// Fetched users, teams, teams users and scale teams, indexed for joins.
+#[derive(Debug, Default)]
+pub struct FtEntityStore {
+    users: HashMap<FtUserId, FtUser>,
+    teams: HashMap<FtTeamId, FtTeam>,
+    teams_users: Vec<FtTeamsUser>,
+    scale_teams: HashMap<FtScaleTeamId, FtScaleTeam>,
+    logins: HashMap<FtUserId, FtLoginId>,
+    members: HashMap<FtTeamId, Vec<FtUserId>>,
+}
+
+impl FtEntityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds users, replacing the ones already stored with the same id. Users without id are
+    /// ignored.
+    pub fn extend_users(&mut self, users: impl IntoIterator<Item = FtUser>) {
+        for user in users {
+            let Some(user_id) = user.id else { continue };
+            self.see(&user);
+            self.users.insert(user_id, user);
+        }
+    }
+
+    /// Adds teams, and their embedded users as members.
+    pub fn extend_teams(&mut self, teams: impl IntoIterator<Item = FtTeam>) {
+        for team in teams {
+            for user in team.users.iter().flatten() {
+                self.see(user);
+                if let Some(user_id) = user.id {
+                    self.add_member(&team.id, user_id);
+                }
+            }
+            self.teams.insert(team.id.clone(), team);
+        }
+    }
+
+    /// Adds teams users, linking each user to its team.
+    pub fn extend_teams_users(&mut self, teams_users: impl IntoIterator<Item = FtTeamsUser>) {
+        for teams_user in teams_users {
+            if let Some(user) = &teams_user.user {
+                self.see(user);
+            }
+            self.add_member(&teams_user.team_id, teams_user.user_id);
+            self.teams_users.push(teams_user);
+        }
+    }
+
+    /// Adds scale teams, and the users embedded as corrector and correcteds.
+    pub fn extend_scale_teams(&mut self, scale_teams: impl IntoIterator<Item = FtScaleTeam>) {
+        for scale_team in scale_teams {
+            if let FtCorrector::User(user) = &scale_team.corrector {
+                self.see(user);
+            }
+            if let FtCorrecteds::Vec(users) = &scale_team.correcteds {
+                for user in users {
+                    self.see(user);
+                }
+            }
+            self.scale_teams.insert(scale_team.id.clone(), scale_team);
+        }
+    }
+
+    pub fn user(&self, user_id: &FtUserId) -> Option<&FtUser> {
+        self.users.get(user_id)
+    }
+
+    pub fn team(&self, team_id: &FtTeamId) -> Option<&FtTeam> {
+        self.teams.get(team_id)
+    }
+
+    pub fn scale_team(&self, scale_team_id: &FtScaleTeamId) -> Option<&FtScaleTeam> {
+        self.scale_teams.get(scale_team_id)
+    }
+
+    pub fn users(&self) -> impl Iterator<Item = &FtUser> {
+        self.users.values()
+    }
+
+    pub fn teams(&self) -> impl Iterator<Item = &FtTeam> {
+        self.teams.values()
+    }
+
+    pub fn teams_users(&self) -> &[FtTeamsUser] {
+        &self.teams_users
+    }
+
+    pub fn scale_teams(&self) -> impl Iterator<Item = &FtScaleTeam> {
+        self.scale_teams.values()
+    }
+
+    /// The login of `user_id`, from any user seen so far.
+    pub fn login(&self, user_id: &FtUserId) -> Option<&FtLoginId> {
+        self.logins.get(user_id)
+    }
+
+    /// The members of `team_id`, in the order they were seen.
+    pub fn team_members(&self, team_id: &FtTeamId) -> &[FtUserId] {
+        self.members.get(team_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The logins of the members of `team_id`, skipping members whose login is unknown.
+    pub fn team_logins(&self, team_id: &FtTeamId) -> Vec<&FtLoginId> {
+        self.team_members(team_id)
+            .iter()
+            .filter_map(|user_id| self.login(user_id))
+            .collect()
+    }
+
+    /// The teams `user_id` is a member of.
+    pub fn user_teams(&self, user_id: &FtUserId) -> Vec<&FtTeam> {
+        self.members
+            .iter()
+            .filter(|(_, members)| members.contains(user_id))
+            .filter_map(|(team_id, _)| self.team(team_id))
+            .collect()
+    }
+
+    /// The team evaluated in `scale_team`, as stored, else as embedded in the scale team.
+    pub fn scale_team_team<'a>(&'a self, scale_team: &'a FtScaleTeam) -> Option<&'a FtTeam> {
+        let team = scale_team.team.as_ref()?;
+        Some(self.team(&team.id).unwrap_or(team))
+    }
+
+    /// The scale teams evaluating `team_id`.
+    pub fn team_scale_teams(&self, team_id: &FtTeamId) -> Vec<&FtScaleTeam> {
+        self.scale_teams
+            .values()
+            .filter(|scale_team| {
+                scale_team
+                    .team
+                    .as_ref()
+                    .is_some_and(|team| &team.id == team_id)
+            })
+            .collect()
+    }
+
+    /// The corrector of `scale_team`, `None` when hidden by the API.
+    pub fn corrector_id(&self, scale_team: &FtScaleTeam) -> Option<FtUserId> {
+        match &scale_team.corrector {
+            FtCorrector::User(user) => user.id,
+            FtCorrector::String(_) => None,
+        }
+    }
+
+    /// The login of the corrector of `scale_team`.
+    pub fn corrector_login<'a>(&'a self, scale_team: &'a FtScaleTeam) -> Option<&'a FtLoginId> {
+        match &scale_team.corrector {
+            FtCorrector::User(user) => user.login.as_ref().or_else(|| self.login(&user.id?)),
+            FtCorrector::String(_) => None,
+        }
+    }
+
+    fn see(&mut self, user: &FtUser) {
+        if let (Some(user_id), Some(login)) = (user.id, &user.login) {
+            self.logins.insert(user_id, login.clone());
+        }
+    }
+
+    fn add_member(&mut self, team_id: &FtTeamId, user_id: FtUserId) {
+        let members = self.members.entry(team_id.clone()).or_default();
+        if !members.contains(&user_id) {
+            members.push(user_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> FtEntityStore {
+        let mut store = FtEntityStore::new();
+        store.extend_users([FtUser::new()
+            .with_id(FtUserId::new(3))
+            .with_login(FtLoginId::new("third".to_string()))]);
+        store.extend_teams(
+            serde_json::from_value::<Vec<FtTeam>>(serde_json::json!([
+                { "id": 10, "users": [{ "id": 1, "login": "first" }] },
+                { "id": 11, "users": [] }
+            ]))
+            .unwrap(),
+        );
+        store.extend_teams_users(
+            serde_json::from_value::<Vec<FtTeamsUser>>(serde_json::json!([
+                { "id": 100, "team_id": 10, "user_id": 1 },
+                { "id": 101, "team_id": 10, "user_id": 3 },
+                { "id": 102, "team_id": 11, "user_id": 4 }
+            ]))
+            .unwrap(),
+        );
+        store.extend_scale_teams(
+            serde_json::from_value::<Vec<FtScaleTeam>>(serde_json::json!([{
+                "id": 1000,
+                "scale_id": 1,
+                "created_at": "2025-03-01T00:00:00.000Z",
+                "updated_at": "2025-03-01T00:00:00.000Z",
+                "corrector": { "id": 3 },
+                "correcteds": [{ "id": 1, "login": "first" }],
+                "truant": {},
+                "team": { "id": 10 }
+            }]))
+            .unwrap(),
+        );
+        store
+    }
+
+    #[test]
+    fn joins() {
+        let store = store();
+        let login = |login: &str| FtLoginId::new(login.to_string());
+
+        assert_eq!(
+            store.team_logins(&FtTeamId::new(10)),
+            vec![&login("first"), &login("third")]
+        );
+        // The login of user 4 was never seen.
+        assert_eq!(store.team_members(&FtTeamId::new(11)), &[FtUserId::new(4)]);
+        assert!(store.team_logins(&FtTeamId::new(11)).is_empty());
+
+        let scale_team = store.scale_team(&FtScaleTeamId::new(1000)).unwrap();
+        assert_eq!(store.corrector_login(scale_team), Some(&login("third")));
+        assert_eq!(
+            store
+                .scale_team_team(scale_team)
+                .map(|team| team.id.clone()),
+            Some(FtTeamId::new(10))
+        );
+        assert_eq!(store.team_scale_teams(&FtTeamId::new(10)).len(), 1);
+        assert_eq!(store.user_teams(&FtUserId::new(3)).len(), 1);
+    }
+}
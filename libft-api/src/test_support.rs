@@ -0,0 +1,431 @@
+//! A mock Intra API for integration tests, behind the `test-support` feature.
+//!
+//! The crate's own unit tests use it too, so the default `cargo test` runs offline.
+//!
+//! `FtMockServer` starts a local [`wiremock`] server preloaded with a few realistic users, teams
+//! and scale teams, answered with the same headers as the Intra (`x-total`, `x-per-page`, rate
+//! limit counters). `FtMockServer::client` returns an `FtClient` pointing at it, and
+//! `FtMockServer::token` a token the mock accepts, so downstream crates can test their code
+//! without credentials nor network.
+//!
+//! The preloaded routes are:
+//! * `GET /v2/users` and `GET /v2/campus/:id/users`, listing the users;
+//! * `GET /v2/users/:id` and `GET /v2/users/:login`, for each user;
+//! * `GET /v2/teams` and `GET /v2/teams/:id`;
+//! * `GET /v2/scale_teams` and `GET /v2/scale_teams/:id`.
+//!
+//! Any other request gets a `404`. Routes mounted with `mock_get` or `mock_rate_limited` take
+//! precedence over the preloaded ones, and `server` gives access to the underlying `MockServer`
+//! for anything else.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//! use libft_api::test_support::*;
+//!
+//! # async fn run() {
+//! let mock = FtMockServer::start().await;
+//! let client = mock.client();
+//! let session = client.open_session(FtMockServer::token());
+//!
+//! let users = session.users(FtApiUsersRequest::new()).await.unwrap().users;
+//! assert_eq!(users.len(), FT_MOCK_USERS.len());
+//!
+//! // The first call is rate-limited, the retry gets the user.
+//! mock.mock_rate_limited("users/190001", 1, 1).await;
+//! let user = session.fetch_users([FtUserId::new(190001)], 1).await;
+//! assert!(user[0].is_ok());
+//! # }
+//! # tokio::runtime::Runtime::new().unwrap().block_on(run());
+//! ```
+
+use chrono::Utc;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::prelude::*;
+
+/// The `(id, login)` of the preloaded users.
+pub const FT_MOCK_USERS: [(i32, &str); 3] = [
+    (190001, "mockstu"),
+    (190002, "mockpee"),
+    (190003, "mockstf"),
+];
+
+/// The ids of the preloaded teams, both of `FT_MOCK_USERS[0]` and `FT_MOCK_USERS[1]`.
+pub const FT_MOCK_TEAM_IDS: [i32; 2] = [6_100_001, 6_100_002];
+
+/// The id of the preloaded scale team, `FT_MOCK_USERS[2]` evaluating `FT_MOCK_TEAM_IDS[0]`.
+pub const FT_MOCK_SCALE_TEAM_ID: i32 = 7_200_001;
+
+/// The rate limits announced by the mock, per second and per hour.
+pub const FT_MOCK_RATELIMITS: (u64, u64) = (8, 1200);
+
+/// A running mock of the Intra API; the server stops when it is dropped.
+pub struct FtMockServer {
+    server: MockServer,
+}
+
+impl FtMockServer {
+    /// Starts a mock server preloaded with the users, teams and scale teams of this module.
+    pub async fn start() -> Self {
+        let mock = Self {
+            server: MockServer::start().await,
+        };
+
+        let users = users();
+        let teams = teams();
+        let scale_teams = scale_teams();
+        mock.preload("users", Value::Array(users.clone())).await;
+        mock.preload(
+            &format!("campus/{}/users", ft_campus_id::GYEONGSAN),
+            Value::Array(users.clone()),
+        )
+        .await;
+        for (user, (id, login)) in users.into_iter().zip(FT_MOCK_USERS) {
+            mock.preload(&format!("users/{id}"), user.clone()).await;
+            mock.preload(&format!("users/{login}"), user).await;
+        }
+        mock.preload("teams", Value::Array(teams.clone())).await;
+        for (team, id) in teams.into_iter().zip(FT_MOCK_TEAM_IDS) {
+            mock.preload(&format!("teams/{id}"), team).await;
+        }
+        mock.preload("scale_teams", Value::Array(scale_teams.clone()))
+            .await;
+        mock.preload(
+            &format!("scale_teams/{FT_MOCK_SCALE_TEAM_ID}"),
+            scale_teams[0].clone(),
+        )
+        .await;
+
+        mock
+    }
+
+    /// The underlying server, to mount custom mocks or inspect the received requests.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// The API root of the mock, to pass to `FtClientReqwestConnector::with_ft_api_url`.
+    pub fn api_url(&self) -> String {
+        format!("{}/v2", self.server.uri())
+    }
+
+    /// A client sending its requests to the mock, with the rate limits the mock announces.
+    pub fn client(&self) -> FtClient<FtClientReqwestConnector> {
+        FtClient::with_ratelimits(
+            FtClientReqwestConnector::new().with_ft_api_url(&self.api_url()),
+            FT_MOCK_RATELIMITS.0,
+            FT_MOCK_RATELIMITS.1,
+        )
+    }
+
+    /// A token valid for two hours. The mock does not check it.
+    pub fn token() -> FtApiToken {
+        let now = Utc::now().timestamp();
+        serde_json::from_value(json!({
+            "access_token": "mock-access-token",
+            "token_type": "bearer",
+            "expires_in": 7200,
+            "scope": "public projects profile",
+            "created_at": now,
+            "secret_valid_until": now + 7200
+        }))
+        .unwrap()
+    }
+
+    /// Answers `GET /v2/{relative_path}` with `body`, instead of the preloaded response if any.
+    ///
+    /// A JSON array body is answered as one page holding every element.
+    pub async fn mock_get(&self, relative_path: &str, body: Value) {
+        self.mock_json("GET", relative_path, body).await;
+    }
+
+    /// Answers `{http_method} /v2/{relative_path}` with `body`, e.g. for the `PATCH` or `POST`
+    /// the code under test sends. Nothing is preloaded for those methods.
+    pub async fn mock_json(&self, http_method: &str, relative_path: &str, body: Value) {
+        Mock::given(method(http_method))
+            .and(path(format!("/v2/{relative_path}")))
+            .respond_with(ok(body))
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Answers the next `times` requests to `/v2/{relative_path}` with a `429` asking to retry
+    /// after `retry_after` seconds.
+    pub async fn mock_rate_limited(&self, relative_path: &str, retry_after: u64, times: u64) {
+        Mock::given(path(format!("/v2/{relative_path}")))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("retry-after", retry_after.to_string())
+                    .insert_header(
+                        "x-secondly-ratelimit-limit",
+                        FT_MOCK_RATELIMITS.0.to_string(),
+                    )
+                    .insert_header("x-secondly-ratelimit-remaining", "0")
+                    .set_body_string("Retry later\n"),
+            )
+            .up_to_n_times(times)
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    async fn preload(&self, relative_path: &str, body: Value) {
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/{relative_path}")))
+            .respond_with(ok(body))
+            .mount(&self.server)
+            .await;
+    }
+}
+
+/// A `200` with the headers of the Intra.
+fn ok(body: Value) -> ResponseTemplate {
+    let mut res = ResponseTemplate::new(200)
+        .insert_header(
+            "x-secondly-ratelimit-limit",
+            FT_MOCK_RATELIMITS.0.to_string(),
+        )
+        .insert_header(
+            "x-secondly-ratelimit-remaining",
+            (FT_MOCK_RATELIMITS.0 - 1).to_string(),
+        )
+        .insert_header("x-hourly-ratelimit-limit", FT_MOCK_RATELIMITS.1.to_string())
+        .insert_header(
+            "x-hourly-ratelimit-remaining",
+            (FT_MOCK_RATELIMITS.1 - 1).to_string(),
+        );
+    if let Value::Array(records) = &body {
+        res = res
+            .insert_header("x-total", records.len().to_string())
+            .insert_header("x-page", "1")
+            .insert_header("x-per-page", records.len().max(1).to_string());
+    }
+    // The connector only decodes this exact content type.
+    res.set_body_raw(body.to_string(), "application/json; charset=utf-8")
+}
+
+fn users() -> Vec<Value> {
+    let [(stu, stu_login), (pee, pee_login), (stf, stf_login)] = FT_MOCK_USERS;
+    vec![
+        user(stu, stu_login, "Minji", "Kim", "student", "march", false),
+        user(pee, pee_login, "Jiho", "Park", "student", "july", false),
+        user(stf, stf_login, "Seoyeon", "Lee", "staff", "january", true),
+    ]
+}
+
+fn user(
+    id: i32,
+    login: &str,
+    first_name: &str,
+    last_name: &str,
+    kind: &str,
+    pool_month: &str,
+    staff: bool,
+) -> Value {
+    json!({
+        "id": id,
+        "email": format!("{login}@student.42gyeongsan.kr"),
+        "login": login,
+        "first_name": first_name,
+        "last_name": last_name,
+        "usual_full_name": format!("{first_name} {last_name}"),
+        "usual_first_name": null,
+        "url": format!("https://api.intra.42.fr/v2/users/{login}"),
+        "phone": "hidden",
+        "displayname": format!("{first_name} {last_name}"),
+        "kind": kind,
+        "image": {
+            "link": format!("https://cdn.intra.42.fr/users/{login}.jpg"),
+            "versions": {
+                "large": format!("https://cdn.intra.42.fr/users/large_{login}.jpg"),
+                "medium": format!("https://cdn.intra.42.fr/users/medium_{login}.jpg"),
+                "small": format!("https://cdn.intra.42.fr/users/small_{login}.jpg"),
+                "micro": format!("https://cdn.intra.42.fr/users/micro_{login}.jpg")
+            }
+        },
+        "staff?": staff,
+        "correction_point": 5,
+        "pool_month": pool_month,
+        "pool_year": "2024",
+        "location": null,
+        "wallet": 120,
+        "anonymize_date": "2029-03-01T00:00:00.000+09:00",
+        "data_erasure_date": "2029-03-01T00:00:00.000Z",
+        "created_at": "2024-02-13T04:12:09.512Z",
+        "updated_at": "2025-03-01T09:30:00.000Z",
+        "alumnized_at": null,
+        "alumni?": false,
+        "active?": true
+    })
+}
+
+fn teams() -> Vec<Value> {
+    let [(stu, stu_login), (pee, pee_login), _] = FT_MOCK_USERS;
+    FT_MOCK_TEAM_IDS
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let closed = i == 0;
+            json!({
+                "id": id,
+                "name": format!("{stu_login}'s group {i}"),
+                "url": format!("https://api.intra.42.fr/v2/teams/{id}"),
+                "final_mark": if closed { json!(125) } else { Value::Null },
+                "project_id": 1314,
+                "created_at": "2025-02-20T10:00:00.000Z",
+                "updated_at": "2025-03-01T09:30:00.000Z",
+                "status": if closed { "finished" } else { "in_progress" },
+                "terminating_at": null,
+                "users": [
+                    team_user(stu, stu_login, true, 4_000_001),
+                    team_user(pee, pee_login, false, 4_000_002)
+                ],
+                "locked?": true,
+                "validated?": if closed { json!(true) } else { Value::Null },
+                "closed?": closed,
+                "repo_url": format!("git@vogsphere.42gyeongsan.kr:vogsphere/intra-uuid-{id}"),
+                "repo_uuid": format!("intra-uuid-{id}"),
+                "locked_at": "2025-02-20T10:00:00.000Z",
+                "closed_at": closed.then_some("2025-02-28T18:00:00.000Z"),
+                "project_session_id": 10_001,
+                "project_gitlab_path": "pedago_world/42-cursus/inner-circle/libft"
+            })
+        })
+        .collect()
+}
+
+/// A user as embedded in other records.
+fn user_ref(id: i32, login: &str) -> Value {
+    json!({
+        "id": id,
+        "login": login,
+        "url": format!("https://api.intra.42.fr/v2/users/{login}")
+    })
+}
+
+fn team_user(id: i32, login: &str, leader: bool, projects_user_id: i32) -> Value {
+    let mut user = user_ref(id, login);
+    user["leader"] = json!(leader);
+    user["occurrence"] = json!(0);
+    user["validated"] = json!(true);
+    user["projects_user_id"] = json!(projects_user_id);
+    user
+}
+
+fn scale_teams() -> Vec<Value> {
+    let [(stu, stu_login), (pee, pee_login), (stf, stf_login)] = FT_MOCK_USERS;
+    vec![json!({
+        "id": FT_MOCK_SCALE_TEAM_ID,
+        "scale_id": 30_001,
+        "comment": "Clean code, every test passed.",
+        "created_at": "2025-02-28T12:00:00.000Z",
+        "updated_at": "2025-02-28T18:00:00.000Z",
+        "feedback": "Thanks for the thorough review.",
+        "final_mark": 125,
+        "flag": {
+            "id": 9,
+            "name": "Outstanding project",
+            "positive": true,
+            "icon": "",
+            "created_at": "2015-09-14T23:06:52.000Z",
+            "updated_at": "2015-09-14T23:06:52.000Z"
+        },
+        "begin_at": "2025-02-28T17:00:00.000Z",
+        "correcteds": [
+            user_ref(stu, stu_login),
+            user_ref(pee, pee_login)
+        ],
+        "corrector": user_ref(stf, stf_login),
+        "truant": {},
+        "filled_at": "2025-02-28T18:00:00.000Z",
+        "questions_with_answers": [],
+        "team": {
+            "id": FT_MOCK_TEAM_IDS[0],
+            "name": format!("{stu_login}'s group 0"),
+            "closed?": true,
+            "locked?": true
+        }
+    })]
+}
+
+#[cfg(all(test, feature = "users", feature = "teams", feature = "scale_teams"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn serves_fixtures() {
+        let mock = FtMockServer::start().await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        let users = session.users(FtApiUsersRequest::new()).await.unwrap().users;
+        assert_eq!(users.len(), FT_MOCK_USERS.len());
+
+        let user = session
+            .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::Login(
+                FtLoginId::new("mockpee".to_string()),
+            )))
+            .await
+            .unwrap()
+            .user;
+        assert_eq!(user.id, Some(FtUserId::new(190002)));
+
+        let team = session
+            .teams_id(FtApiTeamsIdRequest::new(FtTeamId::new(FT_MOCK_TEAM_IDS[0])))
+            .await
+            .unwrap();
+        assert_eq!(team.team.closed, Some(true));
+
+        let mut store = FtEntityStore::new();
+        store.extend_scale_teams(
+            session
+                .scale_teams(FtApiScaleTeamsRequest::new())
+                .await
+                .unwrap()
+                .scale_teams,
+        );
+        let scale_team = store
+            .scale_team(&FtScaleTeamId::new(FT_MOCK_SCALE_TEAM_ID))
+            .unwrap();
+        assert_eq!(
+            store.corrector_login(scale_team),
+            Some(&FtLoginId::new("mockstf".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn overrides_and_rate_limits() {
+        let mock = FtMockServer::start().await;
+        let client = mock.client();
+        let session = client.open_session(FtMockServer::token());
+
+        mock.mock_get("users", json!([])).await;
+        let users = session.users(FtApiUsersRequest::new()).await.unwrap().users;
+        assert!(users.is_empty());
+
+        mock.mock_rate_limited("users/190001", 1, 1).await;
+        let err = session
+            .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::UserId(
+                FtUserId::new(190001),
+            )))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FtClientError::RateLimitError(_)));
+
+        let users = session.fetch_users([FtUserId::new(190001)], 1).await;
+        assert_eq!(users[0].as_ref().unwrap().id, Some(FtUserId::new(190001)));
+
+        let err = session
+            .users_id(FtApiUsersIdRequest::new(FtUserIdentifier::UserId(
+                FtUserId::new(1),
+            )))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FtClientError::HttpError(_)));
+    }
+}
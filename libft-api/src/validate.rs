@@ -0,0 +1,224 @@
+//! Consistency checks across fetched collections.
+//!
+//! The checks run on plain slices of models, as fetched from the API, read back from JSON exports
+//! or from the `mirror`. Each anomaly becomes a typed `FtFinding`; an empty result means the data
+//! is consistent.
+//!
+//! # Example
+//!
+//! ```rust
+//! use libft_api::prelude::*;
+//!
+//! async fn after_rush(project_session_id: FtProjectSessionId) -> ClientResult<()> {
+//!     let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap()).await.unwrap();
+//!     let client = FtClient::new(FtClientReqwestConnector::new());
+//!     let session = client.open_session(token);
+//!
+//!     let teams = session
+//!         .project_sessions_id_teams(
+//!             FtApiProjectSessionsTeamsRequest::new(project_session_id.clone()).with_per_page(100),
+//!         )
+//!         .await?
+//!         .teams;
+//!     let scale_teams = session
+//!         .project_sessions_id_scale_teams(
+//!             FtApiProjectSessionsScaleTeamsRequest::new(project_session_id).with_per_page(100),
+//!         )
+//!         .await?
+//!         .scale_teams;
+//!
+//!     for finding in check_consistency(&teams, &scale_teams, &[]) {
+//!         println!("{}", serde_json::to_string(&finding).unwrap());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// An anomaly found across collections.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FtFinding {
+    /// A team listing no user.
+    TeamWithoutUsers { team_id: FtTeamId },
+    /// A scale team whose team is locked.
+    ScaleTeamOnLockedTeam {
+        scale_team_id: FtScaleTeamId,
+        team_id: FtTeamId,
+    },
+    /// A marked projects user whose current team is not closed, or who has no current team.
+    MarkedWithoutClosedTeam {
+        projects_user_id: FtProjectUserId,
+        user_id: Option<FtUserId>,
+        team_id: Option<FtTeamId>,
+    },
+}
+
+/// Runs every check, with `teams` used to look up the teams referenced by the other collections.
+pub fn check_consistency(
+    teams: &[FtTeam],
+    scale_teams: &[FtScaleTeam],
+    projects_users: &[FtProjectsUser],
+) -> Vec<FtFinding> {
+    let mut findings = check_teams(teams);
+    findings.extend(check_scale_teams(scale_teams, teams));
+    findings.extend(check_projects_users(projects_users, teams));
+    findings
+}
+
+/// Reports the teams whose user list is empty. Teams fetched without their users are skipped.
+pub fn check_teams(teams: &[FtTeam]) -> Vec<FtFinding> {
+    teams
+        .iter()
+        .filter(|team| team.users.as_ref().is_some_and(Vec::is_empty))
+        .map(|team| FtFinding::TeamWithoutUsers {
+            team_id: team.id.clone(),
+        })
+        .collect()
+}
+
+/// Reports the scale teams whose team is locked.
+///
+/// The team is looked up in `teams` first, then in the team embedded in the scale team.
+pub fn check_scale_teams(scale_teams: &[FtScaleTeam], teams: &[FtTeam]) -> Vec<FtFinding> {
+    let teams = by_id(teams);
+    scale_teams
+        .iter()
+        .filter_map(|scale_team| {
+            let embedded = scale_team.team.as_ref()?;
+            let team = teams.get(&embedded.id).copied().unwrap_or(embedded);
+            (team.locked == Some(true)).then(|| FtFinding::ScaleTeamOnLockedTeam {
+                scale_team_id: scale_team.id.clone(),
+                team_id: team.id.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Reports the marked projects users whose current team is not closed.
+///
+/// The current team is looked up in the projects user's own `teams` first, then in `teams`;
+/// projects users whose current team is found in neither are skipped.
+pub fn check_projects_users(projects_users: &[FtProjectsUser], teams: &[FtTeam]) -> Vec<FtFinding> {
+    let teams = by_id(teams);
+    projects_users
+        .iter()
+        .filter(|projects_user| projects_user.marked)
+        .filter_map(|projects_user| {
+            let closed = match &projects_user.current_team_id {
+                None => false,
+                Some(team_id) => {
+                    let team = projects_user
+                        .teams
+                        .iter()
+                        .flatten()
+                        .find(|team| &team.id == team_id)
+                        .or_else(|| teams.get(team_id).copied())?;
+                    team.closed == Some(true)
+                }
+            };
+            (!closed).then(|| FtFinding::MarkedWithoutClosedTeam {
+                projects_user_id: projects_user.id.clone(),
+                user_id: projects_user.user.as_ref().and_then(|user| user.id),
+                team_id: projects_user.current_team_id.clone(),
+            })
+        })
+        .collect()
+}
+
+fn by_id(teams: &[FtTeam]) -> HashMap<&FtTeamId, &FtTeam> {
+    teams.iter().map(|team| (&team.id, team)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(id: i32, users: serde_json::Value, locked: bool, closed: bool) -> FtTeam {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "users": users,
+            "locked?": locked,
+            "closed?": closed
+        }))
+        .unwrap()
+    }
+
+    fn scale_team(id: i32, team_id: i32) -> FtScaleTeam {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "scale_id": 1,
+            "created_at": "2025-03-01T00:00:00.000Z",
+            "updated_at": "2025-03-01T00:00:00.000Z",
+            "corrector": { "id": 1, "login": "corrector" },
+            "correcteds": [{ "id": 2, "login": "corrected" }],
+            "truant": {},
+            "team": { "id": team_id }
+        }))
+        .unwrap()
+    }
+
+    fn projects_user(id: i32, current_team_id: Option<i32>, marked: bool) -> FtProjectsUser {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "occurrence": 0,
+            "final_mark": null,
+            "status": "finished",
+            "current_team_id": current_team_id,
+            "project": { "id": 1314, "name": "Libft", "slug": "42cursus-libft" },
+            "cursus_ids": [21],
+            "marked": marked,
+            "marked_at": null,
+            "retriable_at": null,
+            "created_at": "2025-03-01T00:00:00.000Z",
+            "updated_at": "2025-03-01T00:00:00.000Z",
+            "user": { "id": 2 }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_anomalies() {
+        let teams = [
+            team(10, serde_json::json!([{ "id": 2 }]), true, true),
+            team(11, serde_json::json!([]), false, false),
+            team(12, serde_json::json!(null), false, false),
+        ];
+        let scale_teams = [scale_team(1, 10), scale_team(2, 11)];
+        let projects_users = [
+            projects_user(100, Some(10), true),
+            projects_user(101, Some(11), true),
+            projects_user(102, None, true),
+            projects_user(103, Some(99), true),
+            projects_user(104, Some(11), false),
+        ];
+
+        assert_eq!(
+            check_consistency(&teams, &scale_teams, &projects_users),
+            vec![
+                FtFinding::TeamWithoutUsers {
+                    team_id: FtTeamId::new(11)
+                },
+                FtFinding::ScaleTeamOnLockedTeam {
+                    scale_team_id: FtScaleTeamId::new(1),
+                    team_id: FtTeamId::new(10),
+                },
+                FtFinding::MarkedWithoutClosedTeam {
+                    projects_user_id: FtProjectUserId::new(101),
+                    user_id: Some(FtUserId::new(2)),
+                    team_id: Some(FtTeamId::new(11)),
+                },
+                FtFinding::MarkedWithoutClosedTeam {
+                    projects_user_id: FtProjectUserId::new(102),
+                    user_id: Some(FtUserId::new(2)),
+                    team_id: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Contract tests replaying recorded API responses through every endpoint module.
+//!
+//! Each contract calls one endpoint through `FtClientReplayConnector`, which answers from the
+//! cassettes of `tests/cassettes`, so the test checks the endpoint builds the recorded request and
+//! deserializes the recorded payload, without network nor credentials.
+//!
+//! To record the cassettes again from the live API, with credentials in the environment:
+//!
+//! ```sh
+//! cargo test --features online-tests --test contracts -- --ignored record
+//! ```
+//!
+//! Review the recorded payloads before committing them, they hold real user data.
+
+use std::path::{Path, PathBuf};
+
+use libft_api::connector::FtCassette;
+use libft_api::prelude::*;
+
+fn cassettes() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cassettes")
+}
+
+/// A token the replay connector never sends.
+fn token() -> FtApiToken {
+    let now = chrono::Utc::now().timestamp();
+    serde_json::from_value(serde_json::json!({
+        "access_token": "replayed",
+        "token_type": "bearer",
+        "expires_in": 7200,
+        "scope": "public",
+        "created_at": now,
+        "secret_valid_until": now + 7200
+    }))
+    .unwrap()
+}
+
+macro_rules! contracts {
+    ($($name:ident: |$session:ident| $call:expr,)*) => {
+        $(
+            #[tokio::test]
+            async fn $name() {
+                let client = FtClient::new(FtClientReplayConnector::replay(cassettes()));
+                let $session = client.open_session(token());
+                if let Err(err) = $call.await {
+                    panic!("{}: {err}", stringify!($name));
+                }
+            }
+        )*
+
+        /// Records the cassettes of every contract from the live API.
+        #[cfg(feature = "online-tests")]
+        #[tokio::test]
+        #[ignore = "records the cassettes from the live API"]
+        async fn record() {
+            let token = FtApiToken::try_get(AuthInfo::build_from_env().unwrap())
+                .await
+                .unwrap();
+            let client = FtClient::new(FtClientReplayConnector::record(
+                FtClientReqwestConnector::new(),
+                cassettes(),
+            ));
+            let live = client.open_session(token);
+            $(
+                let $session = &live;
+                if let Err(err) = $call.await {
+                    panic!("{}: {err}", stringify!($name));
+                }
+            )*
+        }
+    };
+}
+
+contracts! {
+    achievements: |session| session.achievements(FtApiAchievementsRequest::new()),
+    achievements_id_users: |session| session.achievements_id_users(
+        FtApiAchievementsIdUsersRequest::new(FtAchievementId::new(1))
+    ),
+    achievements_users: |session| session.achievements_users(
+        FtApiAchievementsUsersRequest::new()
+    ),
+    apps: |session| session.apps(FtApiAppsRequest::new()),
+    attachments: |session| session.attachments(FtApiAttachmentsRequest::new()),
+    blocs: |session| session.blocs(FtApiBlocsRequest::new()),
+    blocs_id_scores: |session| session.blocs_id_scores(
+        FtApiBlocsIdScoresRequest::new(FtBlocId::new(1))
+    ),
+    broadcasts: |session| session.broadcasts(FtApiBroadcastsRequest::new()),
+    campus_id: |session| session.campus_id(FtApiCampusIdRequest::new()),
+    campus_id_journals: |session| session.campus_id_journals(
+        FtApiCampusIdJournalsRequest::new(
+            FtCampusId::new(ft_campus_id::GYEONGSAN),
+            "2025-03-01".to_string(),
+            "2025-03-02".to_string(),
+        )
+    ),
+    campus_id_locations: |session| session.campus_id_locations(
+        FtApiCampusIdLocationsRequest::new(FtCampusId::new(ft_campus_id::GYEONGSAN))
+    ),
+    campus_id_users: |session| session.campus_id_users(
+        FtApiCampusIdUsersRequest::new(FtCampusId::new(ft_campus_id::GYEONGSAN))
+    ),
+    campus_users: |session| session.campus_users(FtApiCampusUsersRequest::new()),
+    coalitions: |session| session.coalitions(FtApiCoalitionsRequest::new()),
+    coalitions_id_users: |session| session.coalitions_id_users(
+        FtApiCoalitionsIdUsersRequest::new(FtCoalitionId::new(1))
+    ),
+    coalitions_users: |session| session.coalitions_users(FtApiCoalitionsUsersRequest::new()),
+    community_services: |session| session.community_services(
+        FtApiCommunityServicesRequest::new()
+    ),
+    cursus_id_projects: |session| session.cursus_id_projects(
+        FtApiCursusIdProjectsRequest::new(FtCursusId::new(21))
+    ),
+    cursus_id_users: |session| session.cursus_id_users(
+        FtApiCursusIdUsersRequest::new(FtCursusId::new(21))
+    ),
+    cursus_list: |session| session.cursus(FtApiCursusRequest::new()),
+    cursus_users: |session| session.cursus_users(FtApiCursusUsersRequest::new()),
+    exams: |session| session.exams(FtApiExamsRequest::new()),
+    feedbacks: |session| session.feedbacks(FtApiFeedbacksRequest::new()),
+    gitlab_users: |session| session.gitlab_users(FtApiGitlabUsersRequest::new()),
+    groups: |session| session.groups(FtApiGroupsRequest::new()),
+    journals: |session| session.journals(
+        FtApiJournalsRequest::new("2025-03-01".to_string(), "2025-03-02".to_string())
+    ),
+    languages: |session| session.languages(FtApiLanguagesRequest::new()),
+    levels: |session| session.levels(FtApiLevelsRequest::new()),
+    locations: |session| session.locations(FtApiLocationsRequest::new()),
+    notions: |session| session.notions(FtApiNotionsRequest::new()),
+    notions_id_subnotions: |session| session.notions_id_subnotions(
+        FtApiNotionsIdSubnotionsRequest::new(FtNotionId::new(1))
+    ),
+    offers: |session| session.offers(FtApiOffersRequest::new()),
+    pools: |session| session.pools(FtApiPoolsRequest::new()),
+    products: |session| session.products(FtApiProductsRequest::new()),
+    products_id_commands: |session| session.products_id_commands(
+        FtApiProductsIdCommandsRequest::new(FtProductId::new(1))
+    ),
+    project_data: |session| session.project_data(FtApiProjectDataRequest::new()),
+    projects: |session| session.projects(FtApiProjectRequest::new()),
+    projects_id: |session| session.projects_id(FtApiProjectsIdRequest::new(
+        FtProjectIdentifier::ProjectId(FtProjectId::new(1314))
+    )),
+    projects_id_project_sessions: |session| session.projects_id_project_sessions(
+        FtApiProjectsIdProjectSessionsRequest::new(FtProjectId::new(1314))
+    ),
+    projects_id_scales: |session| session.projects_id_scales(
+        FtApiProjectsIdScalesRequest::new(FtProjectId::new(1314))
+    ),
+    projects_id_slots: |session| session.projects_id_slots(
+        FtApiProjectsIdSlotsRequest::new(FtProjectId::new(1314))
+    ),
+    projects_id_teams: |session| session.projects_id_teams(
+        FtApiProjectsIdTeamsRequest::new(FtProjectId::new(1314))
+    ),
+    project_sessions_id: |session| session.project_sessions_id(
+        FtApiProjectSessionsIdRequest::new(FtProjectSessionId::new(10001))
+    ),
+    project_sessions_id_evaluations: |session| session.project_sessions_id_evaluations(
+        FtApiProjectSessionsIdEvaluationsRequest::new(FtProjectSessionId::new(10001))
+    ),
+    project_sessions_id_project_data: |session| session.project_sessions_id_project_data(
+        FtApiProjectSessionsIdProjectDataRequest::new(FtProjectSessionId::new(10001))
+    ),
+    project_sessions_id_project_sessions_rules: |session| session
+        .project_sessions_id_project_sessions_rules(
+            FtApiProjectSessionsIdProjectSessionsRulesRequest::new(FtProjectSessionId::new(10001))
+        ),
+    project_sessions_id_scale_teams: |session| session.project_sessions_id_scale_teams(
+        FtApiProjectSessionsScaleTeamsRequest::new(FtProjectSessionId::new(10001))
+    ),
+    project_sessions_id_teams: |session| session.project_sessions_id_teams(
+        FtApiProjectSessionsTeamsRequest::new(FtProjectSessionId::new(10001))
+    ),
+    project_sessions_rules_id_params_project_sessions_rules: |session| session
+        .project_sessions_rules_id_params_project_sessions_rules(
+            FtApiProjectSessionsRulesIdParamsProjectSessionsRulesRequest::new(
+                FtProjectSessionsRuleId::new(1)
+            )
+        ),
+    projects_users: |session| session.projects_users(FtApiProjectsUsersRequest::new()),
+    quests: |session| session.quests(FtApiQuestsRequest::new()),
+    scales: |session| session.scales(FtApiScalesRequest::new()),
+    scales_id: |session| session.scales_id(FtApiScalesIdRequest::new(FtScaleId::new(30001))),
+    scale_teams: |session| session.scale_teams(FtApiScaleTeamsRequest::new()),
+    scale_teams_id: |session| session.scale_teams_id(
+        FtApiScaleTeamsIdRequest::new(FtScaleTeamId::new(7200001))
+    ),
+    skills: |session| session.skills(FtApiSkillsRequest::new()),
+    slots: |session| session.slots(FtApiSlotsRequest::new()),
+    tags: |session| session.tags(FtApiTagsRequest::new()),
+    teams: |session| session.teams(FtApiTeamsRequest::new()),
+    teams_id: |session| session.teams_id(FtApiTeamsIdRequest::new(FtTeamId::new(6100001))),
+    teams_id_teams_users: |session| session.teams_id_teams_users(
+        FtApiTeamsIdTeamsUsersRequest::new(FtTeamId::new(6100001))
+    ),
+    transactions: |session| session.transactions(FtApiTransactionsRequest::new()),
+    translations: |session| session.translations(FtApiTranslationsRequest::new()),
+    users: |session| session.users(FtApiUsersRequest::new()),
+    users_id: |session| session.users_id(
+        FtApiUsersIdRequest::new(FtUserIdentifier::UserId(FtUserId::new(190001)))
+    ),
+    users_id_correction_point_historics: |session| session.users_id_correction_point_historics(
+        FtApiUsersIdCorrectionPointHistoricsRequest::new(FtUserId::new(190001))
+    ),
+    users_id_cursus_users: |session| session.users_id_cursus_users(
+        FtApiUsersIdCursusUsersRequest::new(FtUserId::new(190001))
+    ),
+    users_id_locations: |session| session.users_id_locations(
+        FtApiUsersIdLocationsRequest::new(FtUserId::new(190001))
+    ),
+    users_id_locations_stats: |session| session.users_id_locations_stats(
+        FtApiUsersIdLocationsStatsRequest::new(FtUserId::new(190001))
+    ),
+    users_id_projects_users: |session| session.users_id_projects_users(
+        FtApiUsersIdProjectsUsersRequest::new(FtUserId::new(190001))
+    ),
+    users_id_teams: |session| session.users_id_teams(
+        FtApiUsersIdTeamsRequest::new(FtUserId::new(190001))
+    ),
+    user_candidatures: |session| session.user_candidatures(FtApiUserCandidaturesRequest::new()),
+    waitlists: |session| session.waitlists(FtApiWaitlistsRequest::new()),
+}
+
+/// Every endpoint module sending GET requests has a contract named after it.
+#[test]
+fn every_endpoint_module_has_a_contract() {
+    let source = include_str!("contracts.rs");
+    let mut dirs = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src/api")];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let module = std::fs::read_to_string(&path).unwrap();
+            if !module.contains("http_get(") {
+                continue;
+            }
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            assert!(
+                source.contains(&format!("\n    {name}: |session|")),
+                "{}: no contract for this endpoint module",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Every cassette is a valid recording, named after the request it holds.
+#[test]
+fn every_cassette_is_valid() {
+    for entry in std::fs::read_dir(cassettes()).unwrap() {
+        let path = entry.unwrap().path();
+        let raw = std::fs::read_to_string(&path).unwrap();
+        let cassette: FtCassette = serde_json::from_str(&raw)
+            .unwrap_or_else(|err| panic!("{}: not a cassette: {err}", path.display()));
+        let url = format!(
+            "{}/{}{}",
+            "https://api.intra.42.fr",
+            cassette.path,
+            cassette
+                .query
+                .as_ref()
+                .map_or(String::new(), |q| format!("?{q}"))
+        );
+        let url: url::Url = url.parse().unwrap();
+        assert_eq!(
+            path.file_name().unwrap().to_str().unwrap(),
+            FtCassette::file_name(&cassette.method, &url),
+            "{}: the file name does not match the recorded request",
+            path.display()
+        );
+    }
+}
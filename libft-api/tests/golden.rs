@@ -0,0 +1,169 @@
+//! Golden-file regression suite for the models.
+//!
+//! Each `tests/fixtures/<name>.json` holds an anonymized payload as returned by the Intra. The
+//! test deserializes it into its model, serializes the model back and compares the result with
+//! `tests/snapshots/<name>.json`. The snapshot also lists the payload fields no model field kept,
+//! so a new field on the Intra side or a field lost in a model change shows up in the diff.
+//!
+//! After an intended change, regenerate the snapshots and review them:
+//!
+//! ```sh
+//! FT_UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use libft_api::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+fn dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(name)
+}
+
+fn check<T: DeserializeOwned + Serialize>(name: &str) {
+    let fixture = dir("fixtures").join(format!("{name}.json"));
+    let raw = std::fs::read_to_string(&fixture)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", fixture.display()));
+    let payload: Value = serde_json::from_str(&raw).unwrap();
+    let model: T = serde_json::from_str(&raw)
+        .unwrap_or_else(|err| panic!("{name}: cannot deserialize the fixture: {err}"));
+    let value = serde_json::to_value(&model).unwrap();
+
+    let mut unmapped = Vec::new();
+    collect_unmapped(&payload, &value, "", &mut unmapped);
+    let snapshot = serde_json::to_string_pretty(&json!({
+        "unmapped": unmapped,
+        "value": value,
+    }))
+    .unwrap()
+        + "\n";
+
+    let path = dir("snapshots").join(format!("{name}.json"));
+    if std::env::var_os("FT_UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, snapshot).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read {}: {err}; run with FT_UPDATE_GOLDEN=1 to create it",
+            path.display()
+        )
+    });
+    assert!(
+        expected == snapshot,
+        "{name}: the snapshot changed, run with FT_UPDATE_GOLDEN=1 and review the diff\n\
+         --- expected\n{expected}\n--- actual\n{snapshot}"
+    );
+}
+
+/// Pushes the paths of the non-null payload fields missing from the serialized model.
+fn collect_unmapped(payload: &Value, value: &Value, at: &str, unmapped: &mut Vec<String>) {
+    match (payload, value) {
+        (Value::Object(payload), Value::Object(value)) => {
+            for (key, field) in payload {
+                let path = format!("{at}.{key}");
+                match value.get(key) {
+                    Some(kept) => collect_unmapped(field, kept, &path, unmapped),
+                    None if !field.is_null() => unmapped.push(path),
+                    None => {}
+                }
+            }
+        }
+        (Value::Array(payload), Value::Array(value)) => {
+            for (i, (field, kept)) in payload.iter().zip(value).enumerate() {
+                collect_unmapped(field, kept, &format!("{at}[{i}]"), unmapped);
+            }
+        }
+        _ => {}
+    }
+}
+
+macro_rules! golden {
+    ($($name:ident: $model:ty,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check::<$model>(stringify!($name));
+            }
+        )*
+    };
+}
+
+golden! {
+    achievement: FtAchievement,
+    achievements_user: FtAchievementsUser,
+    app: FtApp,
+    attachment: FtAttachment,
+    bloc: FtBloc,
+    broadcast: FtBroadcast,
+    campus: FtCampus,
+    campus_user: FtCampusUser,
+    close: FtClose,
+    coalition: FtCoalition,
+    coalitions_user: FtCoalitionsUser,
+    command: FtCommand,
+    community_service: FtCommunityService,
+    correction_point_history: FtCorrectionPointHistory,
+    cursus_user: FtCursusUser,
+    evaluation: FtEvaluation,
+    event: FtEvent,
+    events_user: FtEventsUser,
+    exam: FtExam,
+    exam_user: FtExamUser,
+    experience: FtExperience,
+    feedback: FtFeedback,
+    flag: FtFlag,
+    gitlab_user: FtGitlabUser,
+    group: FtGroup,
+    journal: FtJournal,
+    language: FtLanguage,
+    languages_user: FtLanguagesUser,
+    level: FtLevel,
+    location: FtLocation,
+    note: FtNote,
+    notion: FtNotion,
+    offer: FtOffer,
+    pool: FtPool,
+    product: FtProduct,
+    project: FtProject,
+    project_data: FtProjectData,
+    project_session: FtProjectSession,
+    project_sessions_rule: FtProjectSessionsRule,
+    projects_user: FtProjectsUser,
+    quest: FtQuest,
+    role: FtRole,
+    scale: FtScale,
+    scale_team: FtScaleTeam,
+    score: FtScore,
+    skill: FtSkillDefinition,
+    slot: FtSlot,
+    tag: FtTag,
+    team: FtTeam,
+    teams_user: FtTeamsUser,
+    title: FtTitle,
+    title_user: FtTitleUser,
+    transaction: FtTransaction,
+    translation: FtTranslation,
+    user: FtUser,
+    user_candidature: FtUserCandidature,
+    waitlist: FtWaitlist,
+}
+
+/// Every fixture has a test, so a fixture added without one does not go unnoticed.
+#[test]
+fn every_fixture_is_checked() {
+    let source = include_str!("golden.rs");
+    for entry in std::fs::read_dir(dir("fixtures")).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+        assert!(
+            source.contains(&format!("\n    {name}: Ft")),
+            "{}: no golden test for this fixture",
+            path.display()
+        );
+    }
+}